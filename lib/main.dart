@@ -1822,11 +1822,38 @@ class CallDetailsWidget extends StatelessWidget {
                       '${(statisticsController.loss * 100).toStringAsFixed(1)}%',
                       style: TextStyle(color: color, fontSize: 17),
                     ),
+                    const Spacer(),
+                    Text(
+                      'Jitter: ${statisticsController.jitter.toStringAsFixed(1)} ms',
+                      style: const TextStyle(fontSize: 17),
+                    ),
+                    const SizedBox(width: 12),
+                    Text(
+                      'Buffer: ${statisticsController.jitterBuffer} ms',
+                      style: const TextStyle(fontSize: 17),
+                    ),
                   ],
                 );
               }),
           const Spacer(),
-          const Text('Input level'),
+          ListenableBuilder(
+              listenable: statisticsController,
+              builder: (BuildContext context, Widget? child) {
+                return Row(
+                  children: [
+                    const Text('Input level'),
+                    const SizedBox(width: 7),
+                    Icon(Icons.mic,
+                        size: 16,
+                        color: statisticsController.speaking
+                            ? Theme.of(context).colorScheme.primary
+                            : Theme.of(context).disabledColor),
+                    const Spacer(),
+                    Text(
+                        'Gain: ${statisticsController.gain.toStringAsFixed(1)} dB'),
+                  ],
+                );
+              }),
           const SizedBox(height: 7),
           ListenableBuilder(
               listenable: statisticsController,
@@ -1859,8 +1886,11 @@ class CallDetailsWidget extends StatelessWidget {
               ListenableBuilder(
                   listenable: statisticsController,
                   builder: (BuildContext context, Widget? child) {
-                    return Text('${statisticsController.latency} ms',
-                        style: const TextStyle(height: 0));
+                    return Tooltip(
+                      message: statisticsController.delayBreakdown,
+                      child: Text('${statisticsController.latency} ms',
+                          style: const TextStyle(height: 0)),
+                    );
                   }),
               const Spacer(),
               SvgPicture.asset('assets/icons/Upload.svg',
@@ -2288,6 +2318,24 @@ class StatisticsController extends ChangeNotifier {
       ? '?'
       : formatBandwidth(_statistics!.downloadBandwidth.toInt());
   double get loss => _statistics == null ? 0 : _statistics!.loss;
+  int get jitterBuffer =>
+      _statistics == null ? 0 : _statistics!.jitterBuffer.toInt();
+  double get jitter => _statistics == null ? 0 : _statistics!.jitter;
+  double get gain => _statistics == null ? 0 : _statistics!.gain;
+  bool get speaking => _statistics == null ? false : _statistics!.speaking;
+
+  /// the estimated one-way delay and the stages it comes from
+  String get delayBreakdown {
+    if (_statistics == null) return 'Delay: ?';
+    final statistics = _statistics!;
+
+    return 'Delay: ${statistics.oneWayDelay} ms\n'
+        'Capture: ${statistics.captureDelay} ms\n'
+        'Processing: ${statistics.processingDelay} ms\n'
+        'Network: ${statistics.networkDelay} ms\n'
+        'Jitter buffer: ${statistics.jitterBuffer} ms\n'
+        'Playout: ${statistics.playoutDelay} ms';
+  }
 
   void setStatistics(Statistics statistics) {
     _statistics = statistics;
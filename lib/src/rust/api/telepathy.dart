@@ -41,6 +41,8 @@ abstract class ChatMessage implements RustOpaqueInterface {
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CodecConfig>>
 abstract class CodecConfig implements RustOpaqueInterface {
+  bool fec();
+
   factory CodecConfig(
           {required bool enabled,
           required bool vbr,
@@ -50,6 +52,8 @@ abstract class CodecConfig implements RustOpaqueInterface {
 
   void setEnabled({required bool enabled});
 
+  void setFec({required bool fec});
+
   void setResidualBits({required double residualBits});
 
   void setVbr({required bool vbr});
@@ -133,8 +137,13 @@ abstract class Telepathy implements RustOpaqueInterface {
   /// Ends the call (if there is one)
   void endCall();
 
+  bool isRecording();
+
   Future<void> joinRoom({required List<String> memberStrings});
 
+  /// Lists the input and output devices with their identifiers and supported configurations
+  Future<(List<DeviceInfo>, List<DeviceInfo>)> listDeviceInfo();
+
   /// Lists the input and output devices
   Future<(List<String>, List<String>)> listDevices();
 
@@ -176,17 +185,49 @@ abstract class Telepathy implements RustOpaqueInterface {
           managerActive: managerActive,
           screenshareStarted: screenshareStarted);
 
+  /// The configurations the last input and output streams were opened with
+  (AudioConfig?, AudioConfig?) openedAudioConfigs();
+
   /// Restarts the session manager
   Future<void> restartManager();
 
   /// Sends a chat message
   Future<void> sendChat({required ChatMessage message});
 
+  void setAgc({required bool enabled});
+
+  /// How quickly the gain is lowered when the input gets louder
+  void setAgcAttack({required double milliseconds});
+
+  /// The most the automatic gain control amplifies quiet input by in decibels
+  void setAgcMaxGain({required double decibel});
+
+  /// How quickly the gain is raised when the input gets quieter
+  void setAgcRelease({required double milliseconds});
+
+  /// The level the automatic gain control brings speech to in dBFS
+  void setAgcTargetLevel({required double decibel});
+
+  /// A fixed buffer size in frames, lower sizes reduce latency at the risk of underruns.
+  /// None lets the host choose
+  void setBufferSize({int? frames});
+
+  /// Changing the comfort noise flag will not affect the current call
+  void setComfortNoise({required bool comfortNoise});
+
   void setDeafened({required bool deafened});
 
   /// Changing the denoise flag will not affect the current call
   void setDenoise({required bool denoise});
 
+  /// Sets the callback which receives the devices when devices appear or disappear
+  Future<void> setDevicesChanged(
+      {required FutureOr<void> Function(List<DeviceInfo>, List<DeviceInfo>)
+          devicesChanged});
+
+  /// Changing the echo cancellation flag will not affect the current call
+  void setEchoCancellation({required bool echoCancellation});
+
   void setEfficiencyMode({required bool enabled});
 
   /// Sets the signing key (called when the profile changes)
@@ -206,22 +247,147 @@ abstract class Telepathy implements RustOpaqueInterface {
 
   void setPlayCustomRingtones({required bool play});
 
+  /// The channel count devices are opened with when they support it, None uses the default
+  void setPreferredChannels({int? channels});
+
+  /// The sample rate devices are opened with when they support it, None uses the default
+  void setPreferredSampleRate({int? sampleRate});
+
+  /// Sets the callback which receives the peer id of another party and whether it is recording
+  Future<void> setRecordingChanged(
+      {required FutureOr<void> Function(String, bool) recordingChanged});
+
   void setRmsThreshold({required double decimal});
 
   void setSendCustomRingtone({required bool send});
 
+  /// Selects which input frames are sent: "rms", "vad" or "always"
+  void setSilenceDetection({required String mode});
+
+  /// Changing the stereo flag will not affect the current call
+  void setStereo({required bool stereo});
+
+  /// How long frames keep being sent after speech
+  void setVadHangover({required int milliseconds});
+
+  /// The voice probability between 0 and 1 above which a frame is speech
+  void setVadThreshold({required double probability});
+
   /// Attempts to start a call through an existing session
   Future<void> startCall({required Contact contact});
 
+  /// Records the current call or room to a "wav" or "sea" file at `path`. The local party is
+  /// on the left channel and the other parties on the right, who are told about the recording
+  Future<void> startRecording({required String path, required String format});
+
   Future<void> startScreenshare({required Contact contact});
 
   /// Tries to start a session for a contact
   Future<void> startSession({required Contact contact});
 
+  /// Stops the recording, the file is finished in the background
+  Future<void> stopRecording();
+
   /// Stops a specific session (called when a contact is deleted)
   Future<void> stopSession({required Contact contact});
 }
 
+/// the configuration a device was opened with
+class AudioConfig {
+  /// the identifier of the device
+  final String device;
+
+  final int sampleRate;
+
+  final int channels;
+
+  /// the fixed buffer size in frames, None when the host chose it
+  final int? bufferSize;
+
+  const AudioConfig({
+    required this.device,
+    required this.sampleRate,
+    required this.channels,
+    required this.bufferSize,
+  });
+
+  @override
+  int get hashCode =>
+      device.hashCode ^
+      sampleRate.hashCode ^
+      channels.hashCode ^
+      bufferSize.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AudioConfig &&
+          runtimeType == other.runtimeType &&
+          device == other.device &&
+          sampleRate == other.sampleRate &&
+          channels == other.channels &&
+          bufferSize == other.bufferSize;
+}
+
+/// an audio device for the frontend
+class DeviceInfo {
+  /// selects the device, unlike the name it is unique. Devices sharing a name are told apart
+  /// by a fingerprint of the configurations they support rather than by the order the host
+  /// lists them in
+  final String id;
+
+  /// the name shown to the user
+  final String name;
+
+  /// whether the host uses this device by default
+  final bool isDefault;
+
+  /// the sample rates the device supports
+  final Uint32List sampleRates;
+
+  /// the channel counts the device supports
+  final Uint16List channels;
+
+  /// the smallest buffer size in frames, when the host reports one
+  final int? minBufferSize;
+
+  /// the largest buffer size in frames, when the host reports one
+  final int? maxBufferSize;
+
+  const DeviceInfo({
+    required this.id,
+    required this.name,
+    required this.isDefault,
+    required this.sampleRates,
+    required this.channels,
+    required this.minBufferSize,
+    required this.maxBufferSize,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      name.hashCode ^
+      isDefault.hashCode ^
+      sampleRates.hashCode ^
+      channels.hashCode ^
+      minBufferSize.hashCode ^
+      maxBufferSize.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DeviceInfo &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          isDefault == other.isDefault &&
+          sampleRates == other.sampleRates &&
+          channels == other.channels &&
+          minBufferSize == other.minBufferSize &&
+          maxBufferSize == other.maxBufferSize;
+}
+
 /// processed statistics for the frontend
 class Statistics {
   /// a percentage of the max input volume in the window
@@ -242,6 +408,33 @@ class Statistics {
   /// a value between 0 and 1 representing the percent of audio lost in a sliding window
   final double loss;
 
+  /// the audio waiting in the jitter buffer in milliseconds
+  final BigInt jitterBuffer;
+
+  /// the variation of the one-way network delay in milliseconds
+  final double jitter;
+
+  /// the gain applied by the automatic gain control in decibels
+  final double gain;
+
+  /// whether the user is speaking, including the hangover after speech
+  final bool speaking;
+
+  /// the estimated delay of the input device and the captured samples waiting in milliseconds
+  final BigInt captureDelay;
+
+  /// the estimated delay of framing, resampling and coding the audio in milliseconds
+  final BigInt processingDelay;
+
+  /// half of the round trip time in milliseconds
+  final BigInt networkDelay;
+
+  /// the estimated delay of the samples waiting for the output device and its buffer in milliseconds
+  final BigInt playoutDelay;
+
+  /// the estimated mouth to ear delay of the call in milliseconds
+  final BigInt oneWayDelay;
+
   const Statistics({
     required this.inputLevel,
     required this.outputLevel,
@@ -249,6 +442,15 @@ class Statistics {
     required this.uploadBandwidth,
     required this.downloadBandwidth,
     required this.loss,
+    required this.jitterBuffer,
+    required this.jitter,
+    required this.gain,
+    required this.speaking,
+    required this.captureDelay,
+    required this.processingDelay,
+    required this.networkDelay,
+    required this.playoutDelay,
+    required this.oneWayDelay,
   });
 
   static Future<Statistics> default_() =>
@@ -261,7 +463,16 @@ class Statistics {
       latency.hashCode ^
       uploadBandwidth.hashCode ^
       downloadBandwidth.hashCode ^
-      loss.hashCode;
+      loss.hashCode ^
+      jitterBuffer.hashCode ^
+      jitter.hashCode ^
+      gain.hashCode ^
+      speaking.hashCode ^
+      captureDelay.hashCode ^
+      processingDelay.hashCode ^
+      networkDelay.hashCode ^
+      playoutDelay.hashCode ^
+      oneWayDelay.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -273,5 +484,14 @@ class Statistics {
           latency == other.latency &&
           uploadBandwidth == other.uploadBandwidth &&
           downloadBandwidth == other.downloadBandwidth &&
-          loss == other.loss;
+          loss == other.loss &&
+          jitterBuffer == other.jitterBuffer &&
+          jitter == other.jitter &&
+          gain == other.gain &&
+          speaking == other.speaking &&
+          captureDelay == other.captureDelay &&
+          processingDelay == other.processingDelay &&
+          networkDelay == other.networkDelay &&
+          playoutDelay == other.playoutDelay &&
+          oneWayDelay == other.oneWayDelay;
 }
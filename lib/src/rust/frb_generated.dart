@@ -72,7 +72,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.10.0';
 
   @override
-  int get rustContentHash => 617117851;
+  int get rustContentHash => 984467050;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -108,12 +108,17 @@ abstract class RustLibApi extends BaseApi {
 
   String crateApiTelepathyChatMessageTime({required ChatMessage that});
 
+  bool crateApiTelepathyCodecConfigFec({required CodecConfig that});
+
   CodecConfig crateApiTelepathyCodecConfigNew(
       {required bool enabled, required bool vbr, required double residualBits});
 
   void crateApiTelepathyCodecConfigSetEnabled(
       {required CodecConfig that, required bool enabled});
 
+  void crateApiTelepathyCodecConfigSetFec(
+      {required CodecConfig that, required bool fec});
+
   void crateApiTelepathyCodecConfigSetResidualBits(
       {required CodecConfig that, required double residualBits});
 
@@ -259,9 +264,14 @@ abstract class RustLibApi extends BaseApi {
 
   void crateApiTelepathyTelepathyEndCall({required Telepathy that});
 
+  bool crateApiTelepathyTelepathyIsRecording({required Telepathy that});
+
   Future<void> crateApiTelepathyTelepathyJoinRoom(
       {required Telepathy that, required List<String> memberStrings});
 
+  Future<(List<DeviceInfo>, List<DeviceInfo>)>
+      crateApiTelepathyTelepathyListDeviceInfo({required Telepathy that});
+
   Future<(List<String>, List<String>)> crateApiTelepathyTelepathyListDevices(
       {required Telepathy that});
 
@@ -284,18 +294,50 @@ abstract class RustLibApi extends BaseApi {
       required FutureOr<void> Function(bool, bool) managerActive,
       required FutureOr<void> Function(DartNotify, bool) screenshareStarted});
 
+  (AudioConfig?, AudioConfig?) crateApiTelepathyTelepathyOpenedAudioConfigs(
+      {required Telepathy that});
+
   Future<void> crateApiTelepathyTelepathyRestartManager(
       {required Telepathy that});
 
   Future<void> crateApiTelepathyTelepathySendChat(
       {required Telepathy that, required ChatMessage message});
 
+  void crateApiTelepathyTelepathySetAgc(
+      {required Telepathy that, required bool enabled});
+
+  void crateApiTelepathyTelepathySetAgcAttack(
+      {required Telepathy that, required double milliseconds});
+
+  void crateApiTelepathyTelepathySetAgcMaxGain(
+      {required Telepathy that, required double decibel});
+
+  void crateApiTelepathyTelepathySetAgcRelease(
+      {required Telepathy that, required double milliseconds});
+
+  void crateApiTelepathyTelepathySetAgcTargetLevel(
+      {required Telepathy that, required double decibel});
+
+  void crateApiTelepathyTelepathySetBufferSize(
+      {required Telepathy that, int? frames});
+
+  void crateApiTelepathyTelepathySetComfortNoise(
+      {required Telepathy that, required bool comfortNoise});
+
   void crateApiTelepathyTelepathySetDeafened(
       {required Telepathy that, required bool deafened});
 
   void crateApiTelepathyTelepathySetDenoise(
       {required Telepathy that, required bool denoise});
 
+  Future<void> crateApiTelepathyTelepathySetDevicesChanged(
+      {required Telepathy that,
+      required FutureOr<void> Function(List<DeviceInfo>, List<DeviceInfo>)
+          devicesChanged});
+
+  void crateApiTelepathyTelepathySetEchoCancellation(
+      {required Telepathy that, required bool echoCancellation});
+
   void crateApiTelepathyTelepathySetEfficiencyMode(
       {required Telepathy that, required bool enabled});
 
@@ -323,21 +365,49 @@ abstract class RustLibApi extends BaseApi {
   void crateApiTelepathyTelepathySetPlayCustomRingtones(
       {required Telepathy that, required bool play});
 
+  void crateApiTelepathyTelepathySetPreferredChannels(
+      {required Telepathy that, int? channels});
+
+  void crateApiTelepathyTelepathySetPreferredSampleRate(
+      {required Telepathy that, int? sampleRate});
+
+  Future<void> crateApiTelepathyTelepathySetRecordingChanged(
+      {required Telepathy that,
+      required FutureOr<void> Function(String, bool) recordingChanged});
+
   void crateApiTelepathyTelepathySetRmsThreshold(
       {required Telepathy that, required double decimal});
 
   void crateApiTelepathyTelepathySetSendCustomRingtone(
       {required Telepathy that, required bool send});
 
+  void crateApiTelepathyTelepathySetSilenceDetection(
+      {required Telepathy that, required String mode});
+
+  void crateApiTelepathyTelepathySetStereo(
+      {required Telepathy that, required bool stereo});
+
+  void crateApiTelepathyTelepathySetVadHangover(
+      {required Telepathy that, required int milliseconds});
+
+  void crateApiTelepathyTelepathySetVadThreshold(
+      {required Telepathy that, required double probability});
+
   Future<void> crateApiTelepathyTelepathyStartCall(
       {required Telepathy that, required Contact contact});
 
+  Future<void> crateApiTelepathyTelepathyStartRecording(
+      {required Telepathy that, required String path, required String format});
+
   Future<void> crateApiTelepathyTelepathyStartScreenshare(
       {required Telepathy that, required Contact contact});
 
   Future<void> crateApiTelepathyTelepathyStartSession(
       {required Telepathy that, required Contact contact});
 
+  Future<void> crateApiTelepathyTelepathyStopRecording(
+      {required Telepathy that});
+
   Future<void> crateApiTelepathyTelepathyStopSession(
       {required Telepathy that, required Contact contact});
 
@@ -699,6 +769,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that"],
       );
 
+  @override
+  bool crateApiTelepathyCodecConfigFec({required CodecConfig that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCodecConfig(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTelepathyCodecConfigFecConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyCodecConfigFecConstMeta =>
+      const TaskConstMeta(
+        debugName: "CodecConfig_fec",
+        argNames: ["that"],
+      );
+
   @override
   CodecConfig crateApiTelepathyCodecConfigNew(
       {required bool enabled,
@@ -710,7 +805,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_bool(enabled, serializer);
         sse_encode_bool(vbr, serializer);
         sse_encode_f_32(residualBits, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -738,7 +833,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCodecConfig(
             that, serializer);
         sse_encode_bool(enabled, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -756,6 +851,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "enabled"],
       );
 
+  @override
+  void crateApiTelepathyCodecConfigSetFec(
+      {required CodecConfig that, required bool fec}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCodecConfig(
+            that, serializer);
+        sse_encode_bool(fec, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTelepathyCodecConfigSetFecConstMeta,
+      argValues: [that, fec],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyCodecConfigSetFecConstMeta =>
+      const TaskConstMeta(
+        debugName: "CodecConfig_set_fec",
+        argNames: ["that", "fec"],
+      );
+
   @override
   void crateApiTelepathyCodecConfigSetResidualBits(
       {required CodecConfig that, required double residualBits}) {
@@ -765,7 +887,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCodecConfig(
             that, serializer);
         sse_encode_f_32(residualBits, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -792,7 +914,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCodecConfig(
             that, serializer);
         sse_encode_bool(vbr, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -818,7 +940,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCodecConfig(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_record_bool_bool_f_32,
@@ -845,7 +967,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(id, serializer);
         sse_encode_String(nickname, serializer);
         sse_encode_String(peerId, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -871,7 +993,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
@@ -897,7 +1019,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             that, serializer);
         sse_encode_list_prim_u_8_loose(id, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_bool,
@@ -922,7 +1044,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(nickname, serializer);
         sse_encode_String(peerId, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -947,7 +1069,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
@@ -972,7 +1094,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
@@ -997,7 +1119,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -1025,7 +1147,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             that, serializer);
         sse_encode_String(nickname, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1051,7 +1173,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartNotify(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 23, port: port_);
+            funcId: 25, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1076,7 +1198,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartNotify(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1103,7 +1225,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkConfig(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 25, port: port_);
+            funcId: 27, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
@@ -1130,7 +1252,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkConfig(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 26, port: port_);
+            funcId: 28, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
@@ -1156,7 +1278,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(relayAddress, serializer);
         sse_encode_String(relayId, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -1185,7 +1307,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_String(relayAddress, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 28, port: port_);
+            funcId: 30, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1213,7 +1335,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_String(relayId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 29, port: port_);
+            funcId: 31, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1239,7 +1361,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOverlay(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 30, port: port_);
+            funcId: 32, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1265,7 +1387,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOverlay(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 31, port: port_);
+            funcId: 33, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1291,7 +1413,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOverlay(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 32, port: port_);
+            funcId: 34, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1326,7 +1448,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_i_32(width, serializer);
         sse_encode_i_32(height, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 33, port: port_);
+            funcId: 35, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1366,7 +1488,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_u_32(backgroundColor, serializer);
         sse_encode_u_32(fontColor, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 34, port: port_);
+            funcId: 36, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -1411,7 +1533,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOverlay(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_record_i_32_i_32,
@@ -1439,7 +1561,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_u_32(backgroundColor, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 36, port: port_);
+            funcId: 38, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1467,7 +1589,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_u_32(fontColor, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 37, port: port_);
+            funcId: 39, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1495,7 +1617,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_i_32(height, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 38, port: port_);
+            funcId: 40, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1521,7 +1643,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOverlay(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 39, port: port_);
+            funcId: 41, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1546,7 +1668,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_u_32,
@@ -1572,7 +1694,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
@@ -1598,7 +1720,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
@@ -1624,7 +1746,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_u_32,
@@ -1649,7 +1771,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
@@ -1676,7 +1798,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScreenshareConfig(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 45, port: port_);
+            funcId: 47, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -1701,7 +1823,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 46, port: port_);
+            funcId: 48, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -1728,7 +1850,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(configStr, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 47, port: port_);
+            funcId: 49, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -1756,7 +1878,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScreenshareConfig(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 48, port: port_);
+            funcId: 50, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -1784,7 +1906,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScreenshareConfig(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
@@ -1821,7 +1943,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_u_32(framerate, serializer);
         sse_encode_opt_box_autoadd_u_32(height, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 50, port: port_);
+            funcId: 52, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1855,7 +1977,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSoundHandle(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1880,7 +2002,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSoundPlayer(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -1905,7 +2027,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_f_32(outputVolume, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -1934,7 +2056,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_list_prim_u_8_loose(bytes, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 54, port: port_);
+            funcId: 56, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -1963,7 +2085,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_opt_String(name, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 55, port: port_);
+            funcId: 57, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -1990,7 +2112,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSoundPlayer(
             that, serializer);
         sse_encode_f_32(volume, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2016,7 +2138,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 57, port: port_);
+            funcId: 59, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2050,7 +2172,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(text, serializer);
         sse_encode_list_record_string_list_prim_u_8_strict(
             attachments, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -2076,7 +2198,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2094,6 +2216,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that"],
       );
 
+  @override
+  bool crateApiTelepathyTelepathyIsRecording({required Telepathy that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTelepathyTelepathyIsRecordingConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyTelepathyIsRecordingConstMeta =>
+      const TaskConstMeta(
+        debugName: "Telepathy_is_recording",
+        argNames: ["that"],
+      );
+
   @override
   Future<void> crateApiTelepathyTelepathyJoinRoom(
       {required Telepathy that, required List<String> memberStrings}) {
@@ -2104,7 +2251,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_list_String(memberStrings, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 60, port: port_);
+            funcId: 63, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2122,6 +2269,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "memberStrings"],
       );
 
+  @override
+  Future<(List<DeviceInfo>, List<DeviceInfo>)>
+      crateApiTelepathyTelepathyListDeviceInfo({required Telepathy that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 64, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_record_list_device_info_list_device_info,
+        decodeErrorData: sse_decode_dart_error,
+      ),
+      constMeta: kCrateApiTelepathyTelepathyListDeviceInfoConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyTelepathyListDeviceInfoConstMeta =>
+      const TaskConstMeta(
+        debugName: "Telepathy_list_device_info",
+        argNames: ["that"],
+      );
+
   @override
   Future<(List<String>, List<String>)> crateApiTelepathyTelepathyListDevices(
       {required Telepathy that}) {
@@ -2131,7 +2305,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 61, port: port_);
+            funcId: 65, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_record_list_string_list_string,
@@ -2203,7 +2377,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_DartFn_Inputs_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartNotify_bool_Output_unit_AnyhowException(
             screenshareStarted, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 62, port: port_);
+            funcId: 66, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData:
@@ -2256,6 +2430,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         ],
       );
 
+  @override
+  (AudioConfig?, AudioConfig?) crateApiTelepathyTelepathyOpenedAudioConfigs(
+      {required Telepathy that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData:
+            sse_decode_record_opt_box_autoadd_audio_config_opt_box_autoadd_audio_config,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTelepathyTelepathyOpenedAudioConfigsConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyTelepathyOpenedAudioConfigsConstMeta =>
+      const TaskConstMeta(
+        debugName: "Telepathy_opened_audio_configs",
+        argNames: ["that"],
+      );
+
   @override
   Future<void> crateApiTelepathyTelepathyRestartManager(
       {required Telepathy that}) {
@@ -2265,7 +2466,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 63, port: port_);
+            funcId: 68, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2294,7 +2495,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerChatMessage(
             message, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 64, port: port_);
+            funcId: 69, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2313,116 +2514,363 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  void crateApiTelepathyTelepathySetDeafened(
-      {required Telepathy that, required bool deafened}) {
+  void crateApiTelepathyTelepathySetAgc(
+      {required Telepathy that, required bool enabled}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
-        sse_encode_bool(deafened, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
+        sse_encode_bool(enabled, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiTelepathyTelepathySetDeafenedConstMeta,
-      argValues: [that, deafened],
+      constMeta: kCrateApiTelepathyTelepathySetAgcConstMeta,
+      argValues: [that, enabled],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiTelepathyTelepathySetDeafenedConstMeta =>
+  TaskConstMeta get kCrateApiTelepathyTelepathySetAgcConstMeta =>
       const TaskConstMeta(
-        debugName: "Telepathy_set_deafened",
-        argNames: ["that", "deafened"],
+        debugName: "Telepathy_set_agc",
+        argNames: ["that", "enabled"],
       );
 
   @override
-  void crateApiTelepathyTelepathySetDenoise(
-      {required Telepathy that, required bool denoise}) {
+  void crateApiTelepathyTelepathySetAgcAttack(
+      {required Telepathy that, required double milliseconds}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
-        sse_encode_bool(denoise, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+        sse_encode_f_32(milliseconds, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiTelepathyTelepathySetDenoiseConstMeta,
-      argValues: [that, denoise],
+      constMeta: kCrateApiTelepathyTelepathySetAgcAttackConstMeta,
+      argValues: [that, milliseconds],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiTelepathyTelepathySetDenoiseConstMeta =>
+  TaskConstMeta get kCrateApiTelepathyTelepathySetAgcAttackConstMeta =>
       const TaskConstMeta(
-        debugName: "Telepathy_set_denoise",
-        argNames: ["that", "denoise"],
+        debugName: "Telepathy_set_agc_attack",
+        argNames: ["that", "milliseconds"],
       );
 
   @override
-  void crateApiTelepathyTelepathySetEfficiencyMode(
-      {required Telepathy that, required bool enabled}) {
+  void crateApiTelepathyTelepathySetAgcMaxGain(
+      {required Telepathy that, required double decibel}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
-        sse_encode_bool(enabled, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+        sse_encode_f_32(decibel, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiTelepathyTelepathySetEfficiencyModeConstMeta,
-      argValues: [that, enabled],
+      constMeta: kCrateApiTelepathyTelepathySetAgcMaxGainConstMeta,
+      argValues: [that, decibel],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiTelepathyTelepathySetEfficiencyModeConstMeta =>
+  TaskConstMeta get kCrateApiTelepathyTelepathySetAgcMaxGainConstMeta =>
       const TaskConstMeta(
-        debugName: "Telepathy_set_efficiency_mode",
-        argNames: ["that", "enabled"],
+        debugName: "Telepathy_set_agc_max_gain",
+        argNames: ["that", "decibel"],
       );
 
   @override
-  Future<void> crateApiTelepathyTelepathySetIdentity(
-      {required Telepathy that, required List<int> key}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  void crateApiTelepathyTelepathySetAgcRelease(
+      {required Telepathy that, required double milliseconds}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
-        sse_encode_list_prim_u_8_loose(key, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 68, port: port_);
+        sse_encode_f_32(milliseconds, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_dart_error,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiTelepathyTelepathySetIdentityConstMeta,
-      argValues: [that, key],
+      constMeta: kCrateApiTelepathyTelepathySetAgcReleaseConstMeta,
+      argValues: [that, milliseconds],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiTelepathyTelepathySetIdentityConstMeta =>
+  TaskConstMeta get kCrateApiTelepathyTelepathySetAgcReleaseConstMeta =>
       const TaskConstMeta(
-        debugName: "Telepathy_set_identity",
-        argNames: ["that", "key"],
+        debugName: "Telepathy_set_agc_release",
+        argNames: ["that", "milliseconds"],
       );
 
   @override
-  Future<void> crateApiTelepathyTelepathySetInputDevice(
+  void crateApiTelepathyTelepathySetAgcTargetLevel(
+      {required Telepathy that, required double decibel}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_f_32(decibel, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTelepathyTelepathySetAgcTargetLevelConstMeta,
+      argValues: [that, decibel],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyTelepathySetAgcTargetLevelConstMeta =>
+      const TaskConstMeta(
+        debugName: "Telepathy_set_agc_target_level",
+        argNames: ["that", "decibel"],
+      );
+
+  @override
+  void crateApiTelepathyTelepathySetBufferSize(
+      {required Telepathy that, int? frames}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_opt_box_autoadd_u_32(frames, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTelepathyTelepathySetBufferSizeConstMeta,
+      argValues: [that, frames],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyTelepathySetBufferSizeConstMeta =>
+      const TaskConstMeta(
+        debugName: "Telepathy_set_buffer_size",
+        argNames: ["that", "frames"],
+      );
+
+  @override
+  void crateApiTelepathyTelepathySetComfortNoise(
+      {required Telepathy that, required bool comfortNoise}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_bool(comfortNoise, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTelepathyTelepathySetComfortNoiseConstMeta,
+      argValues: [that, comfortNoise],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyTelepathySetComfortNoiseConstMeta =>
+      const TaskConstMeta(
+        debugName: "Telepathy_set_comfort_noise",
+        argNames: ["that", "comfortNoise"],
+      );
+
+  @override
+  void crateApiTelepathyTelepathySetDeafened(
+      {required Telepathy that, required bool deafened}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_bool(deafened, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTelepathyTelepathySetDeafenedConstMeta,
+      argValues: [that, deafened],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyTelepathySetDeafenedConstMeta =>
+      const TaskConstMeta(
+        debugName: "Telepathy_set_deafened",
+        argNames: ["that", "deafened"],
+      );
+
+  @override
+  void crateApiTelepathyTelepathySetDenoise(
+      {required Telepathy that, required bool denoise}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_bool(denoise, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTelepathyTelepathySetDenoiseConstMeta,
+      argValues: [that, denoise],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyTelepathySetDenoiseConstMeta =>
+      const TaskConstMeta(
+        debugName: "Telepathy_set_denoise",
+        argNames: ["that", "denoise"],
+      );
+
+  @override
+  Future<void> crateApiTelepathyTelepathySetDevicesChanged(
+      {required Telepathy that,
+      required FutureOr<void> Function(List<DeviceInfo>, List<DeviceInfo>)
+          devicesChanged}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_DartFn_Inputs_list_device_info_list_device_info_Output_unit_AnyhowException(
+            devicesChanged, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 79, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTelepathyTelepathySetDevicesChangedConstMeta,
+      argValues: [that, devicesChanged],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyTelepathySetDevicesChangedConstMeta =>
+      const TaskConstMeta(
+        debugName: "Telepathy_set_devices_changed",
+        argNames: ["that", "devicesChanged"],
+      );
+
+  @override
+  void crateApiTelepathyTelepathySetEchoCancellation(
+      {required Telepathy that, required bool echoCancellation}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_bool(echoCancellation, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTelepathyTelepathySetEchoCancellationConstMeta,
+      argValues: [that, echoCancellation],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyTelepathySetEchoCancellationConstMeta =>
+      const TaskConstMeta(
+        debugName: "Telepathy_set_echo_cancellation",
+        argNames: ["that", "echoCancellation"],
+      );
+
+  @override
+  void crateApiTelepathyTelepathySetEfficiencyMode(
+      {required Telepathy that, required bool enabled}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_bool(enabled, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTelepathyTelepathySetEfficiencyModeConstMeta,
+      argValues: [that, enabled],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyTelepathySetEfficiencyModeConstMeta =>
+      const TaskConstMeta(
+        debugName: "Telepathy_set_efficiency_mode",
+        argNames: ["that", "enabled"],
+      );
+
+  @override
+  Future<void> crateApiTelepathyTelepathySetIdentity(
+      {required Telepathy that, required List<int> key}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 82, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_dart_error,
+      ),
+      constMeta: kCrateApiTelepathyTelepathySetIdentityConstMeta,
+      argValues: [that, key],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyTelepathySetIdentityConstMeta =>
+      const TaskConstMeta(
+        debugName: "Telepathy_set_identity",
+        argNames: ["that", "key"],
+      );
+
+  @override
+  Future<void> crateApiTelepathyTelepathySetInputDevice(
       {required Telepathy that, String? device}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
@@ -2431,7 +2879,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_opt_String(device, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 69, port: port_);
+            funcId: 83, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2458,7 +2906,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         sse_encode_f_32(decibel, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2486,7 +2934,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_opt_list_prim_u_8_strict(model, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 71, port: port_);
+            funcId: 85, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2513,7 +2961,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         sse_encode_bool(muted, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2541,7 +2989,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_opt_String(device, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 73, port: port_);
+            funcId: 87, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2568,7 +3016,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         sse_encode_f_32(decibel, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2595,7 +3043,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         sse_encode_bool(play, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2614,6 +3062,91 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["that", "play"],
           );
 
+  @override
+  void crateApiTelepathyTelepathySetPreferredChannels(
+      {required Telepathy that, int? channels}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_opt_box_autoadd_u_16(channels, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTelepathyTelepathySetPreferredChannelsConstMeta,
+      argValues: [that, channels],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyTelepathySetPreferredChannelsConstMeta =>
+      const TaskConstMeta(
+        debugName: "Telepathy_set_preferred_channels",
+        argNames: ["that", "channels"],
+      );
+
+  @override
+  void crateApiTelepathyTelepathySetPreferredSampleRate(
+      {required Telepathy that, int? sampleRate}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_opt_box_autoadd_u_32(sampleRate, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTelepathyTelepathySetPreferredSampleRateConstMeta,
+      argValues: [that, sampleRate],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiTelepathyTelepathySetPreferredSampleRateConstMeta =>
+          const TaskConstMeta(
+            debugName: "Telepathy_set_preferred_sample_rate",
+            argNames: ["that", "sampleRate"],
+          );
+
+  @override
+  Future<void> crateApiTelepathyTelepathySetRecordingChanged(
+      {required Telepathy that,
+      required FutureOr<void> Function(String, bool) recordingChanged}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_DartFn_Inputs_String_bool_Output_unit_AnyhowException(
+            recordingChanged, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 92, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTelepathyTelepathySetRecordingChangedConstMeta,
+      argValues: [that, recordingChanged],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyTelepathySetRecordingChangedConstMeta =>
+      const TaskConstMeta(
+        debugName: "Telepathy_set_recording_changed",
+        argNames: ["that", "recordingChanged"],
+      );
+
   @override
   void crateApiTelepathyTelepathySetRmsThreshold(
       {required Telepathy that, required double decimal}) {
@@ -2623,7 +3156,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         sse_encode_f_32(decimal, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2650,7 +3183,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
             that, serializer);
         sse_encode_bool(send, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2668,6 +3201,114 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "send"],
       );
 
+  @override
+  void crateApiTelepathyTelepathySetSilenceDetection(
+      {required Telepathy that, required String mode}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_String(mode, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_dart_error,
+      ),
+      constMeta: kCrateApiTelepathyTelepathySetSilenceDetectionConstMeta,
+      argValues: [that, mode],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyTelepathySetSilenceDetectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "Telepathy_set_silence_detection",
+        argNames: ["that", "mode"],
+      );
+
+  @override
+  void crateApiTelepathyTelepathySetStereo(
+      {required Telepathy that, required bool stereo}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_bool(stereo, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTelepathyTelepathySetStereoConstMeta,
+      argValues: [that, stereo],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyTelepathySetStereoConstMeta =>
+      const TaskConstMeta(
+        debugName: "Telepathy_set_stereo",
+        argNames: ["that", "stereo"],
+      );
+
+  @override
+  void crateApiTelepathyTelepathySetVadHangover(
+      {required Telepathy that, required int milliseconds}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_u_32(milliseconds, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTelepathyTelepathySetVadHangoverConstMeta,
+      argValues: [that, milliseconds],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyTelepathySetVadHangoverConstMeta =>
+      const TaskConstMeta(
+        debugName: "Telepathy_set_vad_hangover",
+        argNames: ["that", "milliseconds"],
+      );
+
+  @override
+  void crateApiTelepathyTelepathySetVadThreshold(
+      {required Telepathy that, required double probability}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_f_32(probability, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTelepathyTelepathySetVadThresholdConstMeta,
+      argValues: [that, probability],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyTelepathySetVadThresholdConstMeta =>
+      const TaskConstMeta(
+        debugName: "Telepathy_set_vad_threshold",
+        argNames: ["that", "probability"],
+      );
+
   @override
   Future<void> crateApiTelepathyTelepathyStartCall(
       {required Telepathy that, required Contact contact}) {
@@ -2679,7 +3320,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             contact, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 78, port: port_);
+            funcId: 99, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2697,6 +3338,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "contact"],
       );
 
+  @override
+  Future<void> crateApiTelepathyTelepathyStartRecording(
+      {required Telepathy that, required String path, required String format}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        sse_encode_String(path, serializer);
+        sse_encode_String(format, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 100, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_dart_error,
+      ),
+      constMeta: kCrateApiTelepathyTelepathyStartRecordingConstMeta,
+      argValues: [that, path, format],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyTelepathyStartRecordingConstMeta =>
+      const TaskConstMeta(
+        debugName: "Telepathy_start_recording",
+        argNames: ["that", "path", "format"],
+      );
+
   @override
   Future<void> crateApiTelepathyTelepathyStartScreenshare(
       {required Telepathy that, required Contact contact}) {
@@ -2708,7 +3378,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             contact, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 79, port: port_);
+            funcId: 101, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2737,7 +3407,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             contact, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 80, port: port_);
+            funcId: 102, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2755,6 +3425,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "contact"],
       );
 
+  @override
+  Future<void> crateApiTelepathyTelepathyStopRecording(
+      {required Telepathy that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTelepathy(
+            that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 103, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTelepathyTelepathyStopRecordingConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTelepathyTelepathyStopRecordingConstMeta =>
+      const TaskConstMeta(
+        debugName: "Telepathy_stop_recording",
+        argNames: ["that"],
+      );
+
   @override
   Future<void> crateApiTelepathyTelepathyStopSession(
       {required Telepathy that, required Contact contact}) {
@@ -2766,7 +3463,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact(
             contact, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 81, port: port_);
+            funcId: 104, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2791,7 +3488,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_StreamSink_String_Sse(s, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2815,7 +3512,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_record_string_list_prim_u_8_strict,
@@ -2838,7 +3535,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 84, port: port_);
+            funcId: 107, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2862,7 +3559,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(path, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 85, port: port_);
+            funcId: 108, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2884,7 +3581,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2909,7 +3606,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_StreamSink_String_Sse(streamSink, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 87, port: port_);
+            funcId: 110, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -2934,7 +3631,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 88, port: port_);
+            funcId: 111, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_statistics,
@@ -3172,7 +3869,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       Box<void>? rawOutput;
       Box<AnyhowException>? rawError;
       try {
-        rawOutput = Box(await raw(arg0));
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+          callId: callId,
+          ptr: output.ptr,
+          rustVecLen: output.rustVecLen,
+          dataLen: output.dataLen);
+    };
+  }
+
+  Future<void> Function(int, dynamic, dynamic)
+      encode_DartFn_Inputs_bool_bool_Output_unit_AnyhowException(
+          FutureOr<void> Function(bool, bool) raw) {
+    return (callId, rawArg0, rawArg1) async {
+      final arg0 = dco_decode_bool(rawArg0);
+      final arg1 = dco_decode_bool(rawArg1);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0, arg1));
       } catch (e, s) {
         rawError = Box(AnyhowException("$e\n\n$s"));
       }
@@ -3197,11 +3928,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   Future<void> Function(int, dynamic, dynamic)
-      encode_DartFn_Inputs_bool_bool_Output_unit_AnyhowException(
-          FutureOr<void> Function(bool, bool) raw) {
+      encode_DartFn_Inputs_list_device_info_list_device_info_Output_unit_AnyhowException(
+          FutureOr<void> Function(List<DeviceInfo>, List<DeviceInfo>) raw) {
     return (callId, rawArg0, rawArg1) async {
-      final arg0 = dco_decode_bool(rawArg0);
-      final arg1 = dco_decode_bool(rawArg1);
+      final arg0 = dco_decode_list_device_info(rawArg0);
+      final arg1 = dco_decode_list_device_info(rawArg1);
 
       Box<void>? rawOutput;
       Box<AnyhowException>? rawError;
@@ -3686,6 +4417,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('');
   }
 
+  @protected
+  FutureOr<void> Function(List<DeviceInfo>, List<DeviceInfo>)
+      dco_decode_DartFn_Inputs_list_device_info_list_device_info_Output_unit_AnyhowException(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
   @protected
   FutureOr<Contact?> Function(Uint8List)
       dco_decode_DartFn_Inputs_list_prim_u_8_strict_Output_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact_AnyhowException(
@@ -3824,6 +4563,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as String;
   }
 
+  @protected
+  AudioConfig dco_decode_audio_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AudioConfig(
+      device: dco_decode_String(arr[0]),
+      sampleRate: dco_decode_u_32(arr[1]),
+      channels: dco_decode_u_16(arr[2]),
+      bufferSize: dco_decode_opt_box_autoadd_u_32(arr[3]),
+    );
+  }
+
   @protected
   bool dco_decode_bool(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3848,6 +4601,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         raw);
   }
 
+  @protected
+  AudioConfig dco_decode_box_autoadd_audio_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_audio_config(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3865,6 +4630,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  DeviceInfo dco_decode_device_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return DeviceInfo(
+      id: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      isDefault: dco_decode_bool(arr[2]),
+      sampleRates: dco_decode_list_prim_u_32_strict(arr[3]),
+      channels: dco_decode_list_prim_u_16_strict(arr[4]),
+      minBufferSize: dco_decode_opt_box_autoadd_u_32(arr[5]),
+      maxBufferSize: dco_decode_opt_box_autoadd_u_32(arr[6]),
+    );
+  }
+
   @protected
   double dco_decode_f_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3895,6 +4677,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_String).toList();
   }
 
+  @protected
+  List<DeviceInfo> dco_decode_list_device_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_device_info).toList();
+  }
+
+  @protected
+  Uint16List dco_decode_list_prim_u_16_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint16List;
+  }
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint32List;
+  }
+
   @protected
   List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3944,6 +4744,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             raw);
   }
 
+  @protected
+  AudioConfig? dco_decode_opt_box_autoadd_audio_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_audio_config(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_16(raw);
+  }
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3983,6 +4795,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  (List<DeviceInfo>, List<DeviceInfo>)
+      dco_decode_record_list_device_info_list_device_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_list_device_info(arr[0]),
+      dco_decode_list_device_info(arr[1]),
+    );
+  }
+
   @protected
   (List<String>, List<String>) dco_decode_record_list_string_list_string(
       dynamic raw) {
@@ -3997,6 +4823,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  (AudioConfig?, AudioConfig?)
+      dco_decode_record_opt_box_autoadd_audio_config_opt_box_autoadd_audio_config(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_opt_box_autoadd_audio_config(arr[0]),
+      dco_decode_opt_box_autoadd_audio_config(arr[1]),
+    );
+  }
+
   @protected
   (String, Uint8List) dco_decode_record_string_list_prim_u_8_strict(
       dynamic raw) {
@@ -4024,8 +4865,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   Statistics dco_decode_statistics(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    if (arr.length != 15)
+      throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
     return Statistics(
       inputLevel: dco_decode_f_32(arr[0]),
       outputLevel: dco_decode_f_32(arr[1]),
@@ -4033,9 +4874,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       uploadBandwidth: dco_decode_usize(arr[3]),
       downloadBandwidth: dco_decode_usize(arr[4]),
       loss: dco_decode_f_64(arr[5]),
+      jitterBuffer: dco_decode_usize(arr[6]),
+      jitter: dco_decode_f_64(arr[7]),
+      gain: dco_decode_f_32(arr[8]),
+      speaking: dco_decode_bool(arr[9]),
+      captureDelay: dco_decode_usize(arr[10]),
+      processingDelay: dco_decode_usize(arr[11]),
+      networkDelay: dco_decode_usize(arr[12]),
+      playoutDelay: dco_decode_usize(arr[13]),
+      oneWayDelay: dco_decode_usize(arr[14]),
     );
   }
 
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
   @protected
   int dco_decode_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4448,6 +5304,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return utf8.decoder.convert(inner);
   }
 
+  @protected
+  AudioConfig sse_decode_audio_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_device = sse_decode_String(deserializer);
+    var var_sampleRate = sse_decode_u_32(deserializer);
+    var var_channels = sse_decode_u_16(deserializer);
+    var var_bufferSize = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return AudioConfig(
+        device: var_device,
+        sampleRate: var_sampleRate,
+        channels: var_channels,
+        bufferSize: var_bufferSize);
+  }
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4472,6 +5342,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         deserializer));
   }
 
+  @protected
+  AudioConfig sse_decode_box_autoadd_audio_config(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_audio_config(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_16(deserializer));
+  }
+
   @protected
   int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4485,6 +5368,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return DartError(message: var_message);
   }
 
+  @protected
+  DeviceInfo sse_decode_device_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_isDefault = sse_decode_bool(deserializer);
+    var var_sampleRates = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_channels = sse_decode_list_prim_u_16_strict(deserializer);
+    var var_minBufferSize = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_maxBufferSize = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return DeviceInfo(
+        id: var_id,
+        name: var_name,
+        isDefault: var_isDefault,
+        sampleRates: var_sampleRates,
+        channels: var_channels,
+        minBufferSize: var_minBufferSize,
+        maxBufferSize: var_maxBufferSize);
+  }
+
   @protected
   double sse_decode_f_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4521,6 +5424,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<DeviceInfo> sse_decode_list_device_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DeviceInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_device_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint16List sse_decode_list_prim_u_16_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint16List(len_);
+  }
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
   @protected
   List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4587,6 +5516,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  AudioConfig? sse_decode_opt_box_autoadd_audio_config(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_audio_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_16(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4627,6 +5579,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (var_field0, var_field1);
   }
 
+  @protected
+  (List<DeviceInfo>, List<DeviceInfo>)
+      sse_decode_record_list_device_info_list_device_info(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_list_device_info(deserializer);
+    var var_field1 = sse_decode_list_device_info(deserializer);
+    return (var_field0, var_field1);
+  }
+
   @protected
   (List<String>, List<String>) sse_decode_record_list_string_list_string(
       SseDeserializer deserializer) {
@@ -4636,6 +5598,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (var_field0, var_field1);
   }
 
+  @protected
+  (AudioConfig?, AudioConfig?)
+      sse_decode_record_opt_box_autoadd_audio_config_opt_box_autoadd_audio_config(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_opt_box_autoadd_audio_config(deserializer);
+    var var_field1 = sse_decode_opt_box_autoadd_audio_config(deserializer);
+    return (var_field0, var_field1);
+  }
+
   @protected
   (String, Uint8List) sse_decode_record_string_list_prim_u_8_strict(
       SseDeserializer deserializer) {
@@ -4661,13 +5633,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_uploadBandwidth = sse_decode_usize(deserializer);
     var var_downloadBandwidth = sse_decode_usize(deserializer);
     var var_loss = sse_decode_f_64(deserializer);
+    var var_jitterBuffer = sse_decode_usize(deserializer);
+    var var_jitter = sse_decode_f_64(deserializer);
+    var var_gain = sse_decode_f_32(deserializer);
+    var var_speaking = sse_decode_bool(deserializer);
+    var var_captureDelay = sse_decode_usize(deserializer);
+    var var_processingDelay = sse_decode_usize(deserializer);
+    var var_networkDelay = sse_decode_usize(deserializer);
+    var var_playoutDelay = sse_decode_usize(deserializer);
+    var var_oneWayDelay = sse_decode_usize(deserializer);
     return Statistics(
         inputLevel: var_inputLevel,
         outputLevel: var_outputLevel,
         latency: var_latency,
         uploadBandwidth: var_uploadBandwidth,
         downloadBandwidth: var_downloadBandwidth,
-        loss: var_loss);
+        loss: var_loss,
+        jitterBuffer: var_jitterBuffer,
+        jitter: var_jitter,
+        gain: var_gain,
+        speaking: var_speaking,
+        captureDelay: var_captureDelay,
+        processingDelay: var_processingDelay,
+        networkDelay: var_networkDelay,
+        playoutDelay: var_playoutDelay,
+        oneWayDelay: var_oneWayDelay);
+  }
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
   }
 
   @protected
@@ -5038,6 +6034,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         serializer);
   }
 
+  @protected
+  void
+      sse_encode_DartFn_Inputs_list_device_info_list_device_info_Output_unit_AnyhowException(
+          FutureOr<void> Function(List<DeviceInfo>, List<DeviceInfo>) self,
+          SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+        encode_DartFn_Inputs_list_device_info_list_device_info_Output_unit_AnyhowException(
+            self),
+        serializer);
+  }
+
   @protected
   void
       sse_encode_DartFn_Inputs_list_prim_u_8_strict_Output_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact_AnyhowException(
@@ -5208,6 +6216,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
+  @protected
+  void sse_encode_audio_config(AudioConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.device, serializer);
+    sse_encode_u_32(self.sampleRate, serializer);
+    sse_encode_u_16(self.channels, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.bufferSize, serializer);
+  }
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5232,6 +6249,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_audio_config(
+      AudioConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_audio_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_16(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5244,6 +6274,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.message, serializer);
   }
 
+  @protected
+  void sse_encode_device_info(DeviceInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_bool(self.isDefault, serializer);
+    sse_encode_list_prim_u_32_strict(self.sampleRates, serializer);
+    sse_encode_list_prim_u_16_strict(self.channels, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.minBufferSize, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.maxBufferSize, serializer);
+  }
+
   @protected
   void sse_encode_f_32(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5277,6 +6319,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_device_info(
+      List<DeviceInfo> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_device_info(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_u_16_strict(
+      Uint16List self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint16List(self);
+  }
+
+  @protected
+  void sse_encode_list_prim_u_32_strict(
+      Uint32List self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(self);
+  }
+
   @protected
   void sse_encode_list_prim_u_8_loose(
       List<int> self, SseSerializer serializer) {
@@ -5340,6 +6408,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_audio_config(
+      AudioConfig? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_audio_config(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_16(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5377,6 +6466,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_32(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_record_list_device_info_list_device_info(
+      (List<DeviceInfo>, List<DeviceInfo>) self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_device_info(self.$1, serializer);
+    sse_encode_list_device_info(self.$2, serializer);
+  }
+
   @protected
   void sse_encode_record_list_string_list_string(
       (List<String>, List<String>) self, SseSerializer serializer) {
@@ -5385,6 +6482,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_String(self.$2, serializer);
   }
 
+  @protected
+  void
+      sse_encode_record_opt_box_autoadd_audio_config_opt_box_autoadd_audio_config(
+          (AudioConfig?, AudioConfig?) self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_audio_config(self.$1, serializer);
+    sse_encode_opt_box_autoadd_audio_config(self.$2, serializer);
+  }
+
   @protected
   void sse_encode_record_string_list_prim_u_8_strict(
       (String, Uint8List) self, SseSerializer serializer) {
@@ -5408,6 +6514,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_usize(self.uploadBandwidth, serializer);
     sse_encode_usize(self.downloadBandwidth, serializer);
     sse_encode_f_64(self.loss, serializer);
+    sse_encode_usize(self.jitterBuffer, serializer);
+    sse_encode_f_64(self.jitter, serializer);
+    sse_encode_f_32(self.gain, serializer);
+    sse_encode_bool(self.speaking, serializer);
+    sse_encode_usize(self.captureDelay, serializer);
+    sse_encode_usize(self.processingDelay, serializer);
+    sse_encode_usize(self.networkDelay, serializer);
+    sse_encode_usize(self.playoutDelay, serializer);
+    sse_encode_usize(self.oneWayDelay, serializer);
+  }
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint16(self);
   }
 
   @protected
@@ -5548,9 +6669,16 @@ class CodecConfigImpl extends RustOpaque implements CodecConfig {
         RustLib.instance.api.rust_arc_decrement_strong_count_CodecConfigPtr,
   );
 
+  bool fec() => RustLib.instance.api.crateApiTelepathyCodecConfigFec(
+        that: this,
+      );
+
   void setEnabled({required bool enabled}) => RustLib.instance.api
       .crateApiTelepathyCodecConfigSetEnabled(that: this, enabled: enabled);
 
+  void setFec({required bool fec}) => RustLib.instance.api
+      .crateApiTelepathyCodecConfigSetFec(that: this, fec: fec);
+
   void setResidualBits({required double residualBits}) =>
       RustLib.instance.api.crateApiTelepathyCodecConfigSetResidualBits(
           that: this, residualBits: residualBits);
@@ -5936,16 +7064,33 @@ class TelepathyImpl extends RustOpaque implements Telepathy {
         that: this,
       );
 
+  bool isRecording() =>
+      RustLib.instance.api.crateApiTelepathyTelepathyIsRecording(
+        that: this,
+      );
+
   Future<void> joinRoom({required List<String> memberStrings}) =>
       RustLib.instance.api.crateApiTelepathyTelepathyJoinRoom(
           that: this, memberStrings: memberStrings);
 
+  /// Lists the input and output devices with their identifiers and supported configurations
+  Future<(List<DeviceInfo>, List<DeviceInfo>)> listDeviceInfo() =>
+      RustLib.instance.api.crateApiTelepathyTelepathyListDeviceInfo(
+        that: this,
+      );
+
   /// Lists the input and output devices
   Future<(List<String>, List<String>)> listDevices() =>
       RustLib.instance.api.crateApiTelepathyTelepathyListDevices(
         that: this,
       );
 
+  /// The configurations the last input and output streams were opened with
+  (AudioConfig?, AudioConfig?) openedAudioConfigs() =>
+      RustLib.instance.api.crateApiTelepathyTelepathyOpenedAudioConfigs(
+        that: this,
+      );
+
   /// Restarts the session manager
   Future<void> restartManager() =>
       RustLib.instance.api.crateApiTelepathyTelepathyRestartManager(
@@ -5956,6 +7101,38 @@ class TelepathyImpl extends RustOpaque implements Telepathy {
   Future<void> sendChat({required ChatMessage message}) => RustLib.instance.api
       .crateApiTelepathyTelepathySendChat(that: this, message: message);
 
+  void setAgc({required bool enabled}) => RustLib.instance.api
+      .crateApiTelepathyTelepathySetAgc(that: this, enabled: enabled);
+
+  /// How quickly the gain is lowered when the input gets louder
+  void setAgcAttack({required double milliseconds}) =>
+      RustLib.instance.api.crateApiTelepathyTelepathySetAgcAttack(
+          that: this, milliseconds: milliseconds);
+
+  /// The most the automatic gain control amplifies quiet input by in decibels
+  void setAgcMaxGain({required double decibel}) => RustLib.instance.api
+      .crateApiTelepathyTelepathySetAgcMaxGain(that: this, decibel: decibel);
+
+  /// How quickly the gain is raised when the input gets quieter
+  void setAgcRelease({required double milliseconds}) =>
+      RustLib.instance.api.crateApiTelepathyTelepathySetAgcRelease(
+          that: this, milliseconds: milliseconds);
+
+  /// The level the automatic gain control brings speech to in dBFS
+  void setAgcTargetLevel({required double decibel}) =>
+      RustLib.instance.api.crateApiTelepathyTelepathySetAgcTargetLevel(
+          that: this, decibel: decibel);
+
+  /// A fixed buffer size in frames, lower sizes reduce latency at the risk of underruns.
+  /// None lets the host choose
+  void setBufferSize({int? frames}) => RustLib.instance.api
+      .crateApiTelepathyTelepathySetBufferSize(that: this, frames: frames);
+
+  /// Changing the comfort noise flag will not affect the current call
+  void setComfortNoise({required bool comfortNoise}) =>
+      RustLib.instance.api.crateApiTelepathyTelepathySetComfortNoise(
+          that: this, comfortNoise: comfortNoise);
+
   void setDeafened({required bool deafened}) => RustLib.instance.api
       .crateApiTelepathyTelepathySetDeafened(that: this, deafened: deafened);
 
@@ -5963,6 +7140,19 @@ class TelepathyImpl extends RustOpaque implements Telepathy {
   void setDenoise({required bool denoise}) => RustLib.instance.api
       .crateApiTelepathyTelepathySetDenoise(that: this, denoise: denoise);
 
+  /// Sets the callback which receives the devices when devices appear or disappear
+  Future<void> setDevicesChanged(
+          {required FutureOr<void> Function(
+                  List<DeviceInfo>, List<DeviceInfo>)
+              devicesChanged}) =>
+      RustLib.instance.api.crateApiTelepathyTelepathySetDevicesChanged(
+          that: this, devicesChanged: devicesChanged);
+
+  /// Changing the echo cancellation flag will not affect the current call
+  void setEchoCancellation({required bool echoCancellation}) =>
+      RustLib.instance.api.crateApiTelepathyTelepathySetEchoCancellation(
+          that: this, echoCancellation: echoCancellation);
+
   void setEfficiencyMode({required bool enabled}) =>
       RustLib.instance.api.crateApiTelepathyTelepathySetEfficiencyMode(
           that: this, enabled: enabled);
@@ -5992,16 +7182,56 @@ class TelepathyImpl extends RustOpaque implements Telepathy {
   void setPlayCustomRingtones({required bool play}) => RustLib.instance.api
       .crateApiTelepathyTelepathySetPlayCustomRingtones(that: this, play: play);
 
+  /// The channel count devices are opened with when they support it, None uses the default
+  void setPreferredChannels({int? channels}) =>
+      RustLib.instance.api.crateApiTelepathyTelepathySetPreferredChannels(
+          that: this, channels: channels);
+
+  /// The sample rate devices are opened with when they support it, None uses the default
+  void setPreferredSampleRate({int? sampleRate}) =>
+      RustLib.instance.api.crateApiTelepathyTelepathySetPreferredSampleRate(
+          that: this, sampleRate: sampleRate);
+
+  /// Sets the callback which receives the peer id of another party and whether it is recording
+  Future<void> setRecordingChanged(
+          {required FutureOr<void> Function(String, bool) recordingChanged}) =>
+      RustLib.instance.api.crateApiTelepathyTelepathySetRecordingChanged(
+          that: this, recordingChanged: recordingChanged);
+
   void setRmsThreshold({required double decimal}) => RustLib.instance.api
       .crateApiTelepathyTelepathySetRmsThreshold(that: this, decimal: decimal);
 
   void setSendCustomRingtone({required bool send}) => RustLib.instance.api
       .crateApiTelepathyTelepathySetSendCustomRingtone(that: this, send: send);
 
+  /// Selects which input frames are sent: "rms", "vad" or "always"
+  void setSilenceDetection({required String mode}) => RustLib.instance.api
+      .crateApiTelepathyTelepathySetSilenceDetection(that: this, mode: mode);
+
+  /// Changing the stereo flag will not affect the current call
+  void setStereo({required bool stereo}) => RustLib.instance.api
+      .crateApiTelepathyTelepathySetStereo(that: this, stereo: stereo);
+
+  /// How long frames keep being sent after speech
+  void setVadHangover({required int milliseconds}) =>
+      RustLib.instance.api.crateApiTelepathyTelepathySetVadHangover(
+          that: this, milliseconds: milliseconds);
+
+  /// The voice probability between 0 and 1 above which a frame is speech
+  void setVadThreshold({required double probability}) =>
+      RustLib.instance.api.crateApiTelepathyTelepathySetVadThreshold(
+          that: this, probability: probability);
+
   /// Attempts to start a call through an existing session
   Future<void> startCall({required Contact contact}) => RustLib.instance.api
       .crateApiTelepathyTelepathyStartCall(that: this, contact: contact);
 
+  /// Records the current call or room to a "wav" or "sea" file at `path`. The local party is
+  /// on the left channel and the other parties on the right, who are told about the recording
+  Future<void> startRecording({required String path, required String format}) =>
+      RustLib.instance.api.crateApiTelepathyTelepathyStartRecording(
+          that: this, path: path, format: format);
+
   Future<void> startScreenshare({required Contact contact}) => RustLib
       .instance.api
       .crateApiTelepathyTelepathyStartScreenshare(that: this, contact: contact);
@@ -6010,6 +7240,12 @@ class TelepathyImpl extends RustOpaque implements Telepathy {
   Future<void> startSession({required Contact contact}) => RustLib.instance.api
       .crateApiTelepathyTelepathyStartSession(that: this, contact: contact);
 
+  /// Stops the recording, the file is finished in the background
+  Future<void> stopRecording() =>
+      RustLib.instance.api.crateApiTelepathyTelepathyStopRecording(
+        that: this,
+      );
+
   /// Stops a specific session (called when a contact is deleted)
   Future<void> stopSession({required Contact contact}) => RustLib.instance.api
       .crateApiTelepathyTelepathyStopSession(that: this, contact: contact);
@@ -250,6 +250,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_DartFn_Inputs_bool_bool_Output_unit_AnyhowException(
           dynamic raw);
 
+  @protected
+  FutureOr<void> Function(List<DeviceInfo>, List<DeviceInfo>)
+      dco_decode_DartFn_Inputs_list_device_info_list_device_info_Output_unit_AnyhowException(
+          dynamic raw);
+
   @protected
   FutureOr<Contact?> Function(Uint8List)
       dco_decode_DartFn_Inputs_list_prim_u_8_strict_Output_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact_AnyhowException(
@@ -334,6 +339,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AudioConfig dco_decode_audio_config(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
@@ -347,12 +355,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
           dynamic raw);
 
+  @protected
+  AudioConfig dco_decode_box_autoadd_audio_config(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw);
 
   @protected
   DartError dco_decode_dart_error(dynamic raw);
 
+  @protected
+  DeviceInfo dco_decode_device_info(dynamic raw);
+
   @protected
   double dco_decode_f_32(dynamic raw);
 
@@ -368,6 +385,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<DeviceInfo> dco_decode_list_device_info(dynamic raw);
+
+  @protected
+  Uint16List dco_decode_list_prim_u_16_strict(dynamic raw);
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
   @protected
   List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
 
@@ -391,6 +417,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
           dynamic raw);
 
+  @protected
+  AudioConfig? dco_decode_opt_box_autoadd_audio_config(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
@@ -403,10 +435,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   (int, int) dco_decode_record_i_32_i_32(dynamic raw);
 
+  @protected
+  (List<DeviceInfo>, List<DeviceInfo>)
+      dco_decode_record_list_device_info_list_device_info(dynamic raw);
+
   @protected
   (List<String>, List<String>) dco_decode_record_list_string_list_string(
       dynamic raw);
 
+  @protected
+  (AudioConfig?, AudioConfig?)
+      dco_decode_record_opt_box_autoadd_audio_config_opt_box_autoadd_audio_config(
+          dynamic raw);
+
   @protected
   (String, Uint8List) dco_decode_record_string_list_prim_u_8_strict(
       dynamic raw);
@@ -417,6 +458,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Statistics dco_decode_statistics(dynamic raw);
 
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
   @protected
   int dco_decode_u_32(dynamic raw);
 
@@ -642,6 +686,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  AudioConfig sse_decode_audio_config(SseDeserializer deserializer);
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
@@ -655,12 +702,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
           SseDeserializer deserializer);
 
+  @protected
+  AudioConfig sse_decode_box_autoadd_audio_config(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+
   @protected
   int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
 
   @protected
   DartError sse_decode_dart_error(SseDeserializer deserializer);
 
+  @protected
+  DeviceInfo sse_decode_device_info(SseDeserializer deserializer);
+
   @protected
   double sse_decode_f_32(SseDeserializer deserializer);
 
@@ -676,6 +732,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+  @protected
+  List<DeviceInfo> sse_decode_list_device_info(SseDeserializer deserializer);
+
+  @protected
+  Uint16List sse_decode_list_prim_u_16_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
   @protected
   List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
 
@@ -699,6 +764,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
           SseDeserializer deserializer);
 
+  @protected
+  AudioConfig? sse_decode_opt_box_autoadd_audio_config(
+      SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
 
@@ -712,10 +784,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   (int, int) sse_decode_record_i_32_i_32(SseDeserializer deserializer);
 
+  @protected
+  (List<DeviceInfo>, List<DeviceInfo>)
+      sse_decode_record_list_device_info_list_device_info(
+          SseDeserializer deserializer);
+
   @protected
   (List<String>, List<String>) sse_decode_record_list_string_list_string(
       SseDeserializer deserializer);
 
+  @protected
+  (AudioConfig?, AudioConfig?)
+      sse_decode_record_opt_box_autoadd_audio_config_opt_box_autoadd_audio_config(
+          SseDeserializer deserializer);
+
   @protected
   (String, Uint8List) sse_decode_record_string_list_prim_u_8_strict(
       SseDeserializer deserializer);
@@ -726,6 +808,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Statistics sse_decode_statistics(SseDeserializer deserializer);
 
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer);
 
@@ -915,6 +1000,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_DartFn_Inputs_bool_bool_Output_unit_AnyhowException(
       FutureOr<void> Function(bool, bool) self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_DartFn_Inputs_list_device_info_list_device_info_Output_unit_AnyhowException(
+          FutureOr<void> Function(List<DeviceInfo>, List<DeviceInfo>) self,
+          SseSerializer serializer);
+
   @protected
   void
       sse_encode_DartFn_Inputs_list_prim_u_8_strict_Output_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact_AnyhowException(
@@ -1000,6 +1091,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_audio_config(AudioConfig self, SseSerializer serializer);
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
@@ -1013,12 +1107,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
           RecordingConfig self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_audio_config(
+      AudioConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
   @protected
   void sse_encode_dart_error(DartError self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_device_info(DeviceInfo self, SseSerializer serializer);
+
   @protected
   void sse_encode_f_32(double self, SseSerializer serializer);
 
@@ -1034,6 +1138,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_device_info(
+      List<DeviceInfo> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_u_16_strict(
+      Uint16List self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_u_32_strict(
+      Uint32List self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
 
@@ -1058,6 +1174,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecordingConfig(
           RecordingConfig? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_audio_config(
+      AudioConfig? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
@@ -1072,10 +1195,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_record_i_32_i_32((int, int) self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_record_list_device_info_list_device_info(
+      (List<DeviceInfo>, List<DeviceInfo>) self, SseSerializer serializer);
+
   @protected
   void sse_encode_record_list_string_list_string(
       (List<String>, List<String>) self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_record_opt_box_autoadd_audio_config_opt_box_autoadd_audio_config(
+          (AudioConfig?, AudioConfig?) self, SseSerializer serializer);
+
   @protected
   void sse_encode_record_string_list_prim_u_8_strict(
       (String, Uint8List) self, SseSerializer serializer);
@@ -1087,6 +1219,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_statistics(Statistics self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
@@ -0,0 +1,80 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use sea_codec::encoder::{EncoderSettings, SeaChunkEncoder};
+use sea_codec::set_simd_enabled;
+use std::hint::black_box;
+
+const CHANNELS: u8 = 1;
+const SAMPLE_RATE: u32 = 48_000;
+
+pub fn bench_encode(c: &mut Criterion) {
+    let samples = dummy_speech(SAMPLE_RATE as usize);
+
+    for vbr in [false, true] {
+        let settings = EncoderSettings {
+            frames_per_chunk: 480,
+            scale_factor_frames: 20,
+            residual_bits: 3.5,
+            vbr,
+            ..Default::default()
+        };
+
+        // the simd paths must produce exactly the same stream as the scalar one
+        let scalar = encode(false, &settings, &samples);
+        let simd = encode(true, &settings, &samples);
+        assert_eq!(scalar, simd, "simd output differs from scalar");
+
+        let name = if vbr { "encode vbr" } else { "encode cbr" };
+        let mut group = c.benchmark_group(name);
+
+        group.bench_function("scalar", |b| {
+            b.iter(|| encode(false, black_box(&settings), black_box(&samples)))
+        });
+
+        group.bench_function("simd", |b| {
+            b.iter(|| encode(true, black_box(&settings), black_box(&samples)))
+        });
+
+        group.finish();
+    }
+
+    set_simd_enabled(true);
+}
+
+fn encode(simd: bool, settings: &EncoderSettings, samples: &[i16]) -> Vec<Vec<u8>> {
+    set_simd_enabled(simd);
+
+    let mut encoder = SeaChunkEncoder::new(CHANNELS, SAMPLE_RATE, settings.clone()).unwrap();
+    let samples_per_chunk = encoder.samples_per_chunk();
+
+    samples
+        .chunks_exact(samples_per_chunk)
+        .map(|chunk| encoder.encode_chunk(chunk).unwrap())
+        .collect()
+}
+
+/// a few harmonics with a slow envelope and some noise, roughly shaped like voiced speech
+fn dummy_speech(len: usize) -> Vec<i16> {
+    let mut noise = 0x2545_f491_u32;
+
+    (0..len)
+        .map(|i| {
+            let t = i as f32 / SAMPLE_RATE as f32;
+            let envelope = (t * 3.0 * std::f32::consts::TAU).sin().abs();
+            let voice = (1..=5)
+                .map(|harmonic| {
+                    (t * 140.0 * harmonic as f32 * std::f32::consts::TAU).sin() / harmonic as f32
+                })
+                .sum::<f32>();
+
+            noise ^= noise << 13;
+            noise ^= noise >> 17;
+            noise ^= noise << 5;
+            let hiss = (noise as f32 / u32::MAX as f32 - 0.5) * 0.02;
+
+            ((voice * envelope * 0.4 + hiss) * i16::MAX as f32) as i16
+        })
+        .collect()
+}
+
+criterion_group!(benches, bench_encode);
+criterion_main!(benches);
@@ -0,0 +1,51 @@
+//! prints the quality every setting achieves on the reference signals
+//! set `SEA_REFERENCE_WAVS` to a directory of 16 bit 48kHz wav files to measure those as well
+
+use sea_codec::encoder::EncoderSettings;
+use sea_codec::quality::measure;
+
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+fn main() {
+    let references = common::synthetic().into_iter().chain(common::wav_files());
+
+    println!(
+        "{:<12} {:<10} {:>8} {:>8} {:>8} {:>8}",
+        "signal", "setting", "kbps", "snr", "segsnr", "lsd"
+    );
+
+    for reference in references {
+        for vbr in [false, true] {
+            for residual_bits in [1.5, 2.0, 3.0, 4.0, 5.0, 6.0] {
+                let settings = EncoderSettings {
+                    frames_per_chunk: 480,
+                    scale_factor_frames: 20,
+                    residual_bits,
+                    vbr,
+                    ..Default::default()
+                };
+
+                // vbr needs room below its target, so it rejects the lowest settings
+                let Ok(report) = measure(
+                    &reference.samples,
+                    reference.channels,
+                    common::SAMPLE_RATE,
+                    settings,
+                ) else {
+                    continue;
+                };
+
+                println!(
+                    "{:<12} {:<10} {:>8.1} {:>8.2} {:>8.2} {:>8.2}",
+                    reference.name,
+                    format!("{} {}", if vbr { "vbr" } else { "cbr" }, residual_bits),
+                    report.bitrate,
+                    report.snr,
+                    report.segmental_snr,
+                    report.spectral_distance
+                );
+            }
+        }
+    }
+}
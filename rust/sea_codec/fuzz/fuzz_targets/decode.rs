@@ -0,0 +1,27 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sea_codec::io::SeaReader;
+use sea_codec::SeaError;
+use std::io::Cursor;
+
+// decodes a whole file, then seeks back into it through the chunk index
+// malformed input must surface as a SeaError, never as a panic
+fuzz_target!(|data: &[u8]| {
+    let Ok(mut reader) = SeaReader::new(Cursor::new(data)) else {
+        return;
+    };
+
+    loop {
+        match reader.read_chunk() {
+            Ok(Some(_)) => (),
+            Ok(None) | Err(SeaError::IoError(_)) => break,
+            // a corrupt chunk is skipped, the following chunks still decode
+            Err(_) => (),
+        }
+    }
+
+    if reader.seek_to_frame(data.len() as u64 / 2).is_ok() {
+        _ = reader.read_chunk();
+    }
+});
@@ -0,0 +1,61 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sea_codec::decoder::SeaChunkDecoder;
+use sea_codec::encoder::{EncoderSettings, SeaChunkEncoder};
+use std::sync::OnceLock;
+
+/// the headers of live streams with forward error correction, by channels and vbr
+fn header(channels: u8, vbr: bool) -> &'static [u8] {
+    static HEADERS: OnceLock<Vec<Vec<u8>>> = OnceLock::new();
+
+    let headers = HEADERS.get_or_init(|| {
+        [(1, false), (1, true), (2, false), (2, true)]
+            .into_iter()
+            .map(|(channels, vbr)| {
+                let settings = EncoderSettings {
+                    frames_per_chunk: 480,
+                    vbr,
+                    fec_residual_bits: Some(2.0),
+                    ..Default::default()
+                };
+                let mut encoder = SeaChunkEncoder::new(channels, 48_000, settings).unwrap();
+                encoder
+                    .encode_packet(&vec![0; 480 * channels as usize])
+                    .unwrap();
+                encoder.header().unwrap().unwrap()
+            })
+            .collect()
+    });
+
+    &headers[(channels as usize - 1) * 2 + vbr as usize]
+}
+
+// feeds packets to a decoder which started from a valid header, reaching the fec packet parsing
+// the first byte selects the stream, the rest are packets each prefixed with a u16 le length
+// malformed packets must surface as a SeaError, never as a panic
+fuzz_target!(|data: &[u8]| {
+    let Some((&selector, mut data)) = data.split_first() else {
+        return;
+    };
+
+    let channels = 1 + (selector & 1);
+    let vbr = selector & 2 != 0;
+    let Ok(mut decoder) = SeaChunkDecoder::new(header(channels, vbr)) else {
+        return;
+    };
+    decoder.set_fec(true);
+
+    while let [low, high, rest @ ..] = data {
+        let len = (u16::from_le_bytes([*low, *high]) as usize).min(rest.len());
+        let (packet, rest) = rest.split_at(len);
+        data = rest;
+
+        // an empty packet stands for a lost one
+        if packet.is_empty() {
+            _ = decoder.conceal_chunk();
+        } else {
+            _ = decoder.decode_packet(packet);
+        }
+    }
+});
@@ -1,4 +1,7 @@
-use std::mem;
+use alloc::vec::Vec;
+use core::mem;
+
+use super::common::SeaError;
 
 pub struct BitUnpacker {
     bits_stored: u32,
@@ -9,24 +12,23 @@ pub struct BitUnpacker {
 }
 
 impl BitUnpacker {
-    pub fn new_const_bits(bitlength: u8) -> Self {
-        Self {
-            bits_stored: 0,
-            carry: 0,
-            bitlengths: vec![bitlength; 1],
-            bitlengths_index: 0,
-            output: Vec::new(),
-        }
+    pub fn new_const_bits(bitlength: u8) -> Result<Self, SeaError> {
+        Self::new_var_bits(&[bitlength])
     }
 
-    pub fn new_var_bits(bitlengths: &[u8]) -> Self {
-        Self {
+    /// bit lengths must be between 1 and 8, anything else is a malformed chunk
+    pub fn new_var_bits(bitlengths: &[u8]) -> Result<Self, SeaError> {
+        if bitlengths.iter().any(|bits| !(1..=8).contains(bits)) {
+            return Err(SeaError::InvalidFrame);
+        }
+
+        Ok(Self {
             bits_stored: 0,
             carry: 0,
             bitlengths: bitlengths.to_vec(),
             bitlengths_index: 0,
             output: Vec::new(),
-        }
+        })
     }
 
     const MASKS: [u32; 9] = [0, 1, 3, 7, 15, 31, 63, 127, 255];
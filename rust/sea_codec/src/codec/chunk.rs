@@ -2,10 +2,11 @@ use crate::{
     codec::{bits::BitUnpacker, lms::LMS_LEN},
     encoder::EncoderSettings,
 };
+use alloc::{borrow::ToOwned, vec, vec::Vec};
 
 use super::{
     bits::BitPacker,
-    common::{SeaError, SeaResidualSize},
+    common::{EncodedSamples, SeaError, SeaResidualSize},
     file::SeaFileHeader,
     lms::SeaLMS,
 };
@@ -39,10 +40,15 @@ impl SeaChunk {
         file_header: &SeaFileHeader,
         lms: &[SeaLMS],
         encoder_settings: &EncoderSettings,
-        scale_factors: Vec<u8>,
-        vbr_residual_sizes: Vec<u8>,
-        residuals: Vec<u8>,
+        encoded: EncodedSamples,
     ) -> SeaChunk {
+        let EncodedSamples {
+            residual_size,
+            scale_factors,
+            residual_bits: vbr_residual_sizes,
+            residuals,
+        } = encoded;
+
         let is_vbr = !vbr_residual_sizes.is_empty();
         let chunk_type = if is_vbr {
             SeaChunkType::Vbr
@@ -57,7 +63,7 @@ impl SeaChunk {
             chunk_type,
             scale_factor_bits: encoder_settings.scale_factor_bits,
             scale_factor_frames: encoder_settings.scale_factor_frames,
-            residual_size: SeaResidualSize::from(encoder_settings.residual_bits.floor() as u8),
+            residual_size,
 
             lms: lms.to_owned(),
             scale_factors,
@@ -66,8 +72,14 @@ impl SeaChunk {
         }
     }
 
-    pub fn from_slice(encoded: &[u8], file_header: &SeaFileHeader) -> Result<Self, SeaError> {
-        assert!(encoded.len() <= file_header.chunk_size as usize);
+    /// parses a chunk from the start of `encoded`, also returning the number of bytes it used
+    pub fn from_slice(
+        encoded: &[u8],
+        file_header: &SeaFileHeader,
+    ) -> Result<(Self, usize), SeaError> {
+        if encoded.len() < 4 || encoded.len() > file_header.max_chunk_size() {
+            return Err(SeaError::InvalidFrame);
+        }
 
         let chunk_type: SeaChunkType = match encoded[0] {
             0x01 => SeaChunkType::Cbr,
@@ -77,20 +89,22 @@ impl SeaChunk {
 
         let scale_factor_bits = encoded[1] >> 4;
 
-        let residual_size = SeaResidualSize::from(encoded[1] & 0b1111);
+        let residual_size = SeaResidualSize::try_from(encoded[1] & 0b1111)?;
         let scale_factor_frames = encoded[2];
         let _reserved = encoded[3];
 
+        if scale_factor_bits == 0 || scale_factor_bits > 8 || scale_factor_frames == 0 {
+            return Err(SeaError::InvalidFrame);
+        }
+
         let mut encoded_index = 4;
 
         let mut lms: Vec<SeaLMS> = vec![];
         for _ in 0..file_header.channels as usize {
+            let lms_bytes = take_bytes(encoded, &mut encoded_index, LMS_LEN * 4)?;
             lms.push(SeaLMS::from_bytes(
-                &encoded[encoded_index..encoded_index + LMS_LEN * 4]
-                    .try_into()
-                    .unwrap(),
+                lms_bytes.try_into().map_err(|_| SeaError::InvalidFrame)?,
             ));
-            encoded_index += LMS_LEN * 4;
         }
 
         let frames_in_this_chunk = file_header.frames_per_chunk as usize;
@@ -103,10 +117,9 @@ impl SeaChunk {
                 (scale_factor_items * scale_factor_bits as usize).div_ceil(8);
 
             let packed_scale_factors =
-                &encoded[encoded_index..encoded_index + packed_scale_factor_bytes];
-            encoded_index += packed_scale_factor_bytes;
+                take_bytes(encoded, &mut encoded_index, packed_scale_factor_bytes)?;
 
-            let mut unpacker = BitUnpacker::new_const_bits(scale_factor_bits);
+            let mut unpacker = BitUnpacker::new_const_bits(scale_factor_bits)?;
             unpacker.process_bytes(packed_scale_factors);
             let mut res = unpacker.finish();
             res.resize(scale_factor_items, 0);
@@ -116,10 +129,9 @@ impl SeaChunk {
         let vbr_residual_sizes: Vec<u8> = if matches!(chunk_type, SeaChunkType::Vbr) {
             let packed_vbr_residual_sizes_bytes = (scale_factor_items * 2).div_ceil(8);
             let packed_vbr_residual_sizes =
-                &encoded[encoded_index..encoded_index + packed_vbr_residual_sizes_bytes];
-            encoded_index += packed_vbr_residual_sizes_bytes;
+                take_bytes(encoded, &mut encoded_index, packed_vbr_residual_sizes_bytes)?;
 
-            let mut unpacker: BitUnpacker = BitUnpacker::new_const_bits(2);
+            let mut unpacker: BitUnpacker = BitUnpacker::new_const_bits(2)?;
             unpacker.process_bytes(packed_vbr_residual_sizes);
             let mut res = unpacker.finish();
             res.resize(scale_factor_items, 0);
@@ -142,9 +154,9 @@ impl SeaChunk {
                     }
                 }
 
-                BitUnpacker::new_var_bits(&bitlengths)
+                BitUnpacker::new_var_bits(&bitlengths)?
             } else {
-                BitUnpacker::new_const_bits(residual_size as u8)
+                BitUnpacker::new_const_bits(residual_size as u8)?
             };
 
             let packed_residuals_bytes = if matches!(chunk_type, SeaChunkType::Vbr) {
@@ -177,7 +189,7 @@ impl SeaChunk {
                     .div_ceil(8)
             };
 
-            let packed_residuals = &encoded[encoded_index..encoded_index + packed_residuals_bytes];
+            let packed_residuals = take_bytes(encoded, &mut encoded_index, packed_residuals_bytes)?;
 
             unpacker.process_bytes(packed_residuals);
 
@@ -186,7 +198,7 @@ impl SeaChunk {
             res
         };
 
-        Ok(Self {
+        let chunk = Self {
             channels: file_header.channels as usize,
             frames_per_chunk: file_header.frames_per_chunk as usize,
 
@@ -199,7 +211,9 @@ impl SeaChunk {
             scale_factors,
             vbr_residual_sizes,
             residuals,
-        })
+        };
+
+        Ok((chunk, encoded_index))
     }
 
     fn serialize_header(&self) -> [u8; 4] {
@@ -281,3 +295,109 @@ impl SeaChunk {
         output
     }
 }
+
+/// takes the next `len` bytes of a chunk, failing instead of reading past the end
+fn take_bytes<'a>(encoded: &'a [u8], index: &mut usize, len: usize) -> Result<&'a [u8], SeaError> {
+    let bytes = encoded
+        .get(*index..*index + len)
+        .ok_or(SeaError::InvalidFrame)?;
+    *index += len;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        decoder::SeaChunkDecoder,
+        encoder::{EncoderSettings, SeaChunkEncoder},
+        SeaError,
+    };
+    use alloc::vec::Vec;
+
+    const FRAMES_PER_CHUNK: usize = 480;
+
+    /// a header and a chunk of a stereo tone
+    fn encode(vbr: bool) -> (Vec<u8>, Vec<u8>) {
+        let settings = EncoderSettings {
+            frames_per_chunk: FRAMES_PER_CHUNK as u16,
+            vbr,
+            ..Default::default()
+        };
+        let mut encoder = SeaChunkEncoder::new(2, 48_000, settings).unwrap();
+        let samples: Vec<i16> = (0..FRAMES_PER_CHUNK * 2)
+            .map(|i| ((i as f32 * 0.03).sin() * 10_000.0) as i16)
+            .collect();
+
+        let chunk = encoder.encode_chunk(&samples).unwrap();
+        (encoder.header().unwrap().unwrap(), chunk)
+    }
+
+    #[test]
+    fn truncated_chunks_fail() {
+        for vbr in [false, true] {
+            let (header, chunk) = encode(vbr);
+            let mut decoder = SeaChunkDecoder::new(&header).unwrap();
+
+            for len in 0..chunk.len() {
+                assert!(
+                    matches!(
+                        decoder.decode_chunk(&chunk[..len]),
+                        Err(SeaError::InvalidFrame)
+                    ),
+                    "vbr {} len {}",
+                    vbr,
+                    len
+                );
+            }
+
+            assert!(decoder.decode_chunk(&chunk).is_ok());
+        }
+    }
+
+    #[test]
+    fn invalid_chunk_headers_fail() {
+        let (header, chunk) = encode(true);
+        let mut decoder = SeaChunkDecoder::new(&header).unwrap();
+
+        // an unknown chunk type, zero scale factor bits, a zero residual size
+        // a residual size too large for vbr and zero scale factor frames
+        for (index, byte) in [(0, 0x03), (1, 0x03), (1, 0x40), (1, 0x48), (2, 0)] {
+            let mut corrupt = chunk.clone();
+            corrupt[index] = byte;
+
+            assert!(
+                decoder.decode_chunk(&corrupt).is_err(),
+                "byte {} set to {:#x}",
+                index,
+                byte
+            );
+        }
+    }
+
+    #[test]
+    fn corrupt_chunks_do_not_panic() {
+        // xorshift, any byte of the chunk may be damaged in transit
+        let mut state = 0x2545_f491_u32;
+        let mut random = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        };
+
+        for vbr in [false, true] {
+            let (header, chunk) = encode(vbr);
+            let mut decoder = SeaChunkDecoder::new(&header).unwrap();
+
+            for _ in 0..500 {
+                let mut corrupt = chunk.clone();
+                for _ in 0..1 + random() % 8 {
+                    let index = random() as usize % corrupt.len();
+                    corrupt[index] = random() as u8;
+                }
+
+                _ = decoder.decode_chunk(&corrupt);
+            }
+        }
+    }
+}
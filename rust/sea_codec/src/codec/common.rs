@@ -1,4 +1,7 @@
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
 use kanal::{ReceiveError, SendError};
+#[cfg(feature = "std")]
 use std::io;
 
 pub const SEAC_MAGIC: u32 = u32::from_be_bytes(*b"seac"); // 0x73 0x65 0x61 0x63
@@ -36,6 +39,10 @@ impl SeaResidualSize {
         }
     }
 
+    pub fn is_valid(len: u8) -> bool {
+        (1..=8).contains(&len)
+    }
+
     #[inline(always)]
     pub fn to_binary_combinations(self) -> usize {
         match self {
@@ -51,6 +58,18 @@ impl SeaResidualSize {
     }
 }
 
+impl TryFrom<u8> for SeaResidualSize {
+    type Error = SeaError;
+
+    fn try_from(len: u8) -> Result<Self, Self::Error> {
+        if Self::is_valid(len) {
+            Ok(Self::from(len))
+        } else {
+            Err(SeaError::InvalidFrame)
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum SeaError {
     ReadError,
@@ -61,62 +80,103 @@ pub enum SeaError {
     UnsupportedVersion,
     TooManyFrames,
     MetadataTooLarge,
+    #[cfg(feature = "std")]
     IoError(io::Error),
+    #[cfg(feature = "std")]
     SendError(SendError),
+    #[cfg(feature = "std")]
     ReceiveError(ReceiveError),
 }
 
+#[cfg(feature = "std")]
 impl From<io::Error> for SeaError {
     fn from(error: io::Error) -> Self {
         SeaError::IoError(error)
     }
 }
 
+#[cfg(feature = "std")]
 impl From<SendError> for SeaError {
     fn from(error: SendError) -> Self {
         SeaError::SendError(error)
     }
 }
 
+#[cfg(feature = "std")]
 impl From<ReceiveError> for SeaError {
     fn from(error: ReceiveError) -> Self {
         SeaError::ReceiveError(error)
     }
 }
 
+/// where headers are read from, any reader with std
+#[cfg(feature = "std")]
+pub use std::io::Read as ReadBytes;
+
+/// where headers are read from, byte slices without std
+#[cfg(not(feature = "std"))]
+pub trait ReadBytes {
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), SeaError>;
+}
+
+#[cfg(not(feature = "std"))]
+impl ReadBytes for &[u8] {
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), SeaError> {
+        if self.len() < buf.len() {
+            return Err(SeaError::ReadError);
+        }
+
+        let (head, tail) = self.split_at(buf.len());
+        buf.copy_from_slice(head);
+        *self = tail;
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl<R: ReadBytes + ?Sized> ReadBytes for &mut R {
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), SeaError> {
+        (**self).read_exact(buf)
+    }
+}
+
 #[inline(always)]
-pub fn read_bytes<R: io::Read, const BYTES: usize>(mut reader: R) -> io::Result<[u8; BYTES]> {
+pub fn read_bytes<R: ReadBytes, const BYTES: usize>(
+    mut reader: R,
+) -> Result<[u8; BYTES], SeaError> {
     let mut buf = [0_u8; BYTES];
     reader.read_exact(&mut buf)?;
     Ok(buf)
 }
 
 #[inline(always)]
-pub fn read_u8<R: io::Read>(reader: R) -> io::Result<u8> {
+pub fn read_u8<R: ReadBytes>(reader: R) -> Result<u8, SeaError> {
     let data: [u8; 1] = read_bytes(reader)?;
     Ok(data[0])
 }
 
 #[inline(always)]
-pub fn read_u16_le<R: io::Read>(reader: R) -> io::Result<u16> {
+pub fn read_u16_le<R: ReadBytes>(reader: R) -> Result<u16, SeaError> {
     let data = read_bytes(reader)?;
     Ok(u16::from_le_bytes(data))
 }
 
 #[inline(always)]
-pub fn read_u32_be<R: io::Read>(reader: R) -> io::Result<u32> {
+pub fn read_u32_be<R: ReadBytes>(reader: R) -> Result<u32, SeaError> {
     let data = read_bytes(reader)?;
     Ok(u32::from_be_bytes(data))
 }
 
 #[inline(always)]
-pub fn read_u32_le<R: io::Read>(reader: R) -> io::Result<u32> {
+pub fn read_u32_le<R: ReadBytes>(reader: R) -> Result<u32, SeaError> {
     let data = read_bytes(reader)?;
     Ok(u32::from_le_bytes(data))
 }
 
 #[derive(Debug)]
 pub struct EncodedSamples {
+    /// the chunk header residual size, vbr sizes are stored relative to it
+    pub residual_size: SeaResidualSize,
     pub scale_factors: Vec<u8>,
     pub residuals: Vec<u8>,
     pub residual_bits: Vec<u8>,
@@ -1,24 +1,44 @@
-use super::{chunk::SeaChunk, common::clamp_i16, dqt::SeaDequantTab};
+use super::{
+    chunk::SeaChunk,
+    common::{clamp_i16, SeaError},
+    dqt::SeaDequantTab,
+    lms::SeaLMS,
+};
+use alloc::{vec, vec::Vec};
+
+/// the number of consecutive concealed chunks it takes to fade out to silence
+const CONCEALMENT_FADE_CHUNKS: usize = 4;
 
 pub struct Decoder {
     channels: usize,
-    scale_factor_bits: usize,
 
     dequant_tab: SeaDequantTab,
+
+    // the lms state at the end of the last decoded chunk, used for concealment
+    lms: Vec<SeaLMS>,
+    // the peak level of each channel in the last decoded chunk, bounds the extrapolation
+    peaks: Vec<i32>,
+    // the number of chunks concealed since the last decoded chunk
+    concealed_chunks: usize,
 }
 
 impl Decoder {
     pub fn init(channels: usize, scale_factor_bits: usize) -> Self {
         Self {
             channels,
-            scale_factor_bits,
 
             dequant_tab: SeaDequantTab::init(scale_factor_bits),
+
+            lms: Vec::new(),
+            peaks: vec![0; channels],
+            concealed_chunks: CONCEALMENT_FADE_CHUNKS,
         }
     }
 
-    pub fn decode_cbr(&self, chunk: &SeaChunk) -> Vec<i16> {
-        assert_eq!(chunk.scale_factor_bits as usize, self.scale_factor_bits);
+    pub fn decode_cbr(&mut self, chunk: &SeaChunk) -> Result<Vec<i16>, SeaError> {
+        // the encoder may change its scale factor bits between chunks
+        self.dequant_tab
+            .set_scalefactor_bits(chunk.scale_factor_bits as usize);
 
         let mut output: Vec<i16> = Vec::with_capacity(chunk.frames_per_chunk * self.channels);
 
@@ -46,11 +66,14 @@ impl Decoder {
             }
         }
 
-        output
+        self.finish_chunk(lms, &output);
+        Ok(output)
     }
 
-    pub fn decode_vbr(&self, chunk: &SeaChunk) -> Vec<i16> {
-        assert_eq!(chunk.scale_factor_bits as usize, self.scale_factor_bits);
+    pub fn decode_vbr(&mut self, chunk: &SeaChunk) -> Result<Vec<i16>, SeaError> {
+        // the encoder may change its scale factor bits between chunks
+        self.dequant_tab
+            .set_scalefactor_bits(chunk.scale_factor_bits as usize);
 
         let mut output: Vec<i16> = Vec::with_capacity(chunk.frames_per_chunk * self.channels);
 
@@ -82,6 +105,120 @@ impl Decoder {
             }
         }
 
-        output
+        self.finish_chunk(lms, &output);
+        Ok(output)
+    }
+
+    fn finish_chunk(&mut self, lms: Vec<SeaLMS>, output: &[i16]) {
+        self.lms = lms;
+        self.concealed_chunks = 0;
+
+        self.peaks.fill(0);
+        for frame in output.chunks_exact(self.channels) {
+            for (peak, sample) in self.peaks.iter_mut().zip(frame) {
+                *peak = (*peak).max((*sample as i32).abs());
+            }
+        }
+    }
+
+    /// synthesizes a chunk by extrapolating the lms prediction, fading out over consecutive losses
+    /// returns None once the output has faded to silence or before any chunk has been decoded
+    pub fn conceal(&mut self, frames_per_chunk: usize) -> Option<Vec<i16>> {
+        if self.lms.is_empty() || self.concealed_chunks >= CONCEALMENT_FADE_CHUNKS {
+            return None;
+        }
+
+        let mut output: Vec<i16> = Vec::with_capacity(frames_per_chunk * self.channels);
+
+        // the gain falls linearly from 1 to 0 across the whole fade
+        let fade_len = (CONCEALMENT_FADE_CHUNKS * frames_per_chunk) as i32;
+        let fade_start = (self.concealed_chunks * frames_per_chunk) as i32;
+
+        for frame in 0..frames_per_chunk as i32 {
+            let gain = fade_len - fade_start - frame;
+
+            for (lms, peak) in self.lms.iter_mut().zip(&self.peaks) {
+                let predicted = lms.predict().clamp(-peak, *peak) as i16;
+                output.push((predicted as i32 * gain / fade_len) as i16);
+                lms.update(predicted, 0);
+            }
+        }
+
+        self.concealed_chunks += 1;
+
+        Some(output)
+    }
+
+    /// stops concealment from extrapolating across a silent gap
+    pub fn silence(&mut self) {
+        self.concealed_chunks = CONCEALMENT_FADE_CHUNKS;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decoder::SeaChunkDecoder, encoder::EncoderSettings, encoder::SeaChunkEncoder};
+
+    const FRAMES_PER_CHUNK: usize = 480;
+
+    fn decoder_after_tone() -> SeaChunkDecoder {
+        let settings = EncoderSettings {
+            frames_per_chunk: FRAMES_PER_CHUNK as u16,
+            ..Default::default()
+        };
+        let mut encoder = SeaChunkEncoder::new(1, 48_000, settings).unwrap();
+        let tone: Vec<i16> = (0..FRAMES_PER_CHUNK)
+            .map(|i| ((i as f32 * 0.05).sin() * 8_000.0) as i16)
+            .collect();
+
+        let chunk = encoder.encode_chunk(&tone).unwrap();
+        let mut decoder = SeaChunkDecoder::new(&encoder.header().unwrap().unwrap()).unwrap();
+        decoder.decode_chunk(&chunk).unwrap();
+        decoder
+    }
+
+    fn peak(samples: &[i16]) -> i32 {
+        samples
+            .iter()
+            .map(|sample| (*sample as i32).abs())
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn concealment_fades_to_silence() {
+        let mut decoder = decoder_after_tone();
+
+        let chunks: Vec<Vec<i16>> = (0..CONCEALMENT_FADE_CHUNKS)
+            .map(|_| decoder.conceal_chunk().unwrap())
+            .collect();
+        assert!(decoder.conceal_chunk().is_none());
+
+        assert!(peak(&chunks[0]) > 0);
+        for pair in chunks.windows(2) {
+            assert!(peak(&pair[1]) <= peak(&pair[0]));
+        }
+
+        // the gain reaches zero at the end of the last chunk
+        let last = chunks.last().unwrap();
+        assert!(peak(&last[FRAMES_PER_CHUNK - 10..]) < 100);
+    }
+
+    #[test]
+    fn silence_stops_concealment() {
+        let mut decoder = decoder_after_tone();
+
+        decoder.silence();
+        assert!(decoder.conceal_chunk().is_none());
+    }
+
+    #[test]
+    fn nothing_to_conceal_before_the_first_chunk() {
+        let mut decoder = decoder_after_tone();
+        let header = decoder.header().clone();
+        decoder.restart(header);
+
+        assert!(decoder.conceal_chunk().is_none());
     }
 }
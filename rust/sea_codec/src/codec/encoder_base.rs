@@ -1,12 +1,25 @@
-use std::mem;
+use alloc::{vec, vec::Vec};
+use core::mem;
 
 use super::{
     common::{clamp_i16, SeaResidualSize},
     dqt::SeaDequantTab,
-    lms::SeaLMS,
+    lms::{SeaLMS, FLOATING_BITS, LMS_LEN},
     qt::SeaQuantTab,
+    simd::{Lanes, SimdLevel, MAX_LANES},
 };
 
+#[cfg(target_arch = "aarch64")]
+use super::simd::Neon;
+#[cfg(target_arch = "x86_64")]
+use super::simd::{Avx2, Sse41};
+
+// the lane search keeps one vector per lms tap
+const _: () = assert!(LMS_LEN == 4);
+
+const PREDICTION_SHIFT: i32 = 16 - FLOATING_BITS as i32;
+const UPDATE_SHIFT: i32 = FLOATING_BITS as i32 + 1;
+
 pub struct EncoderBase {
     channels: usize,
     scale_factor_bits: usize,
@@ -17,6 +30,168 @@ pub struct EncoderBase {
     dequant_tab: SeaDequantTab,
     quant_tab: SeaQuantTab,
     pub lms: Vec<SeaLMS>,
+
+    simd_level: SimdLevel,
+    // every dequantization row of a residual size back to back, built on first use
+    lane_dqt: [Vec<i32>; 9],
+    // the residuals of every lane, interleaved
+    lane_residuals: Vec<i32>,
+}
+
+/// the scale factor search of one channel
+struct ScaleFactorSearch<'a> {
+    channels: usize,
+    samples: &'a [i16],
+    prev_scalefactor: i32,
+    lms: &'a SeaLMS,
+    residual_size: SeaResidualSize,
+    scalefactor_reciprocals: &'a [i32],
+}
+
+/// up to this many dequantized magnitudes are selected in registers instead of gathered
+const SELECT_MAGNITUDES: usize = 8;
+
+/// the constants of the lane search for one residual size
+struct LaneTables<'a, L: Lanes> {
+    quant_bias: L::V,
+    quant_shift: i32,
+    quant_max: L::V,
+    dequant_tab: &'a [i32],
+    dequant_row_len: i32,
+    // each dequantization row holds every magnitude once positive and once negative
+    magnitudes: usize,
+    min_i16: L::V,
+    max_i16: L::V,
+}
+
+impl<'a, L: Lanes> LaneTables<'a, L> {
+    #[inline(always)]
+    unsafe fn new(residual_size: SeaResidualSize, lane_dqt: &'a [Vec<i32>; 9]) -> Self {
+        // the zig-zag quant table in closed form, see `SeaQuantTab::fill_dqt_table`
+        // even indices are positive and odd ones negative, two residual bits rounds differently
+        let clamp_limit = residual_size.to_binary_combinations() as i32;
+        let two_bits = residual_size as usize == 2;
+
+        Self {
+            quant_bias: L::splat(two_bits as i32),
+            quant_shift: 1 + two_bits as i32,
+            quant_max: L::splat(clamp_limit / 2 - 1),
+            dequant_tab: &lane_dqt[residual_size as usize],
+            dequant_row_len: 1 << residual_size as i32,
+            magnitudes: 1 << (residual_size as usize - 1),
+            min_i16: L::splat(i16::MIN as i32),
+            max_i16: L::splat(i16::MAX as i32),
+        }
+    }
+}
+
+/// one scale factor candidate per lane, with its own lms and rank
+struct LaneBatch<L: Lanes> {
+    scalefactors: [i32; MAX_LANES],
+    reciprocal: L::V,
+    dequant_row: L::V,
+    // the positive dequantized values of every lane's row, if there are few enough
+    magnitudes: [L::V; SELECT_MAGNITUDES],
+    weights: [L::V; LMS_LEN],
+    history: [L::V; LMS_LEN],
+    rank: L::Rank,
+}
+
+impl<L: Lanes> LaneBatch<L> {
+    /// `candidates` are offsets from the previous scale factor like in the scalar search,
+    /// lanes past the last one repeat it and are ignored
+    #[inline(always)]
+    unsafe fn new(
+        search: &ScaleFactorSearch,
+        tables: &LaneTables<L>,
+        candidates: &[i32],
+        scalefactor_end: i32,
+    ) -> Self {
+        let mut scalefactors = [0i32; MAX_LANES];
+        for (lane, scalefactor) in scalefactors.iter_mut().take(L::LANES).enumerate() {
+            let candidate = candidates[lane.min(candidates.len() - 1)];
+            *scalefactor = (candidate + search.prev_scalefactor) % scalefactor_end;
+        }
+
+        let mut reciprocals = [0i32; MAX_LANES];
+        for (reciprocal, scalefactor) in reciprocals.iter_mut().zip(scalefactors) {
+            *reciprocal = search.scalefactor_reciprocals[scalefactor as usize];
+        }
+
+        let mut magnitudes = [L::splat(0); SELECT_MAGNITUDES];
+        if tables.magnitudes <= SELECT_MAGNITUDES {
+            let mut lane_values = [0i32; MAX_LANES];
+            for (magnitude, vector) in magnitudes.iter_mut().take(tables.magnitudes).enumerate() {
+                for (value, scalefactor) in lane_values.iter_mut().zip(scalefactors) {
+                    let row = (scalefactor * tables.dequant_row_len) as usize;
+                    *value = tables.dequant_tab[row + magnitude * 2];
+                }
+                *vector = L::load(&lane_values);
+            }
+        }
+
+        Self {
+            scalefactors,
+            reciprocal: L::load(&reciprocals),
+            dequant_row: L::mul(L::load(&scalefactors), L::splat(tables.dequant_row_len)),
+            magnitudes,
+            weights: search.lms.weights.map(|weight| L::splat(weight)),
+            history: search.lms.history.map(|sample| L::splat(sample)),
+            rank: L::rank_zero(),
+        }
+    }
+
+    /// encodes one sample in every lane, mirroring `EncoderBase::calculate_residuals`
+    #[inline(always)]
+    unsafe fn step(&mut self, tables: &LaneTables<L>, sample: L::V) -> L::V {
+        let [w0, w1, w2, w3] = self.weights;
+        let [h0, h1, h2, h3] = self.history;
+
+        let predicted = L::shr::<PREDICTION_SHIFT>(L::add(
+            L::add(L::mul(w0, h0), L::mul(w1, h1)),
+            L::add(L::mul(w2, h2), L::mul(w3, h3)),
+        ));
+
+        // sea_div
+        let residual = L::sub(sample, predicted);
+        let quotient = L::mul_round_shr16(residual, self.reciprocal);
+        let scaled = L::add(quotient, L::sub(L::signum(residual), L::signum(quotient)));
+
+        let magnitude = L::min(
+            L::shr_by(
+                L::add(L::abs(scaled), tables.quant_bias),
+                tables.quant_shift,
+            ),
+            tables.quant_max,
+        );
+        let quantized = L::sub(L::add(magnitude, magnitude), L::shr::<31>(scaled));
+
+        let dequantized = if tables.magnitudes <= SELECT_MAGNITUDES {
+            let positive = L::select(magnitude, &self.magnitudes[..tables.magnitudes]);
+            L::negate_if_negative(positive, scaled)
+        } else {
+            L::gather(tables.dequant_tab, L::add(self.dequant_row, quantized))
+        };
+        let reconstructed = L::min(
+            L::max(L::add(predicted, dequantized), tables.min_i16),
+            tables.max_i16,
+        );
+
+        let error = L::sub(sample, reconstructed);
+        self.rank = L::rank_add(self.rank, error, &self.weights);
+
+        // lms update
+        let delta = L::shr::<UPDATE_SHIFT>(dequantized);
+        self.weights = [
+            L::add(w0, L::negate_if_negative(delta, h0)),
+            L::add(w1, L::negate_if_negative(delta, h1)),
+            L::add(w2, L::negate_if_negative(delta, h2)),
+            L::add(w3, L::negate_if_negative(delta, h3)),
+        ];
+        self.history = [h1, h2, h3, reconstructed];
+
+        quantized
+    }
 }
 
 #[inline(always)]
@@ -37,6 +212,10 @@ impl EncoderBase {
             dequant_tab: SeaDequantTab::init(scale_factor_bits),
             quant_tab: SeaQuantTab::init(),
             lms: SeaLMS::init_vec(channels as u32),
+
+            simd_level: SimdLevel::detect(),
+            lane_dqt: Default::default(),
+            lane_residuals: Vec::new(),
         }
     }
 
@@ -143,6 +322,183 @@ impl EncoderBase {
         (best_rank, best_lms, best_scalefactor)
     }
 
+    /// searches `L::LANES` scale factors at once, one per lane
+    /// ties are broken in the scalar search's order, so the result is identical
+    ///
+    /// # Safety
+    /// the cpu must support the instruction set of `L`
+    #[inline(always)]
+    unsafe fn get_residuals_with_best_scalefactor_lanes<L: Lanes>(
+        &self,
+        search: &ScaleFactorSearch,
+        best_residual_bits: &mut [u8],
+        lane_residuals: &mut Vec<i32>,
+    ) -> (u64, SeaLMS, i32) {
+        let lanes = L::LANES;
+        let tables = LaneTables::<L>::new(search.residual_size, &self.lane_dqt);
+
+        lane_residuals.resize(best_residual_bits.len() * lanes, 0);
+
+        // the previous scale factor is usually close to the best one, ranking it on its own first
+        // gives every batch a limit to stop early against
+        let residual_size = search.residual_size as usize;
+        let dequant_row_len = 1 << residual_size;
+        let dequant_row = search.prev_scalefactor as usize * dequant_row_len;
+
+        let mut best_lms = search.lms.clone();
+        let mut best_rank = self.calculate_residuals(
+            search.channels,
+            &self.lane_dqt[residual_size][dequant_row..dequant_row + dequant_row_len],
+            search.samples,
+            search.prev_scalefactor,
+            &mut best_lms,
+            u64::MAX,
+            search.residual_size,
+            search.scalefactor_reciprocals,
+            best_residual_bits,
+        );
+        let mut best_scalefactor = search.prev_scalefactor;
+        let mut best_candidate = 0;
+
+        let mut ranks = [0u64; MAX_LANES];
+        let mut weights = [[0i32; MAX_LANES]; LMS_LEN];
+        let mut history = [[0i32; MAX_LANES]; LMS_LEN];
+
+        let scalefactor_end = 1 << self.scale_factor_bits;
+
+        // the remaining candidates nearest to the previous scale factor first, they are the
+        // likeliest to win, so the batches of the far ones mostly stop after a few samples
+        let mut candidates = [0i32; 1 << 8];
+        let candidates = &mut candidates[..scalefactor_end as usize - 1];
+        for (index, candidate) in candidates.iter_mut().enumerate() {
+            let distance = index as i32 / 2 + 1;
+            *candidate = if index % 2 == 0 {
+                distance
+            } else {
+                scalefactor_end - distance
+            };
+        }
+
+        for batch_candidates in candidates.chunks(lanes) {
+            let mut batch = LaneBatch::<L>::new(search, &tables, batch_candidates, scalefactor_end);
+            let mut exceeded = false;
+
+            for (index, sample) in search.samples.iter().step_by(search.channels).enumerate() {
+                let quantized = batch.step(&tables, L::splat(*sample as i32));
+                L::store(quantized, &mut lane_residuals[index * lanes..]);
+
+                // the lane compare is signed, a rank past i64::MAX never stops a batch early
+                if best_rank < i64::MAX as u64 && L::rank_all_above(batch.rank, best_rank) {
+                    exceeded = true;
+                    break;
+                }
+            }
+
+            if exceeded {
+                continue;
+            }
+
+            L::rank_store(batch.rank, &mut ranks);
+            for tap in 0..LMS_LEN {
+                L::store(batch.weights[tap], &mut weights[tap]);
+                L::store(batch.history[tap], &mut history[tap]);
+            }
+
+            for (lane, candidate) in batch_candidates.iter().enumerate() {
+                // ties go to the candidate the scalar search would have ranked first
+                if ranks[lane] < best_rank
+                    || (ranks[lane] == best_rank && *candidate < best_candidate)
+                {
+                    best_rank = ranks[lane];
+                    best_candidate = *candidate;
+                    best_scalefactor = batch.scalefactors[lane];
+                    best_lms = SeaLMS {
+                        history: history.map(|tap| tap[lane]),
+                        weights: weights.map(|tap| tap[lane]),
+                    };
+
+                    for (index, residual) in best_residual_bits.iter_mut().enumerate() {
+                        *residual = lane_residuals[index * lanes + lane] as u8;
+                    }
+                }
+            }
+        }
+
+        (best_rank, best_lms, best_scalefactor)
+    }
+
+    /// the scale factor search with the best instruction set the cpu supports
+    fn search_scalefactor_lanes(
+        &self,
+        search: &ScaleFactorSearch,
+        best_residual_bits: &mut [u8],
+        lane_residuals: &mut Vec<i32>,
+    ) -> (u64, SeaLMS, i32) {
+        // safety: the level was detected at runtime
+        unsafe {
+            match self.simd_level {
+                #[cfg(target_arch = "x86_64")]
+                SimdLevel::Avx2 => {
+                    self.search_scalefactor_avx2(search, best_residual_bits, lane_residuals)
+                }
+                #[cfg(target_arch = "x86_64")]
+                SimdLevel::Sse41 => {
+                    self.search_scalefactor_sse41(search, best_residual_bits, lane_residuals)
+                }
+                #[cfg(target_arch = "aarch64")]
+                SimdLevel::Neon => {
+                    self.search_scalefactor_neon(search, best_residual_bits, lane_residuals)
+                }
+                SimdLevel::Scalar => unreachable!("the scalar search does not use lanes"),
+            }
+        }
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "avx2")]
+    unsafe fn search_scalefactor_avx2(
+        &self,
+        search: &ScaleFactorSearch,
+        best_residual_bits: &mut [u8],
+        lane_residuals: &mut Vec<i32>,
+    ) -> (u64, SeaLMS, i32) {
+        self.get_residuals_with_best_scalefactor_lanes::<Avx2>(
+            search,
+            best_residual_bits,
+            lane_residuals,
+        )
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "sse4.1")]
+    unsafe fn search_scalefactor_sse41(
+        &self,
+        search: &ScaleFactorSearch,
+        best_residual_bits: &mut [u8],
+        lane_residuals: &mut Vec<i32>,
+    ) -> (u64, SeaLMS, i32) {
+        self.get_residuals_with_best_scalefactor_lanes::<Sse41>(
+            search,
+            best_residual_bits,
+            lane_residuals,
+        )
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    #[target_feature(enable = "neon")]
+    unsafe fn search_scalefactor_neon(
+        &self,
+        search: &ScaleFactorSearch,
+        best_residual_bits: &mut [u8],
+        lane_residuals: &mut Vec<i32>,
+    ) -> (u64, SeaLMS, i32) {
+        self.get_residuals_with_best_scalefactor_lanes::<Neon>(
+            search,
+            best_residual_bits,
+            lane_residuals,
+        )
+    }
+
     pub fn get_residuals_for_chunk(
         &mut self,
         samples: &[i16],
@@ -157,7 +513,16 @@ impl EncoderBase {
         let mut current_residuals = mem::take(&mut self.current_residuals);
         current_residuals.resize(best_residual_bits.len(), 0);
 
+        let mut lane_residuals = mem::take(&mut self.lane_residuals);
+
         for channel_offset in 0..self.channels {
+            if self.simd_level != SimdLevel::Scalar {
+                let size = residual_size[channel_offset] as usize;
+                if self.lane_dqt[size].is_empty() {
+                    self.lane_dqt[size] = self.dequant_tab.get_dqt(size).concat();
+                }
+            }
+
             let dqt: &Vec<Vec<i32>> = self
                 .dequant_tab
                 .get_dqt(residual_size[channel_offset] as usize);
@@ -166,17 +531,30 @@ impl EncoderBase {
                 .dequant_tab
                 .get_scalefactor_reciprocals(residual_size[channel_offset] as usize);
 
-            let (best_rank, best_lms, best_scalefactor) = self.get_residuals_with_best_scalefactor(
-                self.channels,
-                dqt,
-                scalefactor_reciprocals,
-                &samples[channel_offset..],
-                self.prev_scalefactor[channel_offset],
-                &self.lms[channel_offset],
-                residual_size[channel_offset],
-                &mut best_residual_bits,
-                &mut current_residuals,
-            );
+            let (best_rank, best_lms, best_scalefactor) = if self.simd_level == SimdLevel::Scalar {
+                self.get_residuals_with_best_scalefactor(
+                    self.channels,
+                    dqt,
+                    scalefactor_reciprocals,
+                    &samples[channel_offset..],
+                    self.prev_scalefactor[channel_offset],
+                    &self.lms[channel_offset],
+                    residual_size[channel_offset],
+                    &mut best_residual_bits,
+                    &mut current_residuals,
+                )
+            } else {
+                let search = ScaleFactorSearch {
+                    channels: self.channels,
+                    samples: &samples[channel_offset..],
+                    prev_scalefactor: self.prev_scalefactor[channel_offset],
+                    lms: &self.lms[channel_offset],
+                    residual_size: residual_size[channel_offset],
+                    scalefactor_reciprocals,
+                };
+
+                self.search_scalefactor_lanes(&search, &mut best_residual_bits, &mut lane_residuals)
+            };
 
             self.prev_scalefactor[channel_offset] = best_scalefactor;
             self.lms[channel_offset] = best_lms;
@@ -192,5 +570,6 @@ impl EncoderBase {
 
         self.best_residual_bits = best_residual_bits;
         self.current_residuals = current_residuals;
+        self.lane_residuals = lane_residuals;
     }
 }
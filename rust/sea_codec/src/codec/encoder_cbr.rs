@@ -1,10 +1,12 @@
 use crate::encoder::EncoderSettings;
+use alloc::{vec, vec::Vec};
 
 use super::{
     common::{EncodedSamples, SeaEncoderTrait, SeaResidualSize},
     encoder_base::EncoderBase,
     file::SeaFileHeader,
     lms::SeaLMS,
+    math,
 };
 
 pub struct CbrEncoder {
@@ -18,7 +20,7 @@ impl CbrEncoder {
     pub fn new(file_header: &SeaFileHeader, encoder_settings: &EncoderSettings) -> Self {
         CbrEncoder {
             channels: file_header.channels as usize,
-            residual_size: SeaResidualSize::from(encoder_settings.residual_bits.floor() as u8),
+            residual_size: SeaResidualSize::from(math::floor(encoder_settings.residual_bits) as u8),
             scale_factor_frames: encoder_settings.scale_factor_frames as usize,
             base_encoder: EncoderBase::new(
                 file_header.channels as usize,
@@ -30,6 +32,14 @@ impl CbrEncoder {
     pub fn get_lms(&self) -> &Vec<SeaLMS> {
         &self.base_encoder.lms
     }
+
+    pub fn set_lms(&mut self, lms: Vec<SeaLMS>) {
+        self.base_encoder.lms = lms;
+    }
+
+    pub fn set_residual_bits(&mut self, residual_bits: f32) {
+        self.residual_size = SeaResidualSize::from(math::floor(residual_bits) as u8);
+    }
 }
 
 impl SeaEncoderTrait for CbrEncoder {
@@ -59,6 +69,7 @@ impl SeaEncoderTrait for CbrEncoder {
         }
 
         EncodedSamples {
+            residual_size: self.residual_size,
             scale_factors,
             residuals,
             residual_bits: vec![],
@@ -2,12 +2,14 @@ use crate::{
     codec::{common::SeaResidualSize, lms::LMS_LEN},
     encoder::EncoderSettings,
 };
+use alloc::{vec, vec::Vec};
 
 use super::{
     common::{EncodedSamples, SeaEncoderTrait},
     encoder_base::EncoderBase,
     file::SeaFileHeader,
     lms::SeaLMS,
+    math,
 };
 
 pub struct VbrEncoder {
@@ -19,6 +21,9 @@ pub struct VbrEncoder {
 
 // const TARGET_RESIDUAL_DISTRIBUTION: [f32; 6] = [0.00, 0.09, 0.82, 0.07, 0.02, 0.00]; // ([0, target-1, target, target+1, target+2, 0])
 const TARGET_RESIDUAL_DISTRIBUTION: [f32; 6] = [0.00, 0.00, 0.95, 0.05, 0.00, 0.00]; // TODO: it needs tuning
+/// the target bitrates whose residual sizes, including the analysis one above, stay between 1 and 8
+const MIN_TARGET_BITRATE: f32 = 1.01;
+const MAX_TARGET_BITRATE: f32 = 6.99;
 
 impl VbrEncoder {
     pub fn new(file_header: &SeaFileHeader, encoder_settings: &EncoderSettings) -> Self {
@@ -37,34 +42,56 @@ impl VbrEncoder {
         &self.base_encoder.lms
     }
 
+    pub fn set_lms(&mut self, lms: Vec<SeaLMS>) {
+        self.base_encoder.lms = lms;
+    }
+
+    pub fn set_residual_bits(&mut self, encoder_settings: &EncoderSettings) {
+        self.vbr_target_bitrate = Self::get_normalized_vbr_bitrate(encoder_settings);
+    }
+
+    /// the residual bits which keep every vbr residual size between 1 and 8
+    pub fn residual_bits_range(encoder_settings: &EncoderSettings) -> (f32, f32) {
+        let overhead = Self::get_vbr_overhead(encoder_settings);
+        (MIN_TARGET_BITRATE + overhead, MAX_TARGET_BITRATE + overhead)
+    }
+
+    /// the target is clamped so that residual bits outside of `residual_bits_range` encode at the
+    /// nearest residual sizes instead of spreading beyond 1 to 8 bits
     fn get_normalized_vbr_bitrate(encoder_settings: &EncoderSettings) -> f32 {
-        let mut vbr_bitrate = encoder_settings.residual_bits;
+        (encoder_settings.residual_bits - Self::get_vbr_overhead(encoder_settings))
+            .clamp(MIN_TARGET_BITRATE, MAX_TARGET_BITRATE)
+    }
+
+    /// the bits per sample spent on anything but the residuals
+    fn get_vbr_overhead(encoder_settings: &EncoderSettings) -> f32 {
+        let mut overhead = 0.0;
 
         // compensate lms
-        vbr_bitrate -= (LMS_LEN as f32 * 16.0 * 2.0) / encoder_settings.frames_per_chunk as f32;
+        overhead += (LMS_LEN as f32 * 16.0 * 2.0) / encoder_settings.frames_per_chunk as f32;
 
         // compensate scale factor data
-        vbr_bitrate -=
+        overhead +=
             encoder_settings.scale_factor_bits as f32 / encoder_settings.scale_factor_frames as f32;
 
         // compensate vbr data
-        vbr_bitrate -= 2.0 / encoder_settings.scale_factor_frames as f32;
+        overhead += 2.0 / encoder_settings.scale_factor_frames as f32;
 
         // compensate with target distribution
-        let base_residuals = encoder_settings.residual_bits.floor();
+        let base_residuals = math::floor(encoder_settings.residual_bits);
         let new_bitrate = TARGET_RESIDUAL_DISTRIBUTION[1] * (base_residuals - 1.0)
             + TARGET_RESIDUAL_DISTRIBUTION[2] * base_residuals
             + TARGET_RESIDUAL_DISTRIBUTION[3] * (base_residuals + 1.0)
             + TARGET_RESIDUAL_DISTRIBUTION[4] * (base_residuals + 2.0);
         let diff = new_bitrate - base_residuals;
-        vbr_bitrate -= diff;
+        overhead += diff;
 
-        vbr_bitrate
+        overhead
     }
 
     // returns items count [target-1, target, target+1, target+2]
     fn interpolate_distribution(items: usize, target_rate: f32) -> [usize; 4] {
-        let frac = target_rate.fract();
+        let frac = math::fract(target_rate);
         let om_frac = 1.0 - frac;
 
         let mut percentages = [0f32; 4];
@@ -207,6 +234,7 @@ impl SeaEncoderTrait for VbrEncoder {
         }
 
         EncodedSamples {
+            residual_size: SeaResidualSize::from(self.vbr_target_bitrate as u8),
             scale_factors,
             residuals,
             residual_bits,
@@ -0,0 +1,157 @@
+use crate::encoder::EncoderSettings;
+use alloc::vec::Vec;
+
+use super::{
+    common::{read_u16_le, SeaError},
+    file::{SeaFile, SeaFileHeader},
+};
+
+/// the sequence number and the length of the primary chunk
+const PACKET_HEADER_LEN: usize = 4;
+
+/// a packet of a stream with forward error correction
+/// u16 sequence number, u16 primary chunk length, the primary chunk
+/// and a low bitrate copy of the previous chunk filling the rest of the packet when there is one
+pub(crate) struct FecPacket<'a> {
+    pub sequence: u16,
+    pub primary: &'a [u8],
+    pub redundant: Option<&'a [u8]>,
+}
+
+impl<'a> FecPacket<'a> {
+    pub fn parse(mut packet: &'a [u8]) -> Result<Self, SeaError> {
+        if packet.len() < PACKET_HEADER_LEN {
+            return Err(SeaError::InvalidFrame);
+        }
+
+        let sequence = read_u16_le(&mut packet)?;
+        let primary_len = read_u16_le(&mut packet)? as usize;
+
+        if primary_len > packet.len() {
+            return Err(SeaError::InvalidFrame);
+        }
+
+        let (primary, redundant) = packet.split_at(primary_len);
+
+        Ok(Self {
+            sequence,
+            primary,
+            redundant: (!redundant.is_empty()).then_some(redundant),
+        })
+    }
+}
+
+/// wraps the chunks of a live stream into packets carrying the previous chunk as well
+pub(crate) struct FecEncoder {
+    /// a cbr encoder for the redundant copies
+    file: SeaFile,
+    /// the samples of the last chunk, None at the start and after silence
+    previous: Option<Vec<i16>>,
+    sequence: u16,
+}
+
+impl FecEncoder {
+    pub fn new(
+        header: &SeaFileHeader,
+        settings: &EncoderSettings,
+        residual_bits: f32,
+    ) -> Result<Self, SeaError> {
+        Ok(Self {
+            file: SeaFile::new(
+                header.clone(),
+                &Self::redundant_settings(settings, residual_bits),
+            )?,
+            previous: None,
+            sequence: 0,
+        })
+    }
+
+    /// follows changes to the primary settings and the redundant residual bits
+    pub fn update_settings(
+        &mut self,
+        settings: &EncoderSettings,
+        residual_bits: f32,
+    ) -> Result<(), SeaError> {
+        self.file
+            .update_settings(&Self::redundant_settings(settings, residual_bits))
+    }
+
+    fn redundant_settings(settings: &EncoderSettings, residual_bits: f32) -> EncoderSettings {
+        EncoderSettings {
+            residual_bits,
+            vbr: false,
+            target_bitrate: None,
+            fec_residual_bits: None,
+            ..settings.clone()
+        }
+    }
+
+    /// builds the packet for `chunk`, the encoded form of `samples`
+    pub fn packet(&mut self, samples: &[i16], chunk: &[u8]) -> Result<Vec<u8>, SeaError> {
+        let primary_len = u16::try_from(chunk.len()).map_err(|_| SeaError::InvalidParameters)?;
+
+        let redundant = match self.previous.as_ref() {
+            Some(previous) => self.file.make_chunk(previous)?,
+            None => Vec::new(),
+        };
+
+        let mut packet = Vec::with_capacity(PACKET_HEADER_LEN + chunk.len() + redundant.len());
+        packet.extend_from_slice(&self.sequence.to_le_bytes());
+        packet.extend_from_slice(&primary_len.to_le_bytes());
+        packet.extend_from_slice(chunk);
+        packet.extend_from_slice(&redundant);
+
+        self.previous = Some(samples.to_vec());
+        self.sequence = self.sequence.wrapping_add(1);
+
+        Ok(packet)
+    }
+
+    /// silence carries no sequence number, the chunk after it has nothing to repeat
+    pub fn silence(&mut self) {
+        self.previous = None;
+    }
+}
+
+/// tracks the sequence numbers of received packets to tell which chunks went missing
+/// silence carries no sequence number, so a concealed chunk may have been in the place of a
+/// silent gap rather than a packet, the concealed chunks are counted instead of taking up
+/// sequence numbers
+#[derive(Default)]
+pub(crate) struct FecDecoder {
+    /// the sequence number after the last received packet, None before the first packet
+    expected: Option<u16>,
+    /// the chunks concealed since the last received packet
+    concealed: u16,
+}
+
+impl FecDecoder {
+    /// the number of chunks missing right before the packet, None for a packet older than
+    /// one already received
+    pub fn missing(&self, packet: &FecPacket) -> Option<u16> {
+        let Some(expected) = self.expected else {
+            return Some(0);
+        };
+
+        let distance = packet.sequence.wrapping_sub(expected) as i16;
+        (distance >= 0).then_some(distance as u16)
+    }
+
+    /// whether the redundant copy in the packet should be played, it repeats the chunk right
+    /// before the packet which is only missing when fewer chunks were concealed than went missing
+    pub fn recover(&self, packet: &FecPacket) -> bool {
+        self.missing(packet)
+            .is_some_and(|missing| missing > self.concealed)
+    }
+
+    /// the packet was decoded and played
+    pub fn received(&mut self, packet: &FecPacket) {
+        self.expected = Some(packet.sequence.wrapping_add(1));
+        self.concealed = 0;
+    }
+
+    /// a concealed chunk took the place of a packet or of silence
+    pub fn concealed(&mut self) {
+        self.concealed = self.concealed.saturating_add(1);
+    }
+}
@@ -1,17 +1,97 @@
-use crate::{codec::chunk::SeaChunk, encoder::EncoderSettings, ProcessorMessage};
-use kanal::Receiver;
-use std::io::Cursor;
+use crate::{codec::chunk::SeaChunk, encoder::EncoderSettings};
+use alloc::{string::String, vec, vec::Vec};
+use core::time::Duration;
 
 use super::{
     chunk::SeaChunkType,
     common::{
-        read_u16_le, read_u32_be, read_u32_le, read_u8, SeaEncoderTrait, SeaError, SEAC_MAGIC,
+        read_u16_le, read_u32_be, read_u32_le, read_u8, ReadBytes, SeaEncoderTrait, SeaError,
+        SEAC_MAGIC,
     },
     decoder::Decoder,
     encoder_cbr::CbrEncoder,
     encoder_vbr::VbrEncoder,
+    lms::LMS_LEN,
 };
 
+/// live streams only carry the basic header
+pub const STREAM_VERSION: u8 = 1;
+/// files add a frame count, a chunk index and metadata to the header
+pub const CONTAINER_VERSION: u8 = 2;
+
+const METADATA_TITLE: u8 = 1;
+const METADATA_ARTIST: u8 = 2;
+const METADATA_LOUDNESS: u8 = 3;
+
+/// optional tags stored in a version 2 header
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SeaMetadata {
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    /// integrated loudness in LUFS
+    pub loudness: Option<f32>,
+}
+
+impl SeaMetadata {
+    /// each entry is a key byte, a u16 length and the value, unknown keys are skipped
+    fn serialize(&self) -> Result<Vec<u8>, SeaError> {
+        let mut output = Vec::new();
+
+        let entries = [
+            (
+                METADATA_TITLE,
+                self.title.as_ref().map(|t| t.as_bytes().to_vec()),
+            ),
+            (
+                METADATA_ARTIST,
+                self.artist.as_ref().map(|a| a.as_bytes().to_vec()),
+            ),
+            (
+                METADATA_LOUDNESS,
+                self.loudness.map(|l| l.to_le_bytes().to_vec()),
+            ),
+        ];
+
+        for (key, value) in entries {
+            let Some(value) = value else { continue };
+            let len = u16::try_from(value.len()).map_err(|_| SeaError::MetadataTooLarge)?;
+
+            output.push(key);
+            output.extend_from_slice(&len.to_le_bytes());
+            output.extend_from_slice(&value);
+        }
+
+        if output.len() > u16::MAX as usize {
+            return Err(SeaError::MetadataTooLarge);
+        }
+
+        Ok(output)
+    }
+
+    fn parse(mut bytes: &[u8]) -> Result<Self, SeaError> {
+        let mut metadata = Self::default();
+
+        while !bytes.is_empty() {
+            let key = read_u8(&mut bytes)?;
+            let len = read_u16_le(&mut bytes)? as usize;
+            let value = bytes.get(..len).ok_or(SeaError::InvalidFile)?;
+            bytes = &bytes[len..];
+
+            match key {
+                METADATA_TITLE => metadata.title = Some(String::from_utf8_lossy(value).into()),
+                METADATA_ARTIST => metadata.artist = Some(String::from_utf8_lossy(value).into()),
+                METADATA_LOUDNESS => {
+                    let loudness = value.try_into().map_err(|_| SeaError::InvalidFile)?;
+                    metadata.loudness = Some(f32::from_le_bytes(loudness));
+                }
+                _ => (),
+            }
+        }
+
+        Ok(metadata)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SeaFileHeader {
     pub version: u8,
@@ -19,6 +99,11 @@ pub struct SeaFileHeader {
     pub chunk_size: u16,
     pub frames_per_chunk: u16,
     pub sample_rate: u32,
+    /// the number of frames in the file, 0 when unknown
+    pub total_frames: u32,
+    /// the byte offset of the chunk index from the start of the header, 0 when there is none
+    pub index_offset: u32,
+    pub metadata: SeaMetadata,
 }
 
 impl SeaFileHeader {
@@ -29,14 +114,30 @@ impl SeaFileHeader {
             && self.sample_rate > 0
     }
 
-    pub fn from_reader(receiver: &Receiver<ProcessorMessage>) -> Result<Self, SeaError> {
-        let buffer = match receiver.recv()? {
-            ProcessorMessage::Data(data) => data,
-            _ => return Err(SeaError::InvalidFrame),
-        };
+    /// the number of interleaved samples in a full chunk
+    pub fn samples_per_chunk(&self) -> usize {
+        self.frames_per_chunk as usize * self.channels as usize
+    }
 
-        let mut reader = Cursor::new(buffer);
+    /// the size of a chunk with the largest scale factors and residuals any settings can produce
+    /// encoders may change settings mid-stream, so decoders accept chunks up to this size
+    pub fn max_chunk_size(&self) -> usize {
+        let samples = self.samples_per_chunk();
 
+        4 + LMS_LEN * 4 * self.channels as usize + samples + (samples * 2).div_ceil(8) + samples
+    }
+
+    /// the duration of the file, None when the frame count is unknown
+    pub fn duration(&self) -> Option<Duration> {
+        (self.total_frames > 0)
+            .then(|| Duration::from_secs_f64(self.total_frames as f64 / self.sample_rate as f64))
+    }
+
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, SeaError> {
+        Self::read(&mut bytes)
+    }
+
+    pub fn read<R: ReadBytes>(mut reader: R) -> Result<Self, SeaError> {
         let magic = read_u32_be(&mut reader)?;
         if magic != SEAC_MAGIC {
             return Err(SeaError::InvalidFile);
@@ -47,14 +148,31 @@ impl SeaFileHeader {
         let frames_per_chunk = read_u16_le(&mut reader)?;
         let sample_rate = read_u32_le(&mut reader)?;
 
-        let res: SeaFileHeader = Self {
+        let mut res: SeaFileHeader = Self {
             version,
             channels,
             chunk_size,
             frames_per_chunk,
             sample_rate,
+            total_frames: 0,
+            index_offset: 0,
+            metadata: SeaMetadata::default(),
         };
 
+        match version {
+            STREAM_VERSION => (),
+            CONTAINER_VERSION => {
+                res.total_frames = read_u32_le(&mut reader)?;
+                res.index_offset = read_u32_le(&mut reader)?;
+
+                let metadata_len = read_u16_le(&mut reader)? as usize;
+                let mut metadata = vec![0; metadata_len];
+                reader.read_exact(&mut metadata)?;
+                res.metadata = SeaMetadata::parse(&metadata)?;
+            }
+            _ => return Err(SeaError::UnsupportedVersion),
+        }
+
         if !res.validate() {
             return Err(SeaError::InvalidFile);
         }
@@ -62,7 +180,7 @@ impl SeaFileHeader {
         Ok(res)
     }
 
-    pub fn serialize(&self) -> Vec<u8> {
+    pub fn serialize(&self) -> Result<Vec<u8>, SeaError> {
         let mut output = Vec::new();
 
         output.extend_from_slice(&SEAC_MAGIC.to_be_bytes());
@@ -72,7 +190,16 @@ impl SeaFileHeader {
         output.extend_from_slice(&self.frames_per_chunk.to_le_bytes());
         output.extend_from_slice(&self.sample_rate.to_le_bytes());
 
-        output
+        if self.version >= CONTAINER_VERSION {
+            let metadata = self.metadata.serialize()?;
+
+            output.extend_from_slice(&self.total_frames.to_le_bytes());
+            output.extend_from_slice(&self.index_offset.to_le_bytes());
+            output.extend_from_slice(&(metadata.len() as u16).to_le_bytes());
+            output.extend_from_slice(&metadata);
+        }
+
+        Ok(output)
     }
 }
 
@@ -111,15 +238,13 @@ impl SeaFile {
         })
     }
 
-    pub fn from_reader(receiver: &Receiver<ProcessorMessage>) -> Result<Self, SeaError> {
-        let header = SeaFileHeader::from_reader(receiver)?;
-
-        Ok(SeaFile {
+    pub fn from_header(header: SeaFileHeader) -> Self {
+        SeaFile {
             header,
             decoder: None,
             encoder: None,
             encoder_settings: None,
-        })
+        }
     }
 
     pub fn make_chunk(&mut self, samples: &[i16]) -> Result<Vec<u8>, SeaError> {
@@ -136,63 +261,202 @@ impl SeaFile {
             ActiveEncoder::Vbr(encoder) => encoder.encode(samples),
         };
 
-        let chunk = SeaChunk::new(
-            &self.header,
-            &initial_lms,
-            encoder_settings,
-            encoded.scale_factors,
-            encoded.residual_bits,
-            encoded.residuals,
-        );
+        let chunk = SeaChunk::new(&self.header, &initial_lms, encoder_settings, encoded);
         let output = chunk.serialize();
 
         if self.header.chunk_size == 0 {
             self.header.chunk_size = output.len() as u16;
         }
 
-        let full_samples_len =
-            self.header.frames_per_chunk as usize * self.header.channels as usize;
-
-        if samples.len() == full_samples_len {
-            assert_eq!(self.header.chunk_size, output.len() as u16);
-        }
+        assert!(output.len() <= self.header.max_chunk_size());
 
         Ok(output)
     }
 
-    pub fn samples_from_reader(
-        &mut self,
-        receiver: &Receiver<ProcessorMessage>,
-    ) -> Result<ProcessorMessage, SeaError> {
-        let encoded = match receiver.recv()? {
-            ProcessorMessage::Data(data) => data,
-            ProcessorMessage::Silence => return Ok(ProcessorMessage::silence()),
-            _ => return Err(SeaError::InvalidFrame),
+    /// changes the residual bits from the next chunk on, the lms state carries over
+    pub fn set_residual_bits(&mut self, residual_bits: f32) {
+        let (Some(encoder_settings), Some(encoder)) =
+            (self.encoder_settings.as_mut(), self.encoder.as_mut())
+        else {
+            return;
         };
 
-        let chunk = SeaChunk::from_slice(&encoded, &self.header);
+        encoder_settings.residual_bits = residual_bits;
 
-        match chunk {
-            Ok(chunk) => {
-                if self.decoder.is_none() {
-                    self.decoder = Some(Decoder::init(
-                        self.header.channels as usize,
-                        chunk.scale_factor_bits as usize,
-                    ));
-                }
-                let decoder = self.decoder.as_mut().unwrap();
-                let decoded = match chunk.chunk_type {
-                    SeaChunkType::Cbr => decoder.decode_cbr(&chunk),
-                    SeaChunkType::Vbr => decoder.decode_vbr(&chunk),
-                };
-
-                if decoded.len() != 480 {
-                    Err(SeaError::InvalidFrame)
-                } else {
-                    Ok(ProcessorMessage::Samples(decoded.try_into().unwrap()))
-                }
+        match encoder {
+            ActiveEncoder::Cbr(encoder) => encoder.set_residual_bits(residual_bits),
+            ActiveEncoder::Vbr(encoder) => encoder.set_residual_bits(encoder_settings),
+        }
+    }
+
+    /// replaces the encoder settings from the next chunk on, the lms state carries over
+    /// and the header stays valid because every chunk describes its own settings
+    pub fn update_settings(&mut self, encoder_settings: &EncoderSettings) -> Result<(), SeaError> {
+        if encoder_settings.frames_per_chunk != self.header.frames_per_chunk
+            || encoder_settings.scale_factor_bits == 0
+            || encoder_settings.scale_factor_bits > 8
+            || encoder_settings.scale_factor_frames == 0
+            || !encoder_settings
+                .frames_per_chunk
+                .is_multiple_of(encoder_settings.scale_factor_frames as u16)
+        {
+            return Err(SeaError::InvalidParameters);
+        }
+
+        let lms = match self.encoder.as_ref() {
+            Some(ActiveEncoder::Cbr(encoder)) => encoder.get_lms().clone(),
+            Some(ActiveEncoder::Vbr(encoder)) => encoder.get_lms().clone(),
+            None => return Err(SeaError::InvalidParameters),
+        };
+
+        let encoder = if encoder_settings.vbr {
+            let mut vbr_encoder = VbrEncoder::new(&self.header, encoder_settings);
+            vbr_encoder.set_lms(lms);
+            ActiveEncoder::Vbr(vbr_encoder)
+        } else {
+            let mut cbr_encoder = CbrEncoder::new(&self.header, encoder_settings);
+            cbr_encoder.set_lms(lms);
+            ActiveEncoder::Cbr(cbr_encoder)
+        };
+
+        self.encoder = Some(encoder);
+        self.encoder_settings = Some(encoder_settings.clone());
+        Ok(())
+    }
+
+    /// the residual bits the active encoder can produce
+    pub fn residual_bits_range(&self) -> (f32, f32) {
+        match (self.encoder.as_ref(), self.encoder_settings.as_ref()) {
+            (Some(ActiveEncoder::Vbr(_)), Some(encoder_settings)) => {
+                VbrEncoder::residual_bits_range(encoder_settings)
             }
-            Err(err) => Err(err),
+            _ => (1.0, 8.99),
+        }
+    }
+
+    /// the size of a chunk with every residual at 8 bits, no chunk can be larger
+    pub fn max_chunk_size(&self) -> usize {
+        let channels = self.header.channels as usize;
+        let frames = self.header.frames_per_chunk as usize;
+
+        let (scale_factor_bits, scale_factor_frames) = self
+            .encoder_settings
+            .as_ref()
+            .map(|settings| (settings.scale_factor_bits, settings.scale_factor_frames))
+            .unwrap_or((8, 1));
+        let scale_factor_items = frames.div_ceil(scale_factor_frames as usize) * channels;
+
+        4 + LMS_LEN * 4 * channels
+            + (scale_factor_items * scale_factor_bits as usize).div_ceil(8)
+            + (scale_factor_items * 2).div_ceil(8)
+            + frames * channels
+    }
+
+    /// decodes the chunk at the start of `encoded`, also returning the number of bytes it used
+    pub fn decode_chunk(&mut self, encoded: &[u8]) -> Result<(Vec<i16>, usize), SeaError> {
+        let (chunk, encoded_len) = SeaChunk::from_slice(encoded, &self.header)?;
+
+        if self.decoder.is_none() {
+            self.decoder = Some(Decoder::init(
+                self.header.channels as usize,
+                chunk.scale_factor_bits as usize,
+            ));
+        }
+        let decoder = self.decoder.as_mut().unwrap();
+        let decoded = match chunk.chunk_type {
+            SeaChunkType::Cbr => decoder.decode_cbr(&chunk)?,
+            SeaChunkType::Vbr => decoder.decode_vbr(&chunk)?,
+        };
+
+        if decoded.len() != self.header.samples_per_chunk() {
+            Err(SeaError::InvalidFrame)
+        } else {
+            Ok((decoded, encoded_len))
+        }
+    }
+
+    /// marks a silent gap in the stream
+    pub fn silence(&mut self) {
+        if let Some(decoder) = self.decoder.as_mut() {
+            decoder.silence();
         }
     }
+
+    /// produces a replacement for a lost chunk, None when there is nothing left to conceal
+    pub fn conceal_samples(&mut self) -> Option<Vec<i16>> {
+        let frames_per_chunk = self.header.frames_per_chunk as usize;
+
+        self.decoder
+            .as_mut()
+            .and_then(|decoder| decoder.conceal(frames_per_chunk))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(version: u8) -> SeaFileHeader {
+        SeaFileHeader {
+            version,
+            channels: 2,
+            chunk_size: 1_000,
+            frames_per_chunk: 480,
+            sample_rate: 48_000,
+            total_frames: 96_000,
+            index_offset: 12_345,
+            metadata: SeaMetadata {
+                title: Some("title".into()),
+                artist: None,
+                loudness: Some(-23.0),
+            },
+        }
+    }
+
+    #[test]
+    fn container_header_round_trip() {
+        let bytes = header(CONTAINER_VERSION).serialize().unwrap();
+        let parsed = SeaFileHeader::from_bytes(&bytes).unwrap();
+
+        assert_eq!(parsed.total_frames, 96_000);
+        assert_eq!(parsed.index_offset, 12_345);
+        assert_eq!(parsed.metadata, header(CONTAINER_VERSION).metadata);
+        assert_eq!(parsed.duration(), Some(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn stream_header_has_no_container_fields() {
+        let bytes = header(STREAM_VERSION).serialize().unwrap();
+        let parsed = SeaFileHeader::from_bytes(&bytes).unwrap();
+
+        assert_eq!(bytes.len(), 14);
+        assert_eq!(parsed.total_frames, 0);
+        assert_eq!(parsed.index_offset, 0);
+        assert_eq!(parsed.metadata, SeaMetadata::default());
+    }
+
+    #[test]
+    fn unknown_metadata_is_skipped() {
+        let mut metadata = vec![99, 3, 0, 1, 2, 3];
+        metadata.extend_from_slice(&[METADATA_ARTIST, 6, 0]);
+        metadata.extend_from_slice(b"artist");
+
+        let parsed = SeaMetadata::parse(&metadata).unwrap();
+        assert_eq!(parsed.artist.as_deref(), Some("artist"));
+        assert_eq!(parsed.title, None);
+
+        // a length running past the end is an error
+        assert!(SeaMetadata::parse(&[METADATA_TITLE, 10, 0, b'a']).is_err());
+    }
+
+    #[test]
+    fn unknown_versions_fail() {
+        let mut bytes = header(STREAM_VERSION).serialize().unwrap();
+        bytes[4] = 3;
+
+        assert!(matches!(
+            SeaFileHeader::from_bytes(&bytes),
+            Err(SeaError::UnsupportedVersion)
+        ));
+    }
 }
@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 pub const LMS_LEN: usize = 4;
 
 #[derive(Debug, Clone)]
@@ -6,7 +7,7 @@ pub struct SeaLMS {
     pub weights: [i32; LMS_LEN],
 }
 
-const FLOATING_BITS: usize = 3;
+pub(crate) const FLOATING_BITS: usize = 3;
 
 impl SeaLMS {
     pub fn new() -> Self {
@@ -34,7 +35,7 @@ impl SeaLMS {
         let mut prediction: i32 = 0;
 
         for i in 0..LMS_LEN {
-            prediction += self.weights[i] * self.history[i];
+            prediction = prediction.wrapping_add(self.weights[i].wrapping_mul(self.history[i]));
         }
 
         prediction >> (16 - FLOATING_BITS)
@@ -43,7 +44,8 @@ impl SeaLMS {
     pub fn update(&mut self, sample: i16, residual: i32) {
         let delta = residual >> (FLOATING_BITS + 1);
         for i in 0..LMS_LEN {
-            self.weights[i] += if self.history[i] < 0 { -delta } else { delta };
+            let step = if self.history[i] < 0 { -delta } else { delta };
+            self.weights[i] = self.weights[i].wrapping_add(step);
         }
 
         self.history.copy_within(1.., 0);
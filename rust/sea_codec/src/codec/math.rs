@@ -0,0 +1,172 @@
+// core has no float functions which need the platform's libm, these fall back
+// to small software versions without std which are accurate enough for the
+// dequantization tables and the rate control
+
+#[cfg(feature = "std")]
+#[inline(always)]
+pub fn floor(x: f32) -> f32 {
+    x.floor()
+}
+
+#[cfg(feature = "std")]
+#[inline(always)]
+pub fn fract(x: f32) -> f32 {
+    x.fract()
+}
+
+#[cfg(feature = "std")]
+#[inline(always)]
+pub fn round(x: f32) -> f32 {
+    x.round()
+}
+
+#[cfg(feature = "std")]
+#[inline(always)]
+pub fn log2(x: f32) -> f32 {
+    x.log2()
+}
+
+#[cfg(feature = "std")]
+#[inline(always)]
+pub fn powf(x: f32, y: f32) -> f32 {
+    x.powf(y)
+}
+
+/// every f32 at least this large is already an integer
+#[cfg(not(feature = "std"))]
+const INTEGRAL: f32 = 8_388_608.0;
+
+#[cfg(not(feature = "std"))]
+pub fn floor(x: f32) -> f32 {
+    if x.is_nan() || x.abs() >= INTEGRAL {
+        return x;
+    }
+
+    let truncated = x as i32 as f32;
+    if truncated > x {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
+#[cfg(not(feature = "std"))]
+pub fn fract(x: f32) -> f32 {
+    if x.abs() >= INTEGRAL {
+        return 0.0;
+    }
+
+    x - x as i32 as f32
+}
+
+/// rounds half way cases away from zero like `f32::round`
+#[cfg(not(feature = "std"))]
+pub fn round(x: f32) -> f32 {
+    if x.is_nan() || x.abs() >= INTEGRAL {
+        return x;
+    }
+
+    // the addition is exact in f64, in f32 it can round up just below one half
+    let rounded = (x.abs() as f64 + 0.5) as i64 as f32;
+    if x < 0.0 {
+        -rounded
+    } else {
+        rounded
+    }
+}
+
+#[cfg(not(feature = "std"))]
+pub fn log2(x: f32) -> f32 {
+    log2_f64(x as f64) as f32
+}
+
+/// only meant for positive bases
+#[cfg(not(feature = "std"))]
+pub fn powf(x: f32, y: f32) -> f32 {
+    if y == 0.0 {
+        return 1.0;
+    }
+
+    exp2_f64(y as f64 * log2_f64(x as f64)) as f32
+}
+
+#[cfg(not(feature = "std"))]
+fn log2_f64(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if x.is_infinite() {
+        return x;
+    }
+
+    // x = mantissa * 2^exponent with the mantissa in [sqrt(1/2), sqrt(2))
+    let (mut mantissa, mut exponent) = split(x);
+    if mantissa > core::f64::consts::SQRT_2 {
+        mantissa /= 2.0;
+        exponent += 1;
+    }
+
+    // ln(m) = 2 * atanh((m - 1) / (m + 1)), |s| < 0.18 so the series converges quickly
+    let s = (mantissa - 1.0) / (mantissa + 1.0);
+    let s2 = s * s;
+    let mut term = s;
+    let mut sum = 0.0;
+    let mut n = 1.0;
+    while term.abs() > f64::EPSILON * 1e-3 {
+        sum += term / n;
+        term *= s2;
+        n += 2.0;
+    }
+
+    exponent as f64 + 2.0 * sum / core::f64::consts::LN_2
+}
+
+#[cfg(not(feature = "std"))]
+fn exp2_f64(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x > 1023.0 {
+        return f64::INFINITY;
+    }
+    if x < -1022.0 {
+        return 0.0;
+    }
+
+    let whole = x as i64 - (x < 0.0 && x != x as i64 as f64) as i64;
+    let fraction = (x - whole as f64) * core::f64::consts::LN_2;
+
+    // e^fraction with fraction in [0, ln 2)
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    let mut n = 1.0;
+    while term > f64::EPSILON * 1e-3 {
+        term *= fraction / n;
+        sum += term;
+        n += 1.0;
+    }
+
+    sum * f64::from_bits(((whole + 1023) as u64) << 52)
+}
+
+/// 2^54, lifts any subnormal into the normal range
+#[cfg(not(feature = "std"))]
+const SUBNORMAL_SCALE: f64 = 18_014_398_509_481_984.0;
+
+/// splits a positive, finite and normal x into a mantissa in [1, 2) and an exponent
+#[cfg(not(feature = "std"))]
+fn split(x: f64) -> (f64, i64) {
+    let bits = x.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i64;
+    if exponent == 0 {
+        // subnormal, scale it up first
+        let (mantissa, exponent) = split(x * SUBNORMAL_SCALE);
+        return (mantissa, exponent - 54);
+    }
+
+    let mantissa = f64::from_bits((bits & !(0x7ff << 52)) | (1023 << 52));
+    (mantissa, exponent - 1023)
+}
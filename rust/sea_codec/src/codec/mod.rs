@@ -6,6 +6,10 @@ mod dqt;
 mod encoder_base;
 mod encoder_cbr;
 mod encoder_vbr;
+pub mod fec;
 pub mod file;
 mod lms;
+mod math;
 mod qt;
+pub mod rate_control;
+pub mod simd;
@@ -0,0 +1,152 @@
+use super::math;
+
+/// the bit reservoir holds this many chunks worth of the target bits
+const RESERVOIR_CHUNKS: f32 = 8.0;
+/// how strongly the residual bits follow the complexity of a chunk, 1.0 is equal noise in every chunk
+const COMPLEXITY_WEIGHT: f32 = 0.5;
+/// smoothing of the long term complexity average
+const COMPLEXITY_SMOOTHING: f32 = 0.05;
+
+/// picks the residual bits of every chunk so the stream averages a target bitrate
+pub struct RateControl {
+    channels: usize,
+    /// the bits each chunk should use on average
+    target_chunk_bits: f32,
+    target_residual_bits: f32,
+    /// bits left unused by previous chunks, negative when they overspent
+    reservoir: f32,
+    max_reservoir: f32,
+    min_residual_bits: f32,
+    max_residual_bits: f32,
+    /// the long term average of log2 complexity
+    average_complexity: Option<f32>,
+}
+
+impl RateControl {
+    /// `residual_bits_range` is what the active encoder can produce
+    pub fn new(
+        target_bitrate: f32,
+        sample_rate: u32,
+        channels: usize,
+        frames_per_chunk: usize,
+        residual_bits_range: (f32, f32),
+    ) -> Self {
+        let samples_per_second = sample_rate as f32 * channels as f32;
+        let target_residual_bits = target_bitrate * 1000.0 / samples_per_second;
+        let target_chunk_bits = target_residual_bits * (frames_per_chunk * channels) as f32;
+
+        Self {
+            channels,
+            target_chunk_bits,
+            target_residual_bits,
+            reservoir: 0.0,
+            max_reservoir: target_chunk_bits * RESERVOIR_CHUNKS,
+            min_residual_bits: residual_bits_range.0,
+            max_residual_bits: residual_bits_range.1,
+            average_complexity: None,
+        }
+    }
+
+    /// the residual bits for the next chunk
+    pub fn residual_bits(&mut self, samples: &[i16]) -> f32 {
+        let complexity = self.complexity(samples);
+        let average = self.average_complexity.get_or_insert(complexity);
+
+        // complex chunks borrow bits from simple ones
+        let deviation = (complexity - *average) * COMPLEXITY_WEIGHT;
+        *average += (complexity - *average) * COMPLEXITY_SMOOTHING;
+
+        // spend the reservoir back towards zero over several chunks
+        let correction =
+            self.reservoir / RESERVOIR_CHUNKS / self.target_chunk_bits * self.target_residual_bits;
+
+        (self.target_residual_bits + deviation + correction)
+            .clamp(self.min_residual_bits, self.max_residual_bits)
+    }
+
+    /// accounts for the size of the chunk which was just encoded
+    pub fn update(&mut self, chunk_len: usize) {
+        let chunk_bits = chunk_len as f32 * 8.0;
+        self.reservoir = (self.reservoir + self.target_chunk_bits - chunk_bits)
+            .clamp(-self.max_reservoir, self.max_reservoir);
+    }
+
+    /// log2 of the mean absolute difference between consecutive samples of each channel
+    fn complexity(&self, samples: &[i16]) -> f32 {
+        let differences = samples
+            .iter()
+            .zip(samples.iter().skip(self.channels))
+            .map(|(previous, sample)| (*sample as i32 - *previous as i32).unsigned_abs() as u64);
+
+        let count = samples.len().saturating_sub(self.channels).max(1);
+        let mean = differences.sum::<u64>() as f32 / count as f32;
+        math::log2(mean + 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::encoder::{EncoderSettings, SeaChunkEncoder};
+    use alloc::vec::Vec;
+
+    const SAMPLE_RATE: u32 = 48_000;
+    const FRAMES_PER_CHUNK: usize = 480;
+
+    /// alternating quiet tones and loud noise, so the chunk complexity swings widely
+    fn signal(chunks: usize, channels: usize) -> Vec<i16> {
+        let mut state = 0x1234_5678_u32;
+        let len = chunks * FRAMES_PER_CHUNK * channels;
+
+        (0..len)
+            .map(|i| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+
+                let frame = i / channels;
+                if (frame / (FRAMES_PER_CHUNK * 10)).is_multiple_of(2) {
+                    ((frame as f32 * 0.03).sin() * 3_000.0) as i16
+                } else {
+                    (state >> 16) as i16 / 2
+                }
+            })
+            .collect()
+    }
+
+    fn achieved_bitrate(target_bitrate: f32, vbr: bool, channels: u8) -> f32 {
+        let settings = EncoderSettings {
+            frames_per_chunk: FRAMES_PER_CHUNK as u16,
+            vbr,
+            target_bitrate: Some(target_bitrate),
+            ..Default::default()
+        };
+        let mut encoder = SeaChunkEncoder::new(channels, SAMPLE_RATE, settings).unwrap();
+
+        for chunk in signal(120, channels as usize).chunks(encoder.samples_per_chunk()) {
+            encoder.encode_chunk(chunk).unwrap();
+        }
+
+        encoder.achieved_bitrate()
+    }
+
+    #[test]
+    fn converges_to_target_bitrate() {
+        for vbr in [false, true] {
+            for channels in [1, 2] {
+                for bits_per_sample in [2.5, 3.5, 5.0] {
+                    let target = bits_per_sample * (SAMPLE_RATE * channels as u32) as f32 / 1000.0;
+                    let achieved = achieved_bitrate(target, vbr, channels);
+
+                    assert!(
+                        (achieved / target - 1.0).abs() < 0.05,
+                        "vbr {} channels {} target {} achieved {}",
+                        vbr,
+                        channels,
+                        target,
+                        achieved
+                    );
+                }
+            }
+        }
+    }
+}
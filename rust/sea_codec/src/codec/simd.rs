@@ -0,0 +1,750 @@
+use core::sync::atomic::{AtomicBool, Ordering::Relaxed};
+
+#[cfg(target_arch = "aarch64")]
+use core::arch::aarch64::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+/// the widest lane count of any implementation
+pub(crate) const MAX_LANES: usize = 8;
+
+static SIMD_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// enables or disables the simd code paths for encoders created afterwards
+/// the output is identical either way, this exists to compare against the scalar implementation
+pub fn set_simd_enabled(enabled: bool) {
+    SIMD_ENABLED.store(enabled, Relaxed);
+}
+
+/// the instruction set used by the encoder's scale factor search
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SimdLevel {
+    Scalar,
+    #[cfg(target_arch = "x86_64")]
+    Sse41,
+    #[cfg(target_arch = "x86_64")]
+    Avx2,
+    #[cfg(target_arch = "aarch64")]
+    Neon,
+}
+
+impl SimdLevel {
+    /// the best level the cpu supports, detected at runtime with std
+    /// without std only the features enabled at compile time are used
+    pub(crate) fn detect() -> Self {
+        if !SIMD_ENABLED.load(Relaxed) {
+            return Self::Scalar;
+        }
+
+        #[cfg(all(target_arch = "x86_64", feature = "std"))]
+        {
+            if is_x86_feature_detected!("avx2") {
+                return Self::Avx2;
+            } else if is_x86_feature_detected!("sse4.1") {
+                return Self::Sse41;
+            }
+        }
+
+        #[cfg(all(target_arch = "x86_64", not(feature = "std")))]
+        {
+            if cfg!(target_feature = "avx2") {
+                return Self::Avx2;
+            } else if cfg!(target_feature = "sse4.1") {
+                return Self::Sse41;
+            }
+        }
+
+        #[cfg(all(target_arch = "aarch64", feature = "std"))]
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            return Self::Neon;
+        }
+
+        #[cfg(all(target_arch = "aarch64", not(feature = "std")))]
+        if cfg!(target_feature = "neon") {
+            return Self::Neon;
+        }
+
+        Self::Scalar
+    }
+}
+
+/// vector operations on i32 lanes, one lane per candidate scale factor
+/// every method is unsafe because the cpu must support the instruction set
+pub(crate) trait Lanes {
+    const LANES: usize;
+
+    type V: Copy;
+    /// a u64 rank per lane
+    type Rank: Copy;
+
+    unsafe fn splat(value: i32) -> Self::V;
+    /// loads the first `LANES` values
+    unsafe fn load(values: &[i32]) -> Self::V;
+    /// stores into the first `LANES` values
+    unsafe fn store(vector: Self::V, values: &mut [i32]);
+
+    unsafe fn add(a: Self::V, b: Self::V) -> Self::V;
+    unsafe fn sub(a: Self::V, b: Self::V) -> Self::V;
+    /// the low 32 bits of the product, like `wrapping_mul`
+    unsafe fn mul(a: Self::V, b: Self::V) -> Self::V;
+    unsafe fn min(a: Self::V, b: Self::V) -> Self::V;
+    unsafe fn max(a: Self::V, b: Self::V) -> Self::V;
+    /// arithmetic shift right
+    unsafe fn shr<const N: i32>(a: Self::V) -> Self::V;
+    /// arithmetic shift right by a count that is the same for every lane
+    unsafe fn shr_by(a: Self::V, count: i32) -> Self::V;
+    unsafe fn abs(a: Self::V) -> Self::V;
+    unsafe fn signum(a: Self::V) -> Self::V;
+    /// `-value` in lanes where `sign` is negative, `value` elsewhere
+    unsafe fn negate_if_negative(value: Self::V, sign: Self::V) -> Self::V;
+    /// `(a as i64 * b as i64 + (1 << 15)) >> 16` truncated to 32 bits
+    unsafe fn mul_round_shr16(a: Self::V, b: Self::V) -> Self::V;
+    /// `table[index]` for every lane, the indices must be in bounds
+    unsafe fn gather(table: &[i32], index: Self::V) -> Self::V;
+    /// `values[index]` for every lane, the indices must be in bounds
+    /// cheaper than a gather for a handful of values
+    unsafe fn select(index: Self::V, values: &[Self::V]) -> Self::V;
+
+    unsafe fn rank_zero() -> Self::Rank;
+    /// adds `error²` and the `SeaLMS::get_weights_penalty` of the weights to the rank of every lane
+    unsafe fn rank_add(rank: Self::Rank, error: Self::V, weights: &[Self::V; 4]) -> Self::Rank;
+    /// whether every lane is above the limit, which must be below `i64::MAX`
+    unsafe fn rank_all_above(rank: Self::Rank, limit: u64) -> bool;
+    /// stores into the first `LANES` values
+    unsafe fn rank_store(rank: Self::Rank, values: &mut [u64]);
+}
+
+/// the penalty only applies above this sum of squared weights, see `SeaLMS::get_weights_penalty`
+const PENALTY_OFFSET: i64 = 0x8ff;
+const PENALTY_SHIFT: i32 = 18;
+
+#[cfg(target_arch = "x86_64")]
+pub(crate) struct Avx2;
+
+#[cfg(target_arch = "x86_64")]
+impl Avx2 {
+    /// squares the even lanes into 64 bits, `_mm256_mul_epi32` reads the low half of every u64
+    #[inline(always)]
+    unsafe fn square_even(a: __m256i) -> __m256i {
+        _mm256_mul_epi32(a, a)
+    }
+
+    #[inline(always)]
+    unsafe fn square_odd(a: __m256i) -> __m256i {
+        let odd = _mm256_srli_epi64::<32>(a);
+        _mm256_mul_epi32(odd, odd)
+    }
+
+    /// the penalty from the summed squared weights of four lanes
+    #[inline(always)]
+    unsafe fn penalty(sum: __m256i) -> __m256i {
+        // the sum is positive, so a logical shift is exact
+        let penalty = _mm256_sub_epi64(
+            _mm256_srli_epi64::<PENALTY_SHIFT>(sum),
+            _mm256_set1_epi64x(PENALTY_OFFSET),
+        );
+        let penalty =
+            _mm256_and_si256(penalty, _mm256_cmpgt_epi64(penalty, _mm256_setzero_si256()));
+        _mm256_mul_epu32(penalty, penalty)
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+impl Lanes for Avx2 {
+    const LANES: usize = 8;
+
+    type V = __m256i;
+    /// the even lanes and the odd lanes
+    type Rank = (__m256i, __m256i);
+
+    #[inline(always)]
+    unsafe fn splat(value: i32) -> __m256i {
+        _mm256_set1_epi32(value)
+    }
+
+    #[inline(always)]
+    unsafe fn load(values: &[i32]) -> __m256i {
+        assert!(values.len() >= Self::LANES);
+        _mm256_loadu_si256(values.as_ptr() as *const __m256i)
+    }
+
+    #[inline(always)]
+    unsafe fn store(vector: __m256i, values: &mut [i32]) {
+        assert!(values.len() >= Self::LANES);
+        _mm256_storeu_si256(values.as_mut_ptr() as *mut __m256i, vector)
+    }
+
+    #[inline(always)]
+    unsafe fn add(a: __m256i, b: __m256i) -> __m256i {
+        _mm256_add_epi32(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn sub(a: __m256i, b: __m256i) -> __m256i {
+        _mm256_sub_epi32(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn mul(a: __m256i, b: __m256i) -> __m256i {
+        _mm256_mullo_epi32(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn min(a: __m256i, b: __m256i) -> __m256i {
+        _mm256_min_epi32(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn max(a: __m256i, b: __m256i) -> __m256i {
+        _mm256_max_epi32(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn shr<const N: i32>(a: __m256i) -> __m256i {
+        _mm256_srai_epi32::<N>(a)
+    }
+
+    #[inline(always)]
+    unsafe fn shr_by(a: __m256i, count: i32) -> __m256i {
+        _mm256_sra_epi32(a, _mm_cvtsi32_si128(count))
+    }
+
+    #[inline(always)]
+    unsafe fn abs(a: __m256i) -> __m256i {
+        _mm256_abs_epi32(a)
+    }
+
+    #[inline(always)]
+    unsafe fn signum(a: __m256i) -> __m256i {
+        let zero = _mm256_setzero_si256();
+        _mm256_sub_epi32(_mm256_cmpgt_epi32(zero, a), _mm256_cmpgt_epi32(a, zero))
+    }
+
+    #[inline(always)]
+    unsafe fn negate_if_negative(value: __m256i, sign: __m256i) -> __m256i {
+        // all ones where negative, (value ^ -1) - -1 is -value
+        let negative = _mm256_srai_epi32::<31>(sign);
+        _mm256_sub_epi32(_mm256_xor_si256(value, negative), negative)
+    }
+
+    #[inline(always)]
+    unsafe fn mul_round_shr16(a: __m256i, b: __m256i) -> __m256i {
+        let round = _mm256_set1_epi64x(1 << 15);
+        // the low 32 bits of the shifted value are the same for a logical and an arithmetic shift
+        let even = _mm256_srli_epi64::<16>(_mm256_add_epi64(_mm256_mul_epi32(a, b), round));
+        let odd = _mm256_mul_epi32(_mm256_srli_epi64::<32>(a), _mm256_srli_epi64::<32>(b));
+        let odd = _mm256_srli_epi64::<16>(_mm256_add_epi64(odd, round));
+        _mm256_blend_epi32::<0b1010_1010>(even, _mm256_slli_epi64::<32>(odd))
+    }
+
+    #[inline(always)]
+    unsafe fn gather(table: &[i32], index: __m256i) -> __m256i {
+        _mm256_i32gather_epi32::<4>(table.as_ptr(), index)
+    }
+
+    #[inline(always)]
+    unsafe fn select(index: __m256i, values: &[__m256i]) -> __m256i {
+        let mut selected = _mm256_setzero_si256();
+        for (i, value) in values.iter().enumerate() {
+            let matches = _mm256_cmpeq_epi32(index, _mm256_set1_epi32(i as i32));
+            selected = _mm256_or_si256(selected, _mm256_and_si256(matches, *value));
+        }
+        selected
+    }
+
+    #[inline(always)]
+    unsafe fn rank_zero() -> (__m256i, __m256i) {
+        (_mm256_setzero_si256(), _mm256_setzero_si256())
+    }
+
+    #[inline(always)]
+    unsafe fn rank_add(
+        rank: (__m256i, __m256i),
+        error: __m256i,
+        weights: &[__m256i; 4],
+    ) -> (__m256i, __m256i) {
+        let mut sum_even = _mm256_setzero_si256();
+        let mut sum_odd = _mm256_setzero_si256();
+        for weight in weights {
+            sum_even = _mm256_add_epi64(sum_even, Self::square_even(*weight));
+            sum_odd = _mm256_add_epi64(sum_odd, Self::square_odd(*weight));
+        }
+
+        let even = _mm256_add_epi64(Self::square_even(error), Self::penalty(sum_even));
+        let odd = _mm256_add_epi64(Self::square_odd(error), Self::penalty(sum_odd));
+        (
+            _mm256_add_epi64(rank.0, even),
+            _mm256_add_epi64(rank.1, odd),
+        )
+    }
+
+    #[inline(always)]
+    unsafe fn rank_all_above(rank: (__m256i, __m256i), limit: u64) -> bool {
+        let limit = _mm256_set1_epi64x(limit as i64);
+        let above = _mm256_and_si256(
+            _mm256_cmpgt_epi64(rank.0, limit),
+            _mm256_cmpgt_epi64(rank.1, limit),
+        );
+        _mm256_movemask_pd(_mm256_castsi256_pd(above)) == 0b1111
+    }
+
+    #[inline(always)]
+    unsafe fn rank_store(rank: (__m256i, __m256i), values: &mut [u64]) {
+        let mut even = [0u64; 4];
+        let mut odd = [0u64; 4];
+        _mm256_storeu_si256(even.as_mut_ptr() as *mut __m256i, rank.0);
+        _mm256_storeu_si256(odd.as_mut_ptr() as *mut __m256i, rank.1);
+
+        for i in 0..4 {
+            values[i * 2] = even[i];
+            values[i * 2 + 1] = odd[i];
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+pub(crate) struct Sse41;
+
+#[cfg(target_arch = "x86_64")]
+impl Sse41 {
+    #[inline(always)]
+    unsafe fn square_even(a: __m128i) -> __m128i {
+        _mm_mul_epi32(a, a)
+    }
+
+    #[inline(always)]
+    unsafe fn square_odd(a: __m128i) -> __m128i {
+        let odd = _mm_srli_epi64::<32>(a);
+        _mm_mul_epi32(odd, odd)
+    }
+
+    #[inline(always)]
+    unsafe fn penalty(sum: __m128i) -> __m128i {
+        let penalty = _mm_sub_epi64(
+            _mm_srli_epi64::<PENALTY_SHIFT>(sum),
+            _mm_set1_epi64x(PENALTY_OFFSET),
+        );
+        // there is no 64 bit compare before sse4.2, the sign of the high half is spread instead
+        let negative = _mm_shuffle_epi32::<0b11_11_01_01>(_mm_srai_epi32::<31>(penalty));
+        let penalty = _mm_andnot_si128(negative, penalty);
+        _mm_mul_epu32(penalty, penalty)
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+impl Lanes for Sse41 {
+    const LANES: usize = 4;
+
+    type V = __m128i;
+    /// the even lanes and the odd lanes
+    type Rank = (__m128i, __m128i);
+
+    #[inline(always)]
+    unsafe fn splat(value: i32) -> __m128i {
+        _mm_set1_epi32(value)
+    }
+
+    #[inline(always)]
+    unsafe fn load(values: &[i32]) -> __m128i {
+        assert!(values.len() >= Self::LANES);
+        _mm_loadu_si128(values.as_ptr() as *const __m128i)
+    }
+
+    #[inline(always)]
+    unsafe fn store(vector: __m128i, values: &mut [i32]) {
+        assert!(values.len() >= Self::LANES);
+        _mm_storeu_si128(values.as_mut_ptr() as *mut __m128i, vector)
+    }
+
+    #[inline(always)]
+    unsafe fn add(a: __m128i, b: __m128i) -> __m128i {
+        _mm_add_epi32(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn sub(a: __m128i, b: __m128i) -> __m128i {
+        _mm_sub_epi32(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn mul(a: __m128i, b: __m128i) -> __m128i {
+        _mm_mullo_epi32(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn min(a: __m128i, b: __m128i) -> __m128i {
+        _mm_min_epi32(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn max(a: __m128i, b: __m128i) -> __m128i {
+        _mm_max_epi32(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn shr<const N: i32>(a: __m128i) -> __m128i {
+        _mm_srai_epi32::<N>(a)
+    }
+
+    #[inline(always)]
+    unsafe fn shr_by(a: __m128i, count: i32) -> __m128i {
+        _mm_sra_epi32(a, _mm_cvtsi32_si128(count))
+    }
+
+    #[inline(always)]
+    unsafe fn abs(a: __m128i) -> __m128i {
+        _mm_abs_epi32(a)
+    }
+
+    #[inline(always)]
+    unsafe fn signum(a: __m128i) -> __m128i {
+        let zero = _mm_setzero_si128();
+        _mm_sub_epi32(_mm_cmpgt_epi32(zero, a), _mm_cmpgt_epi32(a, zero))
+    }
+
+    #[inline(always)]
+    unsafe fn negate_if_negative(value: __m128i, sign: __m128i) -> __m128i {
+        let negative = _mm_srai_epi32::<31>(sign);
+        _mm_sub_epi32(_mm_xor_si128(value, negative), negative)
+    }
+
+    #[inline(always)]
+    unsafe fn mul_round_shr16(a: __m128i, b: __m128i) -> __m128i {
+        let round = _mm_set1_epi64x(1 << 15);
+        let even = _mm_srli_epi64::<16>(_mm_add_epi64(_mm_mul_epi32(a, b), round));
+        let odd = _mm_mul_epi32(_mm_srli_epi64::<32>(a), _mm_srli_epi64::<32>(b));
+        let odd = _mm_srli_epi64::<16>(_mm_add_epi64(odd, round));
+        _mm_blend_epi16::<0b1100_1100>(even, _mm_slli_epi64::<32>(odd))
+    }
+
+    #[inline(always)]
+    unsafe fn gather(table: &[i32], index: __m128i) -> __m128i {
+        // no gather instruction, the lanes are looked up one by one
+        let mut values = [0i32; 4];
+        Self::store(index, &mut values);
+        for value in &mut values {
+            *value = table[*value as usize];
+        }
+        Self::load(&values)
+    }
+
+    #[inline(always)]
+    unsafe fn select(index: __m128i, values: &[__m128i]) -> __m128i {
+        let mut selected = _mm_setzero_si128();
+        for (i, value) in values.iter().enumerate() {
+            let matches = _mm_cmpeq_epi32(index, _mm_set1_epi32(i as i32));
+            selected = _mm_or_si128(selected, _mm_and_si128(matches, *value));
+        }
+        selected
+    }
+
+    #[inline(always)]
+    unsafe fn rank_zero() -> (__m128i, __m128i) {
+        (_mm_setzero_si128(), _mm_setzero_si128())
+    }
+
+    #[inline(always)]
+    unsafe fn rank_add(
+        rank: (__m128i, __m128i),
+        error: __m128i,
+        weights: &[__m128i; 4],
+    ) -> (__m128i, __m128i) {
+        let mut sum_even = _mm_setzero_si128();
+        let mut sum_odd = _mm_setzero_si128();
+        for weight in weights {
+            sum_even = _mm_add_epi64(sum_even, Self::square_even(*weight));
+            sum_odd = _mm_add_epi64(sum_odd, Self::square_odd(*weight));
+        }
+
+        let even = _mm_add_epi64(Self::square_even(error), Self::penalty(sum_even));
+        let odd = _mm_add_epi64(Self::square_odd(error), Self::penalty(sum_odd));
+        (_mm_add_epi64(rank.0, even), _mm_add_epi64(rank.1, odd))
+    }
+
+    #[inline(always)]
+    unsafe fn rank_all_above(rank: (__m128i, __m128i), limit: u64) -> bool {
+        let mut values = [0u64; 4];
+        Self::rank_store(rank, &mut values);
+        values.iter().all(|value| *value > limit)
+    }
+
+    #[inline(always)]
+    unsafe fn rank_store(rank: (__m128i, __m128i), values: &mut [u64]) {
+        let mut even = [0u64; 2];
+        let mut odd = [0u64; 2];
+        _mm_storeu_si128(even.as_mut_ptr() as *mut __m128i, rank.0);
+        _mm_storeu_si128(odd.as_mut_ptr() as *mut __m128i, rank.1);
+
+        for i in 0..2 {
+            values[i * 2] = even[i];
+            values[i * 2 + 1] = odd[i];
+        }
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+pub(crate) struct Neon;
+
+#[cfg(target_arch = "aarch64")]
+impl Neon {
+    /// squares two lanes into 64 bits
+    #[inline(always)]
+    unsafe fn square(a: int32x2_t) -> uint64x2_t {
+        vreinterpretq_u64_s64(vmull_s32(a, a))
+    }
+
+    #[inline(always)]
+    unsafe fn penalty(sum: int64x2_t) -> uint64x2_t {
+        let penalty = vsubq_s64(
+            vshrq_n_s64::<PENALTY_SHIFT>(sum),
+            vdupq_n_s64(PENALTY_OFFSET),
+        );
+        let penalty =
+            vreinterpretq_u64_s64(vbslq_s64(vcltzq_s64(penalty), vdupq_n_s64(0), penalty));
+        let narrow = vmovn_u64(penalty);
+        vmull_u32(narrow, narrow)
+    }
+
+    #[inline(always)]
+    unsafe fn lane_rank(rank: uint64x2_t, error: int32x2_t, weights: [int32x2_t; 4]) -> uint64x2_t {
+        let mut sum = vdupq_n_s64(0);
+        for weight in weights {
+            sum = vaddq_s64(sum, vmull_s32(weight, weight));
+        }
+
+        vaddq_u64(rank, vaddq_u64(Self::square(error), Self::penalty(sum)))
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+impl Lanes for Neon {
+    const LANES: usize = 4;
+
+    type V = int32x4_t;
+    /// the low and the high lanes
+    type Rank = (uint64x2_t, uint64x2_t);
+
+    #[inline(always)]
+    unsafe fn splat(value: i32) -> int32x4_t {
+        vdupq_n_s32(value)
+    }
+
+    #[inline(always)]
+    unsafe fn load(values: &[i32]) -> int32x4_t {
+        assert!(values.len() >= Self::LANES);
+        vld1q_s32(values.as_ptr())
+    }
+
+    #[inline(always)]
+    unsafe fn store(vector: int32x4_t, values: &mut [i32]) {
+        assert!(values.len() >= Self::LANES);
+        vst1q_s32(values.as_mut_ptr(), vector)
+    }
+
+    #[inline(always)]
+    unsafe fn add(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+        vaddq_s32(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn sub(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+        vsubq_s32(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn mul(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+        vmulq_s32(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn min(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+        vminq_s32(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn max(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+        vmaxq_s32(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn shr<const N: i32>(a: int32x4_t) -> int32x4_t {
+        vshrq_n_s32::<N>(a)
+    }
+
+    #[inline(always)]
+    unsafe fn shr_by(a: int32x4_t, count: i32) -> int32x4_t {
+        // a negative count shifts right
+        vshlq_s32(a, vdupq_n_s32(-count))
+    }
+
+    #[inline(always)]
+    unsafe fn abs(a: int32x4_t) -> int32x4_t {
+        vabsq_s32(a)
+    }
+
+    #[inline(always)]
+    unsafe fn signum(a: int32x4_t) -> int32x4_t {
+        vsubq_s32(
+            vreinterpretq_s32_u32(vcltzq_s32(a)),
+            vreinterpretq_s32_u32(vcgtzq_s32(a)),
+        )
+    }
+
+    #[inline(always)]
+    unsafe fn negate_if_negative(value: int32x4_t, sign: int32x4_t) -> int32x4_t {
+        vbslq_s32(vcltzq_s32(sign), vnegq_s32(value), value)
+    }
+
+    #[inline(always)]
+    unsafe fn mul_round_shr16(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+        let round = vdupq_n_s64(1 << 15);
+        let low = vshrq_n_s64::<16>(vaddq_s64(
+            vmull_s32(vget_low_s32(a), vget_low_s32(b)),
+            round,
+        ));
+        let high = vshrq_n_s64::<16>(vaddq_s64(vmull_high_s32(a, b), round));
+        vcombine_s32(vmovn_s64(low), vmovn_s64(high))
+    }
+
+    #[inline(always)]
+    unsafe fn gather(table: &[i32], index: int32x4_t) -> int32x4_t {
+        // no gather instruction, the lanes are looked up one by one
+        let mut values = [0i32; 4];
+        Self::store(index, &mut values);
+        for value in &mut values {
+            *value = table[*value as usize];
+        }
+        Self::load(&values)
+    }
+
+    #[inline(always)]
+    unsafe fn select(index: int32x4_t, values: &[int32x4_t]) -> int32x4_t {
+        let mut selected = vdupq_n_s32(0);
+        for (i, value) in values.iter().enumerate() {
+            let matches = vreinterpretq_s32_u32(vceqq_s32(index, vdupq_n_s32(i as i32)));
+            selected = vorrq_s32(selected, vandq_s32(matches, *value));
+        }
+        selected
+    }
+
+    #[inline(always)]
+    unsafe fn rank_zero() -> (uint64x2_t, uint64x2_t) {
+        (vdupq_n_u64(0), vdupq_n_u64(0))
+    }
+
+    #[inline(always)]
+    unsafe fn rank_add(
+        rank: (uint64x2_t, uint64x2_t),
+        error: int32x4_t,
+        weights: &[int32x4_t; 4],
+    ) -> (uint64x2_t, uint64x2_t) {
+        (
+            Self::lane_rank(
+                rank.0,
+                vget_low_s32(error),
+                weights.map(|weight| vget_low_s32(weight)),
+            ),
+            Self::lane_rank(
+                rank.1,
+                vget_high_s32(error),
+                weights.map(|weight| vget_high_s32(weight)),
+            ),
+        )
+    }
+
+    #[inline(always)]
+    unsafe fn rank_all_above(rank: (uint64x2_t, uint64x2_t), limit: u64) -> bool {
+        let limit = vdupq_n_u64(limit);
+        let above = vandq_u64(vcgtq_u64(rank.0, limit), vcgtq_u64(rank.1, limit));
+        vgetq_lane_u64::<0>(above) & vgetq_lane_u64::<1>(above) != 0
+    }
+
+    #[inline(always)]
+    unsafe fn rank_store(rank: (uint64x2_t, uint64x2_t), values: &mut [u64]) {
+        assert!(values.len() >= Self::LANES);
+        vst1q_u64(values.as_mut_ptr(), rank.0);
+        vst1q_u64(values.as_mut_ptr().add(2), rank.1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoder::{EncoderSettings, SeaChunkEncoder};
+    use alloc::vec::Vec;
+
+    const FRAMES_PER_CHUNK: usize = 480;
+
+    /// a tone over noise, with a loud burst so the scale factors move
+    fn signal(channels: usize) -> Vec<i16> {
+        let mut state = 0x9e37_79b9_u32;
+
+        (0..FRAMES_PER_CHUNK * 4 * channels)
+            .map(|i| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+
+                let frame = i / channels;
+                let level = if (700..900).contains(&frame) {
+                    20_000.0
+                } else {
+                    6_000.0
+                };
+                let tone = (frame as f32 * (0.02 + 0.01 * (i % channels) as f32)).sin() * level;
+                let noise = (state >> 20) as f32 - 2048.0;
+                (tone + noise) as i16
+            })
+            .collect()
+    }
+
+    fn encode(settings: &EncoderSettings, channels: usize, samples: &[i16]) -> Vec<Vec<u8>> {
+        let mut encoder = SeaChunkEncoder::new(channels as u8, 48_000, settings.clone()).unwrap();
+
+        samples
+            .chunks(encoder.samples_per_chunk())
+            .map(|chunk| encoder.encode_chunk(chunk).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn simd_matches_scalar() {
+        for vbr in [false, true] {
+            for channels in [1, 2] {
+                let samples = signal(channels);
+
+                // vbr picks sizes around the target, these cover every size from 1 to 8
+                // targets near the ends of the range can not be encoded with vbr
+                let targets: &[f32] = if vbr {
+                    &[2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 7.4]
+                } else {
+                    &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]
+                };
+
+                for &residual_bits in targets {
+                    let settings = EncoderSettings {
+                        frames_per_chunk: FRAMES_PER_CHUNK as u16,
+                        residual_bits,
+                        vbr,
+                        ..Default::default()
+                    };
+
+                    set_simd_enabled(false);
+                    let scalar = encode(&settings, channels, &samples);
+                    set_simd_enabled(true);
+                    let simd = encode(&settings, channels, &samples);
+
+                    assert_eq!(
+                        scalar,
+                        simd,
+                        "vbr {} channels {} residual bits {} with {:?}",
+                        vbr,
+                        channels,
+                        residual_bits,
+                        SimdLevel::detect()
+                    );
+                }
+            }
+        }
+    }
+}
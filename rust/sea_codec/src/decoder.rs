@@ -1,36 +1,237 @@
 use crate::codec::{
-    common::SeaError,
+    common::{SeaError, SEAC_MAGIC},
+    fec::{FecDecoder, FecPacket},
     file::{SeaFile, SeaFileHeader},
 };
+#[cfg(feature = "std")]
 use crate::ProcessorMessage;
-use kanal::{Receiver, Sender};
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "std")]
+use core::time::Duration;
+#[cfg(feature = "std")]
+use kanal::{ReceiveError, ReceiveErrorTimeout, Receiver, Sender};
 
+/// decodes a stream chunk by chunk without any channels or threads
+pub struct SeaChunkDecoder {
+    file: SeaFile,
+    /// present when the stream carries forward error correction
+    fec: Option<FecDecoder>,
+}
+
+impl SeaChunkDecoder {
+    /// creates a decoder from a serialized file header
+    pub fn new(header: &[u8]) -> Result<Self, SeaError> {
+        let header = SeaFileHeader::from_bytes(header)?;
+
+        Ok(Self::from_header(header))
+    }
+
+    pub(crate) fn from_header(header: SeaFileHeader) -> Self {
+        Self {
+            file: SeaFile::from_header(header),
+            fec: None,
+        }
+    }
+
+    /// the header of a restarted stream when `packet` carries one instead of a chunk
+    /// chunks start with their type, so they never start with the magic. fec packets start with
+    /// a little endian sequence number and the primary chunk length, for the magic those would be
+    /// sequence 0x6573 with a primary chunk of 0x6361 (25441) bytes, far larger than a live chunk
+    pub fn restart_header(packet: &[u8]) -> Result<Option<SeaFileHeader>, SeaError> {
+        if !packet.starts_with(&SEAC_MAGIC.to_be_bytes()) {
+            return Ok(None);
+        }
+
+        SeaFileHeader::from_bytes(packet)
+            .map(Some)
+            .map_err(|_| SeaError::InvalidFrame)
+    }
+
+    /// whether `packet` carries a redundant copy of the chunk before it, so a receiver can leave
+    /// that chunk to `decode_packet` instead of concealing it
+    pub fn carries_redundant(packet: &[u8]) -> bool {
+        !packet.starts_with(&SEAC_MAGIC.to_be_bytes())
+            && FecPacket::parse(packet).is_ok_and(|packet| packet.redundant.is_some())
+    }
+
+    /// continues with a new stream, forward error correction stays as it was
+    pub fn restart(&mut self, header: SeaFileHeader) {
+        let fec = self.fec.is_some();
+        *self = Self::from_header(header);
+        self.set_fec(fec);
+    }
+
+    /// whether the packets carry forward error correction, this is agreed on outside of the stream
+    pub fn set_fec(&mut self, enabled: bool) {
+        if enabled != self.fec.is_some() {
+            self.fec = enabled.then(FecDecoder::default);
+        }
+    }
+
+    /// decodes one chunk into `frames_per_chunk * channels` interleaved samples
+    pub fn decode_chunk(&mut self, chunk: &[u8]) -> Result<Vec<i16>, SeaError> {
+        self.file.decode_chunk(chunk).map(|(samples, _)| samples)
+    }
+
+    /// decodes the chunk at the start of `bytes`, also returning the number of bytes it used
+    #[cfg(feature = "std")]
+    pub(crate) fn decode_prefix(&mut self, bytes: &[u8]) -> Result<(Vec<i16>, usize), SeaError> {
+        self.file.decode_chunk(bytes)
+    }
+
+    /// decodes a packet from `SeaChunkEncoder::encode_packet` into the chunks it yields in order
+    /// with forward error correction the recovered previous chunk comes first when it was missing
+    /// and was not concealed, a packet older than one already decoded yields nothing
+    pub fn decode_packet(&mut self, packet: &[u8]) -> Result<Vec<Vec<i16>>, SeaError> {
+        let Some(fec) = self.fec.as_ref() else {
+            return Ok(vec![self.decode_chunk(packet)?]);
+        };
+
+        let packet = FecPacket::parse(packet)?;
+        if fec.missing(&packet).is_none() {
+            return Ok(Vec::new());
+        }
+
+        let mut chunks = Vec::with_capacity(2);
+
+        // only the chunk right before can be recovered, older gaps have been concealed already
+        if let Some(redundant) = packet.redundant.filter(|_| fec.recover(&packet)) {
+            // a damaged copy is no worse than the loss it was meant to cover
+            if let Ok(samples) = self.decode_chunk(redundant) {
+                chunks.push(samples);
+            }
+        }
+
+        chunks.push(self.decode_chunk(packet.primary)?);
+
+        if let Some(fec) = self.fec.as_mut() {
+            fec.received(&packet);
+        }
+
+        Ok(chunks)
+    }
+
+    /// produces a replacement for a lost chunk, None when there is nothing left to conceal
+    pub fn conceal_chunk(&mut self) -> Option<Vec<i16>> {
+        if let Some(fec) = self.fec.as_mut() {
+            fec.concealed();
+        }
+
+        self.file.conceal_samples()
+    }
+
+    /// marks a silent gap so concealment does not extrapolate across it
+    pub fn silence(&mut self) {
+        self.file.silence();
+    }
+
+    pub fn header(&self) -> &SeaFileHeader {
+        &self.file.header
+    }
+}
+
+/// decodes the chunks arriving on a channel, only available with std
+#[cfg(feature = "std")]
 pub struct SeaDecoder {
     receiver: Receiver<ProcessorMessage>,
     sender: Sender<ProcessorMessage>,
-    file: SeaFile,
+    decoder: SeaChunkDecoder,
     frames_read: usize,
 }
 
+#[cfg(feature = "std")]
 impl SeaDecoder {
     pub fn new(
         receiver: Receiver<ProcessorMessage>,
         sender: Sender<ProcessorMessage>,
     ) -> Result<Self, SeaError> {
-        let file = SeaFile::from_reader(&receiver)?;
+        let decoder = match receiver.recv()? {
+            ProcessorMessage::Data(header) => SeaChunkDecoder::new(&header)?,
+            _ => return Err(SeaError::InvalidFrame),
+        };
 
         Ok(Self {
             receiver,
             sender,
-            file,
+            decoder,
             frames_read: 0,
         })
     }
 
+    /// whether the packets carry forward error correction, see `SeaChunkDecoder::set_fec`
+    pub fn set_fec(&mut self, enabled: bool) {
+        self.decoder.set_fec(enabled);
+    }
+
     pub fn decode_frame(&mut self) -> Result<(), SeaError> {
-        let message = self.file.samples_from_reader(&self.receiver)?;
+        let message = self.receiver.recv()?;
+        self.decode_message(message)
+    }
+
+    /// decodes the next frame if one arrives within the timeout
+    /// returns false when the timeout elapsed, which the caller may treat as a lost frame
+    /// with forward error correction a frame may send a recovered frame ahead of it or nothing at all
+    pub fn decode_frame_timeout(&mut self, timeout: Duration) -> Result<bool, SeaError> {
+        let message = match self.receiver.recv_timeout(timeout) {
+            Ok(message) => message,
+            Err(ReceiveErrorTimeout::Timeout) => return Ok(false),
+            Err(ReceiveErrorTimeout::Closed) => return Err(ReceiveError::Closed.into()),
+            Err(ReceiveErrorTimeout::SendClosed) => return Err(ReceiveError::SendClosed.into()),
+        };
+
+        self.decode_message(message)?;
+        Ok(true)
+    }
+
+    /// conceals the next frame in place of one that never arrived
+    /// returns false when nothing was sent because the concealment has faded out
+    pub fn conceal_frame(&mut self) -> Result<bool, SeaError> {
+        if let Some(samples) = self.decoder.conceal_chunk() {
+            self.send(ProcessorMessage::samples(samples))?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// a packet may send a recovered frame ahead of its own, or nothing when it arrived too late
+    /// a new header restarts the decoder and is passed on as `ProcessorMessage::Restart`
+    /// a lost frame is concealed, or becomes silence once the concealment has faded out
+    /// silence is passed on with any comfort noise description it carries
+    fn decode_message(&mut self, message: ProcessorMessage) -> Result<(), SeaError> {
+        match message {
+            ProcessorMessage::Data(packet) => {
+                if let Some(header) = SeaChunkDecoder::restart_header(&packet)? {
+                    self.decoder.restart(header.clone());
+                    return self.send(ProcessorMessage::Restart(header));
+                }
+
+                for samples in self.decoder.decode_packet(&packet)? {
+                    self.send(ProcessorMessage::samples(samples))?;
+                }
+
+                Ok(())
+            }
+            message @ (ProcessorMessage::Silence | ProcessorMessage::ComfortNoise(_)) => {
+                self.decoder.silence();
+                self.send(message)
+            }
+            ProcessorMessage::Lost => {
+                if self.conceal_frame()? {
+                    Ok(())
+                } else {
+                    self.send(ProcessorMessage::silence())
+                }
+            }
+            _ => Err(SeaError::InvalidFrame),
+        }
+    }
+
+    fn send(&mut self, message: ProcessorMessage) -> Result<(), SeaError> {
+        if matches!(message, ProcessorMessage::Samples(_)) {
+            self.frames_read += self.decoder.header().frames_per_chunk as usize;
+        }
 
-        self.frames_read += 480;
         self.sender.send(message)?;
         Ok(())
     }
@@ -40,6 +241,163 @@ impl SeaDecoder {
     }
 
     pub fn get_header(&self) -> SeaFileHeader {
-        self.file.header.clone()
+        self.decoder.header().clone()
+    }
+
+    /// the number of frames (samples per channel) decoded so far
+    pub fn frames_read(&self) -> usize {
+        self.frames_read
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoder::{EncoderSettings, SeaChunkEncoder};
+
+    const FRAMES_PER_CHUNK: usize = 480;
+
+    fn encoder(channels: u8, fec: bool) -> SeaChunkEncoder {
+        let settings = EncoderSettings {
+            frames_per_chunk: FRAMES_PER_CHUNK as u16,
+            fec_residual_bits: fec.then_some(2.0),
+            ..Default::default()
+        };
+        SeaChunkEncoder::new(channels, 48_000, settings).unwrap()
+    }
+
+    fn tone(channels: usize) -> Vec<i16> {
+        (0..FRAMES_PER_CHUNK * channels)
+            .map(|i| ((i as f32 * 0.04).sin() * 5_000.0) as i16)
+            .collect()
+    }
+
+    #[test]
+    fn detects_restart_headers() {
+        for fec in [false, true] {
+            let mut encoder = encoder(1, fec);
+            let first = encoder.encode_packet(&tone(1)).unwrap();
+            let second = encoder.encode_packet(&tone(1)).unwrap();
+            let header = encoder.header().unwrap().unwrap();
+
+            assert!(SeaChunkDecoder::restart_header(&first).unwrap().is_none());
+            assert!(SeaChunkDecoder::restart_header(&second).unwrap().is_none());
+
+            let restart = SeaChunkDecoder::restart_header(&header).unwrap().unwrap();
+            assert_eq!(restart.frames_per_chunk, FRAMES_PER_CHUNK as u16);
+        }
+
+        // starts like a header but can not be parsed as one
+        assert!(SeaChunkDecoder::restart_header(b"seac").is_err());
+    }
+
+    #[test]
+    fn decodes_after_restart() {
+        let mut mono = encoder(1, false);
+        let packet = mono.encode_packet(&tone(1)).unwrap();
+        let mut decoder = SeaChunkDecoder::new(&mono.header().unwrap().unwrap()).unwrap();
+        assert_eq!(
+            decoder.decode_packet(&packet).unwrap()[0].len(),
+            FRAMES_PER_CHUNK
+        );
+
+        // the remote restarted in stereo with forward error correction
+        let mut stereo = encoder(2, true);
+        let packet = stereo.encode_packet(&tone(2)).unwrap();
+        let header = stereo.header().unwrap().unwrap();
+        decoder.set_fec(true);
+        decoder.restart(SeaChunkDecoder::restart_header(&header).unwrap().unwrap());
+
+        assert_eq!(decoder.header().channels, 2);
+        let chunks = decoder.decode_packet(&packet).unwrap();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].len(), FRAMES_PER_CHUNK * 2);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn passes_restarts_on() {
+        let mut first = encoder(1, false);
+        let packet = first.encode_packet(&tone(1)).unwrap();
+        let mut second = encoder(2, false);
+        let restarted = second.encode_packet(&tone(2)).unwrap();
+
+        let (input, receiver) = kanal::unbounded();
+        let (sender, output) = kanal::unbounded();
+        input
+            .send(ProcessorMessage::Data(
+                first.header().unwrap().unwrap().into(),
+            ))
+            .unwrap();
+        let mut decoder = SeaDecoder::new(receiver, sender).unwrap();
+
+        for message in [packet, second.header().unwrap().unwrap(), restarted] {
+            input.send(ProcessorMessage::Data(message.into())).unwrap();
+            decoder.decode_frame().unwrap();
+        }
+
+        let samples = |message| match message {
+            ProcessorMessage::Samples(samples) => samples.len(),
+            message => panic!("expected samples, got {:?}", message),
+        };
+
+        assert_eq!(samples(output.recv().unwrap()), FRAMES_PER_CHUNK);
+        match output.recv().unwrap() {
+            ProcessorMessage::Restart(header) => assert_eq!(header.channels, 2),
+            message => panic!("expected a restart, got {:?}", message),
+        }
+        assert_eq!(samples(output.recv().unwrap()), FRAMES_PER_CHUNK * 2);
+        assert_eq!(decoder.frames_read(), FRAMES_PER_CHUNK * 2);
+    }
+
+    /// the packets of a fec stream and a decoder for it
+    fn fec_stream(chunks: usize) -> (Vec<Vec<u8>>, SeaChunkDecoder) {
+        let mut encoder = encoder(1, true);
+        let packets: Vec<Vec<u8>> = (0..chunks)
+            .map(|_| encoder.encode_packet(&tone(1)).unwrap())
+            .collect();
+
+        let mut decoder = SeaChunkDecoder::new(&encoder.header().unwrap().unwrap()).unwrap();
+        decoder.set_fec(true);
+        (packets, decoder)
+    }
+
+    #[test]
+    fn recovers_a_lost_packet() {
+        let (packets, mut decoder) = fec_stream(4);
+
+        assert!(!SeaChunkDecoder::carries_redundant(&packets[0]));
+        assert!(SeaChunkDecoder::carries_redundant(&packets[2]));
+
+        assert_eq!(decoder.decode_packet(&packets[0]).unwrap().len(), 1);
+        // the second packet never arrives, the third carries a copy of it
+        assert_eq!(decoder.decode_packet(&packets[2]).unwrap().len(), 2);
+        assert_eq!(decoder.decode_packet(&packets[3]).unwrap().len(), 1);
+        // a packet older than the ones decoded is dropped
+        assert!(decoder.decode_packet(&packets[1]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn recovers_after_concealment() {
+        let (packets, mut decoder) = fec_stream(5);
+        decoder.decode_packet(&packets[0]).unwrap();
+
+        // two packets go missing, the first is concealed and the second recovered
+        assert!(decoder.conceal_chunk().is_some());
+        assert_eq!(decoder.decode_packet(&packets[3]).unwrap().len(), 2);
+
+        // the chunk was concealed, its copy is not played as well
+        assert!(decoder.conceal_chunk().is_some());
+        assert_eq!(decoder.decode_packet(&packets[4]).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn concealed_silence_does_not_drop_packets() {
+        let (packets, mut decoder) = fec_stream(2);
+        decoder.decode_packet(&packets[0]).unwrap();
+
+        // a silence frame carries no packet, concealing it in place leaves the next packet intact
+        assert!(decoder.conceal_chunk().is_some());
+        assert_eq!(decoder.decode_packet(&packets[1]).unwrap().len(), 1);
     }
 }
@@ -1,10 +1,16 @@
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
 use bytes::Bytes;
+#[cfg(feature = "std")]
 use kanal::{Receiver, Sender};
 
 use crate::codec::{
     common::SeaError,
-    file::{SeaFile, SeaFileHeader},
+    fec::FecEncoder,
+    file::{SeaFile, SeaFileHeader, STREAM_VERSION},
+    rate_control::RateControl,
 };
+#[cfg(feature = "std")]
 use crate::ProcessorMessage;
 
 pub enum SeaEncoderState {
@@ -20,6 +26,11 @@ pub struct EncoderSettings {
     pub residual_bits: f32, // 1-8
     pub frames_per_chunk: u16,
     pub vbr: bool,
+    /// target bitrate in kbps, when set residual_bits is chosen for every chunk to hit it on average
+    pub target_bitrate: Option<f32>,
+    /// residual bits of the redundant copy of the previous chunk each packet carries
+    /// None turns forward error correction off, it can not be turned on or off mid-stream
+    pub fec_residual_bits: Option<f32>,
 }
 
 impl Default for EncoderSettings {
@@ -30,36 +41,217 @@ impl Default for EncoderSettings {
             scale_factor_frames: 20,
             residual_bits: 3.0,
             vbr: false,
+            target_bitrate: None,
+            fec_residual_bits: None,
         }
     }
 }
 
-pub struct SeaEncoder {
-    receiver: Receiver<ProcessorMessage>,
-    sender: Sender<ProcessorMessage>,
+/// encodes a stream chunk by chunk without any channels or threads
+pub struct SeaChunkEncoder {
     file: SeaFile,
-    state: SeaEncoderState,
-    written_frames: u32,
+    rate_control: Option<RateControl>,
+    fec: Option<FecEncoder>,
+    chunks_written: usize,
+    bytes_written: u64,
 }
 
-impl SeaEncoder {
+impl SeaChunkEncoder {
     pub fn new(
         channels: u8,
         sample_rate: u32,
         settings: EncoderSettings,
-        receiver: Receiver<ProcessorMessage>,
-        sender: Sender<ProcessorMessage>,
     ) -> Result<Self, SeaError> {
+        if channels == 0 {
+            return Err(SeaError::InvalidParameters);
+        }
+        Self::validate_settings(&settings)?;
+
         let header = SeaFileHeader {
-            version: 1,
+            version: STREAM_VERSION,
             channels,
             chunk_size: 0, // will be set later by the first chunk
             frames_per_chunk: settings.frames_per_chunk,
             sample_rate,
+            total_frames: 0,
+            index_offset: 0,
+            metadata: Default::default(),
         };
 
+        let fec = settings
+            .fec_residual_bits
+            .map(|residual_bits| FecEncoder::new(&header, &settings, residual_bits))
+            .transpose()?;
+        let file = SeaFile::new(header, &settings)?;
+
+        let mut encoder = Self {
+            file,
+            rate_control: None,
+            fec,
+            chunks_written: 0,
+            bytes_written: 0,
+        };
+        encoder.init_rate_control(&settings)?;
+        Ok(encoder)
+    }
+
+    /// changes the settings from the next chunk on without resetting the lms state
+    /// the stream header stays the same, so `frames_per_chunk` can not change
+    pub fn update_settings(&mut self, settings: EncoderSettings) -> Result<(), SeaError> {
+        Self::validate_settings(&settings)?;
+
+        // the packet layout is part of the stream
+        match (self.fec.as_mut(), settings.fec_residual_bits) {
+            (Some(fec), Some(residual_bits)) => fec.update_settings(&settings, residual_bits)?,
+            (None, None) => (),
+            _ => return Err(SeaError::InvalidParameters),
+        }
+
+        self.file.update_settings(&settings)?;
+        self.init_rate_control(&settings)
+    }
+
+    fn validate_settings(settings: &EncoderSettings) -> Result<(), SeaError> {
+        if settings.frames_per_chunk == 0
+            || settings.scale_factor_bits == 0
+            || settings.scale_factor_bits > 8
+            || settings.scale_factor_frames == 0
+            || !settings
+                .frames_per_chunk
+                .is_multiple_of(settings.scale_factor_frames as u16)
+            || !(1.0..9.0).contains(&settings.residual_bits)
+            || settings
+                .fec_residual_bits
+                .is_some_and(|residual_bits| !(1.0..9.0).contains(&residual_bits))
+        {
+            return Err(SeaError::InvalidParameters);
+        }
+
+        if let Some(target_bitrate) = settings.target_bitrate {
+            if !target_bitrate.is_finite() || target_bitrate <= 0.0 {
+                return Err(SeaError::InvalidParameters);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn init_rate_control(&mut self, settings: &EncoderSettings) -> Result<(), SeaError> {
+        let header = &self.file.header;
+
+        self.rate_control = settings.target_bitrate.map(|target_bitrate| {
+            RateControl::new(
+                target_bitrate,
+                header.sample_rate,
+                header.channels as usize,
+                header.frames_per_chunk as usize,
+                self.file.residual_bits_range(),
+            )
+        });
+
+        // chunk sizes vary under rate control, so the header carries the largest possible size
+        // once the header is out it stays as it is, decoders accept any chunk up to the header maximum
+        if self.chunks_written == 0 {
+            self.file.header.chunk_size = match self.rate_control {
+                Some(_) => u16::try_from(self.file.max_chunk_size())
+                    .map_err(|_| SeaError::InvalidParameters)?,
+                None => 0, // will be set later by the first chunk
+            };
+        }
+
+        Ok(())
+    }
+
+    /// encodes `frames_per_chunk * channels` interleaved samples into one chunk
+    pub fn encode_chunk(&mut self, samples: &[i16]) -> Result<Vec<u8>, SeaError> {
+        // the stream only carries full chunks, every chunk must have the same size
+        if samples.len() != self.file.header.samples_per_chunk() {
+            return Err(SeaError::InvalidFrame);
+        }
+
+        if let Some(rate_control) = self.rate_control.as_mut() {
+            self.file
+                .set_residual_bits(rate_control.residual_bits(samples));
+        }
+
+        let encoded_chunk = self.file.make_chunk(samples)?;
+
+        if let Some(rate_control) = self.rate_control.as_mut() {
+            rate_control.update(encoded_chunk.len());
+        }
+
+        self.chunks_written += 1;
+        self.bytes_written += encoded_chunk.len() as u64;
+        Ok(encoded_chunk)
+    }
+
+    /// encodes one chunk into a packet for a live stream
+    /// with forward error correction the packet also carries a redundant copy of the previous chunk,
+    /// otherwise it is just the chunk
+    pub fn encode_packet(&mut self, samples: &[i16]) -> Result<Vec<u8>, SeaError> {
+        let encoded_chunk = self.encode_chunk(samples)?;
+
+        match self.fec.as_mut() {
+            Some(fec) => fec.packet(samples, &encoded_chunk),
+            None => Ok(encoded_chunk),
+        }
+    }
+
+    /// marks a silent gap, the packet after it has no previous chunk to repeat
+    pub fn silence(&mut self) {
+        if let Some(fec) = self.fec.as_mut() {
+            fec.silence();
+        }
+    }
+
+    /// the average bitrate of the chunks encoded so far in kbps
+    pub fn achieved_bitrate(&self) -> f32 {
+        let header = &self.file.header;
+        let frames = self.chunks_written as f64 * header.frames_per_chunk as f64;
+        if frames == 0.0 {
+            return 0.0;
+        }
+
+        let seconds = frames / header.sample_rate as f64;
+        (self.bytes_written as f64 * 8.0 / seconds / 1000.0) as f32
+    }
+
+    /// the serialized stream header, None until the first chunk has set the chunk size
+    pub fn header(&self) -> Result<Option<Vec<u8>>, SeaError> {
+        self.file_header().map(SeaFileHeader::serialize).transpose()
+    }
+
+    pub(crate) fn file_header(&self) -> Option<&SeaFileHeader> {
+        (self.chunks_written > 0).then_some(&self.file.header)
+    }
+
+    /// the number of interleaved samples each chunk must contain
+    pub fn samples_per_chunk(&self) -> usize {
+        self.file.header.samples_per_chunk()
+    }
+}
+
+/// encodes the samples arriving on a channel, only available with std
+#[cfg(feature = "std")]
+pub struct SeaEncoder {
+    receiver: Receiver<ProcessorMessage>,
+    sender: Sender<ProcessorMessage>,
+    encoder: SeaChunkEncoder,
+    state: SeaEncoderState,
+    written_frames: u32,
+}
+
+#[cfg(feature = "std")]
+impl SeaEncoder {
+    pub fn new(
+        channels: u8,
+        sample_rate: u32,
+        settings: EncoderSettings,
+        receiver: Receiver<ProcessorMessage>,
+        sender: Sender<ProcessorMessage>,
+    ) -> Result<Self, SeaError> {
         Ok(SeaEncoder {
-            file: SeaFile::new(header, &settings)?,
+            encoder: SeaChunkEncoder::new(channels, sample_rate, settings)?,
             state: SeaEncoderState::Start,
             receiver,
             sender,
@@ -72,40 +264,169 @@ impl SeaEncoder {
             return Err(SeaError::EncoderClosed);
         }
 
-        let frames = self.file.header.frames_per_chunk as usize;
-
         let samples = match self.receiver.recv()? {
             ProcessorMessage::Samples(samples) => samples,
-            ProcessorMessage::Silence => {
-                self.sender.send(ProcessorMessage::silence())?;
+            // the noise description passes through as it is
+            message @ (ProcessorMessage::Silence | ProcessorMessage::ComfortNoise(_)) => {
+                self.encoder.silence();
+                self.sender.send(message)?;
                 return Ok(());
             }
             _ => return Err(SeaError::InvalidFrame),
         };
 
-        if !samples.is_empty() {
-            let encoded_chunk = self.file.make_chunk(samples.as_ref())?;
-
-            assert_eq!(encoded_chunk.len(), self.file.header.chunk_size as usize);
+        let encoded_packet = self.encoder.encode_packet(&samples)?;
 
-            // we need to write file header after the first chunk is generated
-            if matches!(self.state, SeaEncoderState::Start) {
-                self.sender.send(ProcessorMessage::Data(Bytes::from(
-                    self.file.header.serialize(),
-                )))?;
-                self.state = SeaEncoderState::WritingFrames;
+        // we need to write file header after the first chunk is generated
+        if matches!(self.state, SeaEncoderState::Start) {
+            if let Some(header) = self.encoder.header()? {
+                self.sender
+                    .send(ProcessorMessage::Data(Bytes::from(header)))?;
             }
-
-            self.sender
-                .send(ProcessorMessage::Data(Bytes::from(encoded_chunk)))?;
-            self.written_frames += frames as u32;
+            self.state = SeaEncoderState::WritingFrames;
         }
 
+        self.sender
+            .send(ProcessorMessage::Data(Bytes::from(encoded_packet)))?;
+        self.written_frames += self.encoder.file.header.frames_per_chunk as u32;
+
         Ok(())
     }
 
+    /// changes the settings of the following chunks, see `SeaChunkEncoder::update_settings`
+    pub fn update_settings(&mut self, settings: EncoderSettings) -> Result<(), SeaError> {
+        self.encoder.update_settings(settings)
+    }
+
+    /// the average bitrate of the chunks encoded so far in kbps
+    pub fn achieved_bitrate(&self) -> f32 {
+        self.encoder.achieved_bitrate()
+    }
+
     pub fn finalize(&mut self) {
         _ = self.sender.close();
         self.state = SeaEncoderState::Finished;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::SeaChunkDecoder;
+
+    const FRAMES_PER_CHUNK: u16 = 480;
+
+    fn settings() -> EncoderSettings {
+        EncoderSettings {
+            frames_per_chunk: FRAMES_PER_CHUNK,
+            ..Default::default()
+        }
+    }
+
+    /// signal to noise ratio in decibels
+    fn snr(reference: &[i16], decoded: &[i16]) -> f64 {
+        let (signal, noise) =
+            reference
+                .iter()
+                .zip(decoded)
+                .fold((0_f64, 0_f64), |(signal, noise), (a, b)| {
+                    let (a, b) = (*a as f64, *b as f64);
+                    (signal + a * a, noise + (a - b) * (a - b))
+                });
+
+        10_f64 * (signal / noise.max(1_f64)).log10()
+    }
+
+    #[test]
+    fn settings_change_mid_stream() {
+        let channels = 2;
+        let samples: Vec<i16> = (0..FRAMES_PER_CHUNK as usize * channels * 12)
+            .map(|i| ((i as f32 * 0.011).sin() * 9_000.0) as i16)
+            .collect();
+
+        let changes = [
+            EncoderSettings {
+                residual_bits: 5.0,
+                ..settings()
+            },
+            EncoderSettings {
+                vbr: true,
+                residual_bits: 4.0,
+                ..settings()
+            },
+            EncoderSettings {
+                scale_factor_bits: 6,
+                scale_factor_frames: 40,
+                ..settings()
+            },
+            EncoderSettings {
+                target_bitrate: Some(200.0),
+                ..settings()
+            },
+        ];
+
+        let mut encoder = SeaChunkEncoder::new(channels as u8, 48_000, settings()).unwrap();
+        let mut chunks = Vec::new();
+        for (index, chunk) in samples.chunks(encoder.samples_per_chunk()).enumerate() {
+            if index % 3 == 2 {
+                encoder.update_settings(changes[index / 3].clone()).unwrap();
+            }
+            chunks.push(encoder.encode_chunk(chunk).unwrap());
+        }
+
+        // the header of the first chunk still describes the whole stream
+        let mut decoder = SeaChunkDecoder::new(&encoder.header().unwrap().unwrap()).unwrap();
+        for (index, chunk) in chunks.iter().enumerate() {
+            let range =
+                index * encoder.samples_per_chunk()..(index + 1) * encoder.samples_per_chunk();
+            let decoded = decoder.decode_chunk(chunk).unwrap();
+
+            assert!(snr(&samples[range], &decoded) > 15_f64, "chunk {}", index);
+        }
+    }
+
+    #[test]
+    fn vbr_encodes_every_accepted_residual_bits() {
+        let samples: Vec<i16> = (0..FRAMES_PER_CHUNK as usize)
+            .map(|i| ((i as f32 * 0.011).sin() * 9_000.0) as i16)
+            .collect();
+        let vbr = EncoderSettings {
+            vbr: true,
+            ..settings()
+        };
+
+        let mut encoder = SeaChunkEncoder::new(1, 48_000, vbr.clone()).unwrap();
+        let residual_bits: Vec<f32> = (2..18).map(|halves| halves as f32 / 2.0).collect();
+
+        // residual bits beyond what vbr can spread around are encoded at the nearest sizes
+        let chunks: Vec<Vec<u8>> = residual_bits
+            .iter()
+            .map(|residual_bits| {
+                encoder
+                    .update_settings(EncoderSettings {
+                        residual_bits: *residual_bits,
+                        ..vbr.clone()
+                    })
+                    .unwrap();
+                encoder.encode_chunk(&samples).unwrap()
+            })
+            .collect();
+
+        let mut decoder = SeaChunkDecoder::new(&encoder.header().unwrap().unwrap()).unwrap();
+        for (chunk, residual_bits) in chunks.iter().zip(residual_bits) {
+            let decoded = decoder.decode_chunk(chunk).unwrap();
+            assert_eq!(decoded.len(), samples.len(), "{} bits", residual_bits);
+        }
+    }
+
+    #[test]
+    fn frames_per_chunk_can_not_change() {
+        let mut encoder = SeaChunkEncoder::new(1, 48_000, settings()).unwrap();
+
+        let frames_per_chunk = EncoderSettings {
+            frames_per_chunk: FRAMES_PER_CHUNK * 2,
+            ..settings()
+        };
+        assert!(encoder.update_settings(frames_per_chunk).is_err());
+    }
+}
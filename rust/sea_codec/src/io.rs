@@ -0,0 +1,601 @@
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::time::Duration;
+
+use crate::codec::{
+    common::SeaError,
+    file::{SeaFileHeader, SeaMetadata, CONTAINER_VERSION},
+};
+use crate::decoder::SeaChunkDecoder;
+use crate::encoder::{EncoderSettings, SeaChunkEncoder};
+
+/// encodes interleaved samples into a seekable version 2 sea file
+/// implements `Write` for little endian 16 bit pcm, the final partial chunk is only written by `finish`
+pub struct SeaWriter<W: Write + Seek> {
+    inner: W,
+    encoder: SeaChunkEncoder,
+    metadata: SeaMetadata,
+    /// samples waiting for a full chunk
+    buffer: Vec<i16>,
+    /// the first byte of a pcm sample split across two writes
+    pending_byte: Option<u8>,
+    /// the position of the header in the inner writer, set once the header is written
+    start: Option<u64>,
+    /// the offset of every chunk from the start of the header
+    chunk_offsets: Vec<u32>,
+    samples_written: u64,
+}
+
+impl<W: Write + Seek> SeaWriter<W> {
+    pub fn new(
+        inner: W,
+        channels: u8,
+        sample_rate: u32,
+        settings: EncoderSettings,
+        metadata: SeaMetadata,
+    ) -> Result<Self, SeaError> {
+        let encoder = SeaChunkEncoder::new(channels, sample_rate, settings)?;
+
+        Ok(Self {
+            inner,
+            buffer: Vec::with_capacity(encoder.samples_per_chunk()),
+            encoder,
+            metadata,
+            pending_byte: None,
+            start: None,
+            chunk_offsets: Vec::new(),
+            samples_written: 0,
+        })
+    }
+
+    pub fn write_samples(&mut self, mut samples: &[i16]) -> Result<(), SeaError> {
+        let samples_per_chunk = self.encoder.samples_per_chunk();
+        self.samples_written += samples.len() as u64;
+
+        while !samples.is_empty() {
+            let len = (samples_per_chunk - self.buffer.len()).min(samples.len());
+            self.buffer.extend_from_slice(&samples[..len]);
+            samples = &samples[len..];
+
+            if self.buffer.len() == samples_per_chunk {
+                self.write_chunk()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// pads the final chunk with silence, writes the chunk index and returns the inner writer
+    pub fn finish(mut self) -> Result<W, SeaError> {
+        if !self.buffer.is_empty() {
+            self.buffer.resize(self.encoder.samples_per_chunk(), 0);
+            self.write_chunk()?;
+        }
+
+        if let Some(start) = self.start {
+            let end = self.inner.stream_position()?;
+            let index_offset = offset_u32(end - start)?;
+
+            let mut index = Vec::with_capacity(4 + self.chunk_offsets.len() * 4);
+            index.extend_from_slice(&(self.chunk_offsets.len() as u32).to_le_bytes());
+            for offset in &self.chunk_offsets {
+                index.extend_from_slice(&offset.to_le_bytes());
+            }
+            self.inner.write_all(&index)?;
+
+            // the frame count and index location are only known now, so the header is rewritten
+            let channels = self.channels()?;
+            let header = self.header(self.samples_written / channels, index_offset)?;
+
+            let index_end = self.inner.stream_position()?;
+            self.inner.seek(SeekFrom::Start(start))?;
+            self.inner.write_all(&header)?;
+            self.inner.seek(SeekFrom::Start(index_end))?;
+        }
+
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+
+    fn write_chunk(&mut self) -> Result<(), SeaError> {
+        let chunk = self.encoder.encode_chunk(&self.buffer)?;
+        self.buffer.clear();
+
+        // the header is only complete once the first chunk has been encoded
+        let start = match self.start {
+            Some(start) => start,
+            None => {
+                let start = self.inner.stream_position()?;
+                let header = self.header(0, 0)?;
+                self.inner.write_all(&header)?;
+                self.start = Some(start);
+                start
+            }
+        };
+
+        let offset = self.inner.stream_position()? - start;
+        self.chunk_offsets.push(offset_u32(offset)?);
+
+        self.inner.write_all(&chunk)?;
+        Ok(())
+    }
+
+    fn channels(&self) -> Result<u64, SeaError> {
+        self.encoder
+            .file_header()
+            .map(|header| header.channels as u64)
+            .ok_or(SeaError::InvalidParameters)
+    }
+
+    fn header(&self, total_frames: u64, index_offset: u32) -> Result<Vec<u8>, SeaError> {
+        let mut header = self
+            .encoder
+            .file_header()
+            .ok_or(SeaError::InvalidParameters)?
+            .clone();
+
+        header.version = CONTAINER_VERSION;
+        header.total_frames = u32::try_from(total_frames).map_err(|_| SeaError::TooManyFrames)?;
+        header.index_offset = index_offset;
+        header.metadata = self.metadata.clone();
+
+        header.serialize()
+    }
+}
+
+impl<W: Write + Seek> Write for SeaWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut bytes = buf;
+        let mut samples = Vec::with_capacity(buf.len() / 2 + 1);
+
+        if let Some(first) = self.pending_byte.take() {
+            if let Some((second, rest)) = bytes.split_first() {
+                samples.push(i16::from_le_bytes([first, *second]));
+                bytes = rest;
+            } else {
+                self.pending_byte = Some(first);
+            }
+        }
+
+        let mut sample_bytes = bytes.chunks_exact(2);
+        samples.extend(
+            sample_bytes
+                .by_ref()
+                .map(|b| i16::from_le_bytes([b[0], b[1]])),
+        );
+        if let [last] = sample_bytes.remainder() {
+            self.pending_byte = Some(*last);
+        }
+
+        self.write_samples(&samples).map_err(into_io_error)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// decodes a whole sea file into interleaved samples, version 1 and 2 files are supported
+/// implements `Read` for little endian 16 bit pcm
+pub struct SeaReader<R: Read> {
+    inner: R,
+    decoder: SeaChunkDecoder,
+    /// encoded bytes read ahead of the current position
+    buffer: Vec<u8>,
+    /// decoded pcm bytes not yet returned by `read`
+    pcm: Vec<u8>,
+    pcm_position: usize,
+    /// the offset of the next chunk from the start of the header
+    position: u64,
+    /// the length of the header, where the first chunk begins
+    header_len: u64,
+    /// frames left before the end of the file, None when the frame count is unknown
+    frames_remaining: Option<u64>,
+    /// samples to drop from the next chunk after a seek
+    skip_samples: usize,
+    /// the chunk offsets, loaded by the first seek
+    index: Option<Vec<u32>>,
+}
+
+impl<R: Read> SeaReader<R> {
+    pub fn new(mut inner: R) -> Result<Self, SeaError> {
+        let mut counter = (&mut inner).take(u64::MAX);
+        let header = SeaFileHeader::read(&mut counter)?;
+        let header_len = u64::MAX - counter.limit();
+
+        Ok(Self {
+            inner,
+            buffer: Vec::with_capacity(header.chunk_size as usize),
+            frames_remaining: (header.total_frames > 0).then_some(header.total_frames as u64),
+            decoder: SeaChunkDecoder::from_header(header),
+            pcm: Vec::new(),
+            pcm_position: 0,
+            position: header_len,
+            header_len,
+            skip_samples: 0,
+            index: None,
+        })
+    }
+
+    pub fn header(&self) -> &SeaFileHeader {
+        self.decoder.header()
+    }
+
+    /// decodes the next chunk, None at the end of the file
+    pub fn read_chunk(&mut self) -> Result<Option<Vec<i16>>, SeaError> {
+        let header = self.decoder.header();
+        let channels = header.channels as usize;
+        let chunk_size = header.chunk_size as usize;
+        // the index follows the last chunk
+        let end = (header.index_offset > 0).then_some(header.index_offset as u64);
+
+        if self.frames_remaining == Some(0) {
+            return Ok(None);
+        }
+
+        self.fill_buffer(chunk_size, end)?;
+        if self.buffer.is_empty() {
+            return Ok(None);
+        }
+
+        // chunks may be shorter than the header chunk size, the parser reports how much it used
+        let decoded = self.decoder.decode_prefix(&self.buffer);
+        let used = match &decoded {
+            Ok((_, used)) => *used,
+            // a corrupt chunk is skipped whole
+            Err(_) => self.buffer.len(),
+        };
+        self.buffer.drain(..used);
+        self.position += used as u64;
+
+        let (mut samples, _) = decoded?;
+
+        // the final chunk is padded with silence
+        if let Some(frames_remaining) = self.frames_remaining.as_mut() {
+            let frames = (samples.len() / channels).min(*frames_remaining as usize);
+            samples.truncate(frames * channels);
+            *frames_remaining -= frames as u64;
+        }
+
+        if self.skip_samples > 0 {
+            samples.drain(..self.skip_samples.min(samples.len()));
+            self.skip_samples = 0;
+        }
+
+        Ok(Some(samples))
+    }
+
+    /// decodes every remaining chunk
+    pub fn read_all(&mut self) -> Result<Vec<i16>, SeaError> {
+        let mut samples = Vec::new();
+
+        while let Some(chunk) = self.read_chunk()? {
+            samples.extend_from_slice(&chunk);
+        }
+
+        Ok(samples)
+    }
+
+    /// buffers up to `chunk_size` bytes without reading past `end`
+    fn fill_buffer(&mut self, chunk_size: usize, end: Option<u64>) -> io::Result<()> {
+        let mut wanted = chunk_size;
+        if let Some(end) = end {
+            wanted = wanted.min(end.saturating_sub(self.position) as usize);
+        }
+
+        let mut filled = self.buffer.len();
+        if filled >= wanted {
+            return Ok(());
+        }
+        self.buffer.resize(wanted, 0);
+
+        let result = loop {
+            if filled == wanted {
+                break Ok(());
+            }
+
+            match self.inner.read(&mut self.buffer[filled..]) {
+                Ok(0) => break Ok(()),
+                Ok(read) => filled += read,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => break Err(error),
+            }
+        };
+
+        self.buffer.truncate(filled);
+        result
+    }
+}
+
+impl<R: Read + Seek> SeaReader<R> {
+    /// moves to the given frame, the next chunk read starts exactly there
+    pub fn seek_to_frame(&mut self, frame: u64) -> Result<(), SeaError> {
+        let header = self.decoder.header();
+        let frames_per_chunk = header.frames_per_chunk as u64;
+        let channels = header.channels as usize;
+        let total_frames = header.total_frames as u64;
+
+        if total_frames > 0 && frame >= total_frames {
+            return Err(SeaError::InvalidParameters);
+        }
+
+        let chunk_index = frame / frames_per_chunk;
+        let offset = if header.index_offset > 0 {
+            let index = self.load_index()?;
+            *index
+                .get(chunk_index as usize)
+                .ok_or(SeaError::InvalidParameters)? as u64
+        } else {
+            // without an index every chunk is assumed to have the header chunk size
+            chunk_index
+                .checked_mul(header.chunk_size as u64)
+                .and_then(|offset| offset.checked_add(self.header_len))
+                .ok_or(SeaError::InvalidParameters)?
+        };
+
+        self.seek_to(offset)?;
+
+        if total_frames > 0 {
+            self.frames_remaining = Some(total_frames - chunk_index * frames_per_chunk);
+        }
+        self.skip_samples = (frame % frames_per_chunk) as usize * channels;
+        self.pcm.clear();
+        self.pcm_position = 0;
+
+        Ok(())
+    }
+
+    /// moves to the frame closest to the given time
+    pub fn seek(&mut self, time: Duration) -> Result<(), SeaError> {
+        let sample_rate = self.decoder.header().sample_rate as f64;
+        self.seek_to_frame((time.as_secs_f64() * sample_rate) as u64)
+    }
+
+    fn load_index(&mut self) -> Result<&[u32], SeaError> {
+        if self.index.is_none() {
+            self.seek_to(self.decoder.header().index_offset as u64)?;
+
+            let mut count = [0; 4];
+            self.inner.read_exact(&mut count)?;
+            let len = u32::from_le_bytes(count) as u64 * 4;
+
+            // the count is not trusted for the allocation
+            let mut bytes = Vec::new();
+            (&mut self.inner).take(len).read_to_end(&mut bytes)?;
+            if bytes.len() as u64 != len {
+                return Err(SeaError::InvalidFile);
+            }
+            self.position += 4 + len;
+
+            let index = bytes
+                .chunks_exact(4)
+                .map(|offset| u32::from_le_bytes([offset[0], offset[1], offset[2], offset[3]]))
+                .collect();
+            self.index = Some(index);
+        }
+
+        Ok(self.index.as_deref().unwrap_or_default())
+    }
+
+    /// seeks relative to the current position, the start of the header may not be at zero
+    fn seek_to(&mut self, offset: u64) -> Result<(), SeaError> {
+        let inner_position = self.position + self.buffer.len() as u64;
+        let delta = offset as i64 - inner_position as i64;
+        self.inner.seek(SeekFrom::Current(delta))?;
+        self.position = offset;
+        self.buffer.clear();
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for SeaReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.pcm_position == self.pcm.len() {
+            let Some(samples) = self.read_chunk().map_err(into_io_error)? else {
+                return Ok(0);
+            };
+
+            self.pcm.clear();
+            self.pcm
+                .extend(samples.iter().flat_map(|sample| sample.to_le_bytes()));
+            self.pcm_position = 0;
+        }
+
+        let len = buf.len().min(self.pcm.len() - self.pcm_position);
+        buf[..len].copy_from_slice(&self.pcm[self.pcm_position..self.pcm_position + len]);
+        self.pcm_position += len;
+        Ok(len)
+    }
+}
+
+fn into_io_error(error: SeaError) -> io::Error {
+    match error {
+        SeaError::IoError(error) => error,
+        error => io::Error::new(io::ErrorKind::InvalidData, format!("{error:?}")),
+    }
+}
+
+fn offset_u32(offset: u64) -> Result<u32, SeaError> {
+    u32::try_from(offset).map_err(|_| SeaError::TooManyFrames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    const SAMPLE_RATE: u32 = 48_000;
+
+    fn settings() -> EncoderSettings {
+        EncoderSettings {
+            frames_per_chunk: 480,
+            residual_bits: 5.0,
+            ..Default::default()
+        }
+    }
+
+    /// a tone on each channel, one frame per `channels` samples
+    fn tone(frames: usize, channels: usize) -> Vec<i16> {
+        (0..frames * channels)
+            .map(|i| {
+                let (frame, channel) = (i / channels, i % channels);
+                let frequency = 0.02 + channel as f32 * 0.015;
+                ((frame as f32 * frequency).sin() * 12_000.0) as i16
+            })
+            .collect()
+    }
+
+    /// signal to noise ratio in decibels
+    fn snr(reference: &[i16], decoded: &[i16]) -> f64 {
+        let (signal, noise) =
+            reference
+                .iter()
+                .zip(decoded)
+                .fold((0_f64, 0_f64), |(signal, noise), (a, b)| {
+                    let (a, b) = (*a as f64, *b as f64);
+                    (signal + a * a, noise + (a - b) * (a - b))
+                });
+
+        10_f64 * (signal / noise.max(1_f64)).log10()
+    }
+
+    fn write(samples: &[i16], channels: u8, metadata: SeaMetadata) -> Vec<u8> {
+        let mut writer = SeaWriter::new(
+            Cursor::new(Vec::new()),
+            channels,
+            SAMPLE_RATE,
+            settings(),
+            metadata,
+        )
+        .unwrap();
+        writer.write_samples(samples).unwrap();
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn round_trip() {
+        for channels in [1, 2] {
+            // not a whole number of chunks, the padding must not come back
+            let samples = tone(2_000, channels as usize);
+            let file = write(&samples, channels, SeaMetadata::default());
+
+            let mut reader = SeaReader::new(Cursor::new(file)).unwrap();
+            assert_eq!(reader.header().channels, channels);
+            assert_eq!(reader.header().sample_rate, SAMPLE_RATE);
+
+            let decoded = reader.read_all().unwrap();
+            assert_eq!(decoded.len(), samples.len());
+            assert!(snr(&samples, &decoded) > 20_f64, "channels {}", channels);
+        }
+    }
+
+    #[test]
+    fn round_trip_pcm_bytes() {
+        let samples = tone(1_500, 2);
+        let bytes: Vec<u8> = samples.iter().flat_map(|s| s.to_le_bytes()).collect();
+
+        // odd sized writes split samples across calls
+        let mut writer = SeaWriter::new(
+            Cursor::new(Vec::new()),
+            2,
+            SAMPLE_RATE,
+            settings(),
+            SeaMetadata::default(),
+        )
+        .unwrap();
+        for part in bytes.chunks(333) {
+            writer.write_all(part).unwrap();
+        }
+        let file = writer.finish().unwrap().into_inner();
+
+        let mut reader = SeaReader::new(Cursor::new(file)).unwrap();
+        let mut pcm = Vec::new();
+        reader.read_to_end(&mut pcm).unwrap();
+
+        assert_eq!(pcm.len(), bytes.len());
+        let decoded: Vec<i16> = pcm
+            .chunks_exact(2)
+            .map(|b| i16::from_le_bytes([b[0], b[1]]))
+            .collect();
+        assert!(snr(&samples, &decoded) > 20_f64);
+    }
+
+    #[test]
+    fn empty_file_has_no_header() {
+        let file = write(&[], 1, SeaMetadata::default());
+
+        assert!(file.is_empty());
+        assert!(SeaReader::new(Cursor::new(file)).is_err());
+    }
+
+    #[test]
+    fn seek_matches_sequential_read() {
+        let channels = 2;
+        let samples = tone(3_000, channels);
+        let file = write(&samples, channels as u8, SeaMetadata::default());
+
+        let mut reader = SeaReader::new(Cursor::new(file)).unwrap();
+        let decoded = reader.read_all().unwrap();
+
+        for frame in [0, 1, 479, 480, 1_234, 2_999] {
+            reader.seek_to_frame(frame).unwrap();
+            let rest = reader.read_all().unwrap();
+            assert_eq!(
+                rest,
+                decoded[frame as usize * channels..],
+                "frame {}",
+                frame
+            );
+        }
+
+        reader.seek(Duration::from_millis(50)).unwrap();
+        assert_eq!(reader.read_all().unwrap(), decoded[2_400 * channels..]);
+
+        assert!(reader.seek_to_frame(3_000).is_err());
+    }
+
+    #[test]
+    fn header_describes_the_file() {
+        let metadata = SeaMetadata {
+            title: Some("title".into()),
+            artist: Some("artist".into()),
+            loudness: Some(-16.5),
+        };
+        let file = write(&tone(4_800, 1), 1, metadata.clone());
+
+        let reader = SeaReader::new(Cursor::new(file)).unwrap();
+        let header = reader.header();
+        assert_eq!(header.version, CONTAINER_VERSION);
+        assert_eq!(header.total_frames, 4_800);
+        assert!(header.index_offset > 0);
+        assert_eq!(header.duration(), Some(Duration::from_millis(100)));
+        assert_eq!(header.metadata, metadata);
+    }
+
+    #[test]
+    fn reads_version_1_streams() {
+        let samples = tone(2_400, 1);
+        let mut encoder = SeaChunkEncoder::new(1, SAMPLE_RATE, settings()).unwrap();
+        let chunks: Vec<Vec<u8>> = samples
+            .chunks(encoder.samples_per_chunk())
+            .map(|chunk| encoder.encode_chunk(chunk).unwrap())
+            .collect();
+
+        let mut file = encoder.header().unwrap().unwrap();
+        chunks
+            .iter()
+            .for_each(|chunk| file.extend_from_slice(chunk));
+
+        let mut reader = SeaReader::new(Cursor::new(file)).unwrap();
+        assert_eq!(reader.header().version, 1);
+        assert_eq!(reader.header().duration(), None);
+
+        let decoded = reader.read_all().unwrap();
+        assert_eq!(decoded.len(), samples.len());
+        assert!(snr(&samples, &decoded) > 20_f64);
+
+        // without an index the chunks are found by the header chunk size
+        reader.seek_to_frame(1_000).unwrap();
+        assert_eq!(reader.read_all().unwrap(), decoded[1_000..]);
+    }
+}
@@ -1,18 +1,40 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
 use bytes::Bytes;
 
 mod codec;
 pub mod decoder;
 pub mod encoder;
+#[cfg(feature = "std")]
+pub mod io;
+#[cfg(feature = "std")]
+pub mod quality;
+
+pub use codec::common::SeaError;
+pub use codec::file::{SeaFileHeader, SeaMetadata};
+pub use codec::simd::set_simd_enabled;
 
 /// a message containing either a frame of audio or silence
+#[cfg(feature = "std")]
 #[derive(Debug)]
 pub enum ProcessorMessage {
     Data(Bytes),
-    Samples(Box<[i16; 480]>),
+    /// interleaved samples, `frames_per_chunk * channels` long
+    Samples(Box<[i16]>),
     Silence,
+    /// the remote encoder restarted, the samples following it use the format of this header
+    Restart(SeaFileHeader),
+    /// a frame which never arrived, the receiver conceals it in its place
+    Lost,
+    /// silence carrying a description of the sender's background noise for the receiver to fill it with
+    ComfortNoise(Bytes),
 }
 
 /// common processor message constructors
+#[cfg(feature = "std")]
 impl ProcessorMessage {
     pub fn slice(bytes: &'static [u8]) -> Self {
         Self::Data(Bytes::from(bytes))
@@ -26,7 +48,7 @@ impl ProcessorMessage {
         Self::Data(frame)
     }
 
-    pub fn samples(samples: [i16; 480]) -> Self {
-        Self::Samples(Box::new(samples))
+    pub fn samples(samples: impl Into<Box<[i16]>>) -> Self {
+        Self::Samples(samples.into())
     }
 }
@@ -0,0 +1,259 @@
+use std::f64::consts::PI;
+
+use crate::codec::common::SeaError;
+use crate::decoder::SeaChunkDecoder;
+use crate::encoder::{EncoderSettings, SeaChunkEncoder};
+
+/// segments quieter than this mean square are left out of the segmental snr
+const SILENT_SEGMENT_POWER: f64 = 1.0;
+/// the range each segment's snr is clamped to, so silence and perfect segments do not dominate
+const SEGMENT_SNR_RANGE: (f64, f64) = (-10.0, 35.0);
+/// keeps the log spectra finite for empty bins
+const SPECTRAL_FLOOR: f64 = 1e-3;
+
+/// objective quality of one encoded signal, all metrics are averaged over the channels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QualityReport {
+    /// signal to noise ratio in dB, higher is better
+    pub snr: f64,
+    /// the mean snr of short segments in dB, higher is better
+    pub segmental_snr: f64,
+    /// the mean log spectral distance in dB, lower is better
+    pub spectral_distance: f64,
+    /// the bitrate of the encoded chunks in kbps
+    pub bitrate: f32,
+}
+
+/// encodes and decodes interleaved samples with `settings`, measuring what the settings cost in quality
+/// segments last 20ms and spectra use frames of about 20ms as well
+pub fn measure(
+    samples: &[i16],
+    channels: u8,
+    sample_rate: u32,
+    settings: EncoderSettings,
+) -> Result<QualityReport, SeaError> {
+    let (decoded, bitrate) = round_trip(samples, channels, sample_rate, settings)?;
+
+    let segment_len = (sample_rate as usize / 50).max(1);
+    let frame_len = segment_len.next_power_of_two();
+
+    let mut report = QualityReport {
+        snr: 0.0,
+        segmental_snr: 0.0,
+        spectral_distance: 0.0,
+        bitrate,
+    };
+
+    for channel in 0..channels as usize {
+        let reference = deinterleave(samples, channels, channel);
+        let decoded = deinterleave(&decoded, channels, channel);
+
+        report.snr += snr(&reference, &decoded);
+        report.segmental_snr += segmental_snr(&reference, &decoded, segment_len);
+        report.spectral_distance += spectral_distance(&reference, &decoded, frame_len);
+    }
+
+    report.snr /= channels as f64;
+    report.segmental_snr /= channels as f64;
+    report.spectral_distance /= channels as f64;
+    Ok(report)
+}
+
+/// encodes and decodes interleaved samples, also returning the achieved bitrate in kbps
+/// the last partial chunk is padded with silence and cut off again after decoding
+pub fn round_trip(
+    samples: &[i16],
+    channels: u8,
+    sample_rate: u32,
+    settings: EncoderSettings,
+) -> Result<(Vec<i16>, f32), SeaError> {
+    let mut encoder = SeaChunkEncoder::new(channels, sample_rate, settings)?;
+    let samples_per_chunk = encoder.samples_per_chunk();
+
+    let mut chunks = Vec::new();
+    for chunk in samples.chunks(samples_per_chunk) {
+        let mut padded = chunk.to_vec();
+        padded.resize(samples_per_chunk, 0);
+        chunks.push(encoder.encode_chunk(&padded)?);
+    }
+
+    let Some(header) = encoder.header()? else {
+        return Ok((Vec::new(), 0.0));
+    };
+
+    let mut decoder = SeaChunkDecoder::new(&header)?;
+    let mut decoded = Vec::with_capacity(chunks.len() * samples_per_chunk);
+    for chunk in chunks {
+        decoded.extend(decoder.decode_chunk(&chunk)?);
+    }
+
+    decoded.truncate(samples.len());
+    Ok((decoded, encoder.achieved_bitrate()))
+}
+
+/// the signal to noise ratio of `decoded` against `reference` in dB
+pub fn snr(reference: &[i16], decoded: &[i16]) -> f64 {
+    let (signal, noise) = signal_and_noise(reference, decoded);
+    power_ratio(signal, noise)
+}
+
+/// the mean snr of `segment_len` sample segments in dB, skipping silent segments
+pub fn segmental_snr(reference: &[i16], decoded: &[i16], segment_len: usize) -> f64 {
+    let (low, high) = SEGMENT_SNR_RANGE;
+    let mut total = 0.0;
+    let mut segments = 0;
+
+    for (reference, decoded) in reference
+        .chunks(segment_len)
+        .zip(decoded.chunks(segment_len))
+    {
+        let (signal, noise) = signal_and_noise(reference, decoded);
+        if signal / (reference.len() as f64) < SILENT_SEGMENT_POWER {
+            continue;
+        }
+
+        total += power_ratio(signal, noise).clamp(low, high);
+        segments += 1;
+    }
+
+    if segments == 0 {
+        high
+    } else {
+        total / segments as f64
+    }
+}
+
+/// the mean log spectral distance in dB between hann windowed frames of `frame_len` samples
+/// `frame_len` must be a power of two, frames overlap by half
+pub fn spectral_distance(reference: &[i16], decoded: &[i16], frame_len: usize) -> f64 {
+    assert!(
+        frame_len.is_power_of_two(),
+        "frame_len must be a power of two"
+    );
+
+    let len = reference.len().min(decoded.len());
+    let hop = (frame_len / 2).max(1);
+    let window: Vec<f64> = (0..frame_len)
+        .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f64 / frame_len as f64).cos())
+        .collect();
+
+    let mut total = 0.0;
+    let mut frames = 0;
+    let mut start = 0;
+
+    while start + frame_len <= len {
+        let reference = power_spectrum(&reference[start..start + frame_len], &window);
+        let decoded = power_spectrum(&decoded[start..start + frame_len], &window);
+
+        let mean_square = reference
+            .iter()
+            .zip(&decoded)
+            .map(|(r, d)| {
+                let difference = 10.0 * ((r + SPECTRAL_FLOOR) / (d + SPECTRAL_FLOOR)).log10();
+                difference * difference
+            })
+            .sum::<f64>()
+            / reference.len() as f64;
+
+        total += mean_square.sqrt();
+        frames += 1;
+        start += hop;
+    }
+
+    if frames == 0 {
+        0.0
+    } else {
+        total / frames as f64
+    }
+}
+
+fn signal_and_noise(reference: &[i16], decoded: &[i16]) -> (f64, f64) {
+    reference
+        .iter()
+        .zip(decoded)
+        .fold((0.0, 0.0), |(signal, noise), (&r, &d)| {
+            let r = r as f64;
+            let error = r - d as f64;
+            (signal + r * r, noise + error * error)
+        })
+}
+
+/// the ratio in dB, capped for identical signals
+fn power_ratio(signal: f64, noise: f64) -> f64 {
+    if noise == 0.0 {
+        return if signal == 0.0 { 0.0 } else { 100.0 };
+    }
+
+    if signal == 0.0 {
+        return -100.0;
+    }
+
+    10.0 * (signal / noise).log10()
+}
+
+fn deinterleave(samples: &[i16], channels: u8, channel: usize) -> Vec<i16> {
+    samples
+        .iter()
+        .skip(channel)
+        .step_by(channels as usize)
+        .copied()
+        .collect()
+}
+
+/// the power of the positive frequency bins of a windowed frame
+fn power_spectrum(frame: &[i16], window: &[f64]) -> Vec<f64> {
+    let mut real: Vec<f64> = frame
+        .iter()
+        .zip(window)
+        .map(|(&sample, w)| sample as f64 / i16::MAX as f64 * w)
+        .collect();
+    let mut imaginary = vec![0.0; frame.len()];
+
+    fft(&mut real, &mut imaginary);
+
+    real.iter()
+        .zip(&imaginary)
+        .take(frame.len() / 2 + 1)
+        .map(|(re, im)| re * re + im * im)
+        .collect()
+}
+
+/// an in place iterative radix 2 fft
+fn fft(real: &mut [f64], imaginary: &mut [f64]) {
+    let n = real.len();
+    if n < 2 {
+        return;
+    }
+
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            real.swap(i, j);
+            imaginary.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let angle = -2.0 * PI / len as f64;
+
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let (sin, cos) = (angle * k as f64).sin_cos();
+                let even = start + k;
+                let odd = even + len / 2;
+
+                let re = real[odd] * cos - imaginary[odd] * sin;
+                let im = real[odd] * sin + imaginary[odd] * cos;
+
+                real[odd] = real[even] - re;
+                imaginary[odd] = imaginary[even] - im;
+                real[even] += re;
+                imaginary[even] += im;
+            }
+        }
+
+        len <<= 1;
+    }
+}
@@ -0,0 +1,159 @@
+//! reference signals shared by the quality test and bench
+
+use std::f32::consts::TAU;
+use std::fs;
+use std::path::Path;
+
+pub const SAMPLE_RATE: u32 = 48_000;
+
+/// a reference signal, interleaved samples
+pub struct Reference {
+    pub name: String,
+    pub channels: u8,
+    pub samples: Vec<i16>,
+}
+
+/// one second of each synthetic reference signal
+pub fn synthetic() -> Vec<Reference> {
+    let len = SAMPLE_RATE as usize;
+
+    vec![
+        Reference {
+            name: "speech".into(),
+            channels: 1,
+            samples: speech(len),
+        },
+        Reference {
+            name: "sweep".into(),
+            channels: 1,
+            samples: sweep(len),
+        },
+        Reference {
+            name: "noise".into(),
+            channels: 1,
+            samples: noise(len, 0.3),
+        },
+        Reference {
+            name: "stereo".into(),
+            channels: 2,
+            samples: speech(len)
+                .into_iter()
+                .zip(sweep(len))
+                .flat_map(|(left, right)| [left, right])
+                .collect(),
+        },
+    ]
+}
+
+/// 16 bit pcm wav files from the directory in `SEA_REFERENCE_WAVS`, none when it is not set
+pub fn wav_files() -> Vec<Reference> {
+    let Ok(directory) = std::env::var("SEA_REFERENCE_WAVS") else {
+        return Vec::new();
+    };
+
+    let mut references = Vec::new();
+    for entry in fs::read_dir(&directory).expect("SEA_REFERENCE_WAVS is not a directory") {
+        let path = entry.unwrap().path();
+        if path.extension().is_some_and(|extension| extension == "wav") {
+            match read_wav(&path) {
+                Some(reference) => references.push(reference),
+                None => eprintln!("skipping {}, only 16 bit pcm is supported", path.display()),
+            }
+        }
+    }
+
+    references
+}
+
+/// reads the fmt and data chunks of a 16 bit pcm wav file, only 48kHz files are used as is
+fn read_wav(path: &Path) -> Option<Reference> {
+    let bytes = fs::read(path).ok()?;
+    if bytes.len() < 12 || &bytes[..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return None;
+    }
+
+    let mut format = None;
+    let mut data = None;
+    let mut offset = 12;
+
+    while offset + 8 <= bytes.len() {
+        let id = &bytes[offset..offset + 4];
+        let len = u32::from_le_bytes(bytes[offset + 4..offset + 8].try_into().ok()?) as usize;
+        let body = bytes.get(offset + 8..offset + 8 + len)?;
+
+        match id {
+            b"fmt " if len >= 16 => format = Some(body),
+            b"data" => data = Some(body),
+            _ => (),
+        }
+
+        offset += 8 + len + len % 2;
+    }
+
+    let format = format?;
+    let audio_format = u16::from_le_bytes([format[0], format[1]]);
+    let channels = u16::from_le_bytes([format[2], format[3]]);
+    let sample_rate = u32::from_le_bytes(format[4..8].try_into().ok()?);
+    let bits_per_sample = u16::from_le_bytes([format[14], format[15]]);
+
+    if audio_format != 1 || bits_per_sample != 16 || sample_rate != SAMPLE_RATE {
+        return None;
+    }
+
+    Some(Reference {
+        name: path.file_stem()?.to_string_lossy().into_owned(),
+        channels: u8::try_from(channels).ok()?,
+        samples: data?
+            .chunks_exact(2)
+            .map(|sample| i16::from_le_bytes([sample[0], sample[1]]))
+            .collect(),
+    })
+}
+
+/// a few harmonics with a syllable envelope and some hiss, roughly shaped like voiced speech
+fn speech(len: usize) -> Vec<i16> {
+    let hiss = noise(len, 0.01);
+
+    (0..len)
+        .map(|i| {
+            let t = i as f32 / SAMPLE_RATE as f32;
+            let envelope = (t * 3.0 * TAU).sin().abs();
+            // the pitch wanders a little like intonation does
+            let pitch = 140.0 + 20.0 * (t * 0.7 * TAU).sin();
+            let voice = (1..=6)
+                .map(|harmonic| (t * pitch * harmonic as f32 * TAU).sin() / harmonic as f32)
+                .sum::<f32>();
+
+            (voice * envelope * 0.4 * i16::MAX as f32) as i16 + hiss[i]
+        })
+        .collect()
+}
+
+/// an exponential sine sweep from 50Hz to 20kHz
+fn sweep(len: usize) -> Vec<i16> {
+    let (start, end) = (50_f32, 20_000_f32);
+    let duration = len as f32 / SAMPLE_RATE as f32;
+    let rate = (end / start).ln();
+
+    (0..len)
+        .map(|i| {
+            let t = i as f32 / SAMPLE_RATE as f32;
+            let phase = TAU * start * duration / rate * ((t / duration * rate).exp() - 1.0);
+            (phase.sin() * 0.5 * i16::MAX as f32) as i16
+        })
+        .collect()
+}
+
+/// white noise from a xorshift generator
+fn noise(len: usize, amplitude: f32) -> Vec<i16> {
+    let mut state = 0x2545_f491_u32;
+
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            ((state as f32 / u32::MAX as f32 - 0.5) * 2.0 * amplitude * i16::MAX as f32) as i16
+        })
+        .collect()
+}
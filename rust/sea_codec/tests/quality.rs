@@ -0,0 +1,109 @@
+//! quality regression thresholds for the reference signals
+//! changes to the encoder heuristics must not push any setting below its thresholds
+
+use sea_codec::encoder::EncoderSettings;
+use sea_codec::quality::{measure, segmental_snr, snr, spectral_distance, QualityReport};
+
+mod common;
+
+/// signal, vbr, residual bits, minimum snr, minimum segmental snr, maximum spectral distance
+const THRESHOLDS: [(&str, bool, f32, f64, f64, f64); 16] = [
+    ("speech", false, 3.0, 41.5, 33.5, 2.3),
+    ("speech", false, 5.0, 52.5, 34.5, 0.7),
+    ("speech", true, 3.0, 38.0, 32.5, 3.2),
+    ("speech", true, 5.0, 49.0, 34.5, 1.0),
+    ("sweep", false, 3.0, 62.5, 34.5, 0.2),
+    ("sweep", false, 5.0, 72.0, 34.5, 0.1),
+    ("sweep", true, 3.0, 58.0, 34.5, 0.35),
+    ("sweep", true, 5.0, 68.5, 34.5, 0.1),
+    ("noise", false, 3.0, 15.5, 15.5, 2.3),
+    ("noise", false, 5.0, 27.5, 27.5, 0.7),
+    ("noise", true, 3.0, 11.5, 11.5, 3.3),
+    ("noise", true, 5.0, 23.0, 23.0, 1.1),
+    ("stereo", false, 3.0, 52.0, 33.5, 1.2),
+    ("stereo", false, 5.0, 62.5, 34.5, 0.4),
+    ("stereo", true, 3.0, 48.0, 33.5, 1.55),
+    ("stereo", true, 5.0, 59.5, 34.5, 0.45),
+];
+
+fn settings(vbr: bool, residual_bits: f32) -> EncoderSettings {
+    EncoderSettings {
+        frames_per_chunk: 480,
+        scale_factor_frames: 20,
+        residual_bits,
+        vbr,
+        ..Default::default()
+    }
+}
+
+fn measure_reference(reference: &common::Reference, settings: EncoderSettings) -> QualityReport {
+    measure(
+        &reference.samples,
+        reference.channels,
+        common::SAMPLE_RATE,
+        settings,
+    )
+    .unwrap()
+}
+
+#[test]
+fn quality_thresholds() {
+    let references = common::synthetic();
+
+    for (name, vbr, residual_bits, min_snr, min_segmental_snr, max_spectral_distance) in THRESHOLDS
+    {
+        let reference = references.iter().find(|r| r.name == name).unwrap();
+        let report = measure_reference(reference, settings(vbr, residual_bits));
+        let setting = format!("{name} vbr {vbr} residual bits {residual_bits}");
+
+        assert!(report.snr >= min_snr, "{setting}: {report:?}");
+        assert!(
+            report.segmental_snr >= min_segmental_snr,
+            "{setting}: {report:?}"
+        );
+        assert!(
+            report.spectral_distance <= max_spectral_distance,
+            "{setting}: {report:?}"
+        );
+    }
+}
+
+/// more residual bits must never cost quality, also for the wav files in `SEA_REFERENCE_WAVS`
+#[test]
+fn quality_improves_with_bits() {
+    for reference in common::synthetic().into_iter().chain(common::wav_files()) {
+        for vbr in [false, true] {
+            let reports: Vec<QualityReport> = [2.0, 3.0, 4.0, 5.0, 6.0]
+                .into_iter()
+                .map(|residual_bits| measure_reference(&reference, settings(vbr, residual_bits)))
+                .collect();
+
+            for pair in reports.windows(2) {
+                let setting = format!("{} vbr {vbr}", reference.name);
+
+                assert!(pair[1].bitrate > pair[0].bitrate, "{setting}: {pair:?}");
+                assert!(pair[1].snr > pair[0].snr, "{setting}: {pair:?}");
+                assert!(
+                    pair[1].spectral_distance < pair[0].spectral_distance,
+                    "{setting}: {pair:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn metrics() {
+    // broadband, so every bin stays well above the spectral floor
+    let noise = common::synthetic().remove(2);
+    let reference = &noise.samples[..4096];
+    let halved: Vec<i16> = reference.iter().map(|sample| sample / 2).collect();
+
+    assert_eq!(snr(reference, reference), 100.0);
+    assert_eq!(spectral_distance(reference, reference, 512), 0.0);
+    assert_eq!(segmental_snr(reference, reference, 960), 35.0);
+
+    // half the amplitude leaves half the signal as error and costs 6dB in every bin
+    assert!((snr(reference, &halved) - 6.02).abs() < 0.05);
+    assert!((spectral_distance(reference, &halved, 512) - 6.02).abs() < 0.5);
+}
@@ -1,37 +1,77 @@
+use crate::api::telepathy::CodecConfig;
 use kanal::{Receiver, Sender};
 use log::{info, warn};
-use sea_codec::decoder::SeaDecoder;
 use sea_codec::encoder::{EncoderSettings, SeaEncoder};
 use sea_codec::ProcessorMessage;
 
+/// the redundant copies only need to be intelligible
+const FEC_RESIDUAL_BITS: f32 = 2.0;
+
+/// encodes interleaved frames of `frames_per_chunk * channels` samples
+/// follows changes to the codec config mid-call within the limits the remote asked for
+/// with `fec` every packet also carries a low bitrate copy of the previous frame
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn encoder(
     receiver: Receiver<ProcessorMessage>,
     sender: Sender<ProcessorMessage>,
+    channels: u8,
+    frames_per_chunk: u16,
     sample_rate: u32,
-    vbr: bool,
-    residual_bits: f32,
+    codec_config: CodecConfig,
+    remote_options: (bool, f32),
+    fec: bool,
 ) {
-    let settings = EncoderSettings {
-        frames_per_chunk: 480,
-        scale_factor_frames: 20,
-        residual_bits,
-        vbr,
-        ..Default::default()
-    };
+    let mut options = live_options(&codec_config, remote_options);
+    let settings = encoder_settings(frames_per_chunk, options, fec);
+
+    if let Ok(mut encoder) = SeaEncoder::new(channels, sample_rate, settings, receiver, sender) {
+        loop {
+            // the stream header stays valid, so new settings apply from the next chunk on
+            let new_options = live_options(&codec_config, remote_options);
+            if new_options != options {
+                let settings = encoder_settings(frames_per_chunk, new_options, fec);
+
+                if let Err(error) = encoder.update_settings(settings) {
+                    warn!(
+                        "Encoder rejected new settings {:?}: {:?}",
+                        new_options, error
+                    );
+                } else {
+                    info!("Encoder switched to {:?}", new_options);
+                }
+
+                options = new_options;
+            }
+
+            if encoder.encode_frame().is_err() {
+                break;
+            }
+        }
 
-    if let Ok(mut encoder) = SeaEncoder::new(1, sample_rate, settings, receiver, sender) {
-        while encoder.encode_frame().is_ok() {}
         info!("Encoder finished");
     } else {
         warn!("Encoder did not start successfully");
     }
 }
 
-pub(crate) fn decoder(receiver: Receiver<ProcessorMessage>, sender: Sender<ProcessorMessage>) {
-    if let Ok(mut decoder) = SeaDecoder::new(receiver, sender) {
-        while decoder.decode_frame().is_ok() {}
-        info!("Decoder finished");
-    } else {
-        warn!("Decoder did not start successfully");
+/// the local vbr and residual bits combined with the remote options the same way as during negotiation
+fn live_options(codec_config: &CodecConfig, remote_options: (bool, f32)) -> (bool, f32) {
+    let (_, vbr, residual_bits) = codec_config.to_values();
+    let (remote_vbr, remote_residual_bits) = remote_options;
+    (vbr || remote_vbr, residual_bits.min(remote_residual_bits))
+}
+
+fn encoder_settings(
+    frames_per_chunk: u16,
+    (vbr, residual_bits): (bool, f32),
+    fec: bool,
+) -> EncoderSettings {
+    EncoderSettings {
+        frames_per_chunk,
+        scale_factor_frames: 20,
+        residual_bits,
+        vbr,
+        fec_residual_bits: fec.then_some(FEC_RESIDUAL_BITS),
+        ..Default::default()
     }
 }
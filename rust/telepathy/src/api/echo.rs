@@ -0,0 +1,491 @@
+use crate::api::error::Error;
+use crate::api::utils::resampler_factory;
+use kanal::Receiver;
+use rubato::{Resampler, SincFixedIn};
+use std::collections::VecDeque;
+
+type Result<T> = std::result::Result<T, Error>;
+
+/// the number of taps in the adaptive filter, about 10ms at 48kHz
+const FILTER_LEN: usize = 512;
+/// the filter starts this many samples before the estimated delay to cover estimation errors
+const DELAY_MARGIN: usize = 64;
+/// the longest delay between playing the reference and capturing its echo, 500ms at 48kHz
+const MAX_DELAY: usize = 24_000;
+/// the reference may be produced this far ahead of the capture
+const MAX_AHEAD: usize = 8_192;
+/// the number of captured samples the delay estimate correlates against the reference
+const ESTIMATE_LEN: usize = 8_192;
+/// the delay is estimated on signals averaged over this many samples
+const DECIMATION: usize = 8;
+/// the number of processed frames between delay estimates
+const ESTIMATE_INTERVAL: usize = 16;
+/// delay estimates with a weaker normalized correlation are ignored
+const MIN_CORRELATION: f32 = 0.4;
+/// windows quieter than this average energy per sample carry no echo worth aligning to
+const MIN_ENERGY: f32 = 1e-7;
+/// the nlms step size
+const STEP: f32 = 0.5;
+/// keeps the nlms step finite when the reference is silent
+const REGULARIZATION: f32 = 1e-3;
+/// the background filter is discarded when its error is this much larger than the foreground's
+const DIVERGENCE: f32 = 4_f32;
+
+/// Removes the echo of the far end reference from captured audio
+/// every captured channel has its own echo path to the reference
+pub(crate) struct EchoCanceller {
+    /// the samples sent to the output device
+    receiver: Receiver<f32>,
+    /// resamples the reference to the capture sample rate
+    resampler: Option<SincFixedIn<f32>>,
+    pre_buf: [Vec<f32>; 1],
+    post_buf: [Vec<f32>; 1],
+    /// the position in pre_buf
+    position: usize,
+    paths: Vec<EchoPath>,
+}
+
+impl EchoCanceller {
+    /// `ratio` is the capture sample rate divided by the reference sample rate
+    pub(crate) fn new(
+        receiver: Receiver<f32>,
+        ratio: f64,
+        chunk_size: usize,
+        channels: usize,
+    ) -> Result<Self> {
+        Ok(Self {
+            receiver,
+            resampler: resampler_factory(ratio, 1, chunk_size)?,
+            pre_buf: [vec![0_f32; chunk_size]],
+            // rubato requires 10 extra spaces in the output buffer as a safety margin
+            post_buf: [vec![0_f32; (chunk_size as f64 * ratio + 10_f64) as usize]],
+            position: 0,
+            paths: (0..channels).map(|_| EchoPath::new()).collect(),
+        })
+    }
+
+    /// cancels the echo in the first `len` samples of each channel of a captured frame in place
+    pub(crate) fn process(&mut self, frame: &mut [Vec<f32>], len: usize) -> Result<()> {
+        self.receive_reference()?;
+
+        for (path, channel) in self.paths.iter_mut().zip(frame) {
+            path.process(&mut channel[..len]);
+        }
+
+        Ok(())
+    }
+
+    /// keeps the reference lined up when captured samples are dropped instead of processed
+    pub(crate) fn skip(&mut self, samples: usize) -> Result<()> {
+        self.receive_reference()?;
+        self.paths.iter_mut().for_each(|path| path.skip(samples));
+        Ok(())
+    }
+
+    /// takes all reference samples which have been played since the last frame
+    fn receive_reference(&mut self) -> Result<()> {
+        while let Some(sample) = self.receiver.try_recv()? {
+            self.pre_buf[0][self.position] = sample;
+            self.position += 1;
+
+            if self.position < self.pre_buf[0].len() {
+                continue;
+            }
+
+            self.position = 0;
+
+            if let Some(resampler) = &mut self.resampler {
+                let processed =
+                    resampler.process_into_buffer(&self.pre_buf, &mut self.post_buf, None)?;
+                let reference = &self.post_buf[0][..processed.1];
+                self.paths
+                    .iter_mut()
+                    .for_each(|path| path.push_reference(reference));
+            } else {
+                let reference = &self.pre_buf[0];
+                self.paths
+                    .iter_mut()
+                    .for_each(|path| path.push_reference(reference));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// an adaptive model of the echo path between the reference and the capture
+///
+/// positions in both streams are counted in samples, the reference is aligned to the capture by
+/// a bulk delay found by cross correlation and the remaining echo is modeled by a pair of nlms
+/// filters. the background filter adapts on every sample and only replaces the foreground filter
+/// when it cancels more, which keeps near end speech from corrupting the output
+struct EchoPath {
+    /// the recent reference, `reference_start` is the position of its front
+    reference: VecDeque<f32>,
+    reference_start: i64,
+    /// the recent raw capture for delay estimation
+    capture: VecDeque<f32>,
+    /// the position of the next captured sample
+    capture_count: i64,
+    /// the reference position which lines up with capture position zero, before any delay
+    offset: Option<i64>,
+    /// the estimated delay in samples, None until the first estimate
+    delay: Option<usize>,
+    /// the filter used for the output
+    foreground: Vec<f32>,
+    /// the filter which adapts
+    background: Vec<f32>,
+    /// frames processed since the last delay estimate
+    frames: usize,
+    /// the reference lined up with the current frame
+    window: Vec<f32>,
+}
+
+impl EchoPath {
+    fn new() -> Self {
+        Self {
+            reference: VecDeque::with_capacity(Self::history_len()),
+            reference_start: 0,
+            capture: VecDeque::with_capacity(ESTIMATE_LEN),
+            capture_count: 0,
+            offset: None,
+            delay: None,
+            foreground: vec![0_f32; FILTER_LEN],
+            background: vec![0_f32; FILTER_LEN],
+            frames: 0,
+            window: Vec::new(),
+        }
+    }
+
+    /// the reference needed to estimate the longest delay and to fill the filter
+    const fn history_len() -> usize {
+        MAX_DELAY + ESTIMATE_LEN + FILTER_LEN + MAX_AHEAD
+    }
+
+    fn push_reference(&mut self, samples: &[f32]) {
+        self.reference.extend(samples);
+
+        let excess = self.reference.len().saturating_sub(Self::history_len());
+        self.reference.drain(..excess);
+        self.reference_start += excess as i64;
+    }
+
+    fn reference_at(&self, position: i64) -> f32 {
+        usize::try_from(position - self.reference_start)
+            .ok()
+            .and_then(|index| self.reference.get(index))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn skip(&mut self, samples: usize) {
+        self.capture_count += samples as i64;
+        // the recent capture is no longer continuous
+        self.capture.clear();
+    }
+
+    fn process(&mut self, frame: &mut [f32]) {
+        let frame_start = self.capture_count;
+        self.capture_count += frame.len() as i64;
+
+        self.capture.extend(frame.iter());
+        let excess = self.capture.len().saturating_sub(ESTIMATE_LEN);
+        self.capture.drain(..excess);
+
+        // the reference is anchored to the capture once it starts playing
+        let Some(offset) = self.offset.or_else(|| {
+            let reference_end = self.reference_start + self.reference.len() as i64;
+            (reference_end > 0).then(|| reference_end - self.capture_count)
+        }) else {
+            return;
+        };
+        self.offset = Some(offset);
+
+        self.frames += 1;
+        if self.frames >= ESTIMATE_INTERVAL && self.capture.len() == ESTIMATE_LEN {
+            self.frames = 0;
+            self.estimate_delay(offset);
+        }
+
+        if let Some(delay) = self.delay {
+            self.cancel(
+                frame,
+                frame_start + offset + DELAY_MARGIN as i64 - delay as i64,
+            );
+        }
+    }
+
+    /// the newest tap of the filter for the first sample of the frame is at `newest`
+    fn cancel(&mut self, frame: &mut [f32], newest: i64) {
+        let oldest = newest - FILTER_LEN as i64 + 1;
+        let mut window = std::mem::take(&mut self.window);
+        window.clear();
+        window.extend(
+            (0..(frame.len() + FILTER_LEN - 1) as i64).map(|i| self.reference_at(oldest + i)),
+        );
+
+        let mut energy: f32 = window[..FILTER_LEN - 1].iter().map(|x| x * x).sum();
+        let (mut foreground_error, mut background_error) = (0_f32, 0_f32);
+
+        for (i, sample) in frame.iter_mut().enumerate() {
+            let x = &window[i..i + FILTER_LEN];
+            energy += x[FILTER_LEN - 1] * x[FILTER_LEN - 1];
+
+            let foreground = *sample - dot(&self.foreground, x);
+            let background = *sample - dot(&self.background, x);
+            foreground_error += foreground * foreground;
+            background_error += background * background;
+
+            let step = STEP * background / (energy.max(0_f32) + REGULARIZATION);
+            for (weight, x) in self.background.iter_mut().zip(x) {
+                *weight += step * x;
+            }
+
+            energy -= x[0] * x[0];
+            *sample = foreground;
+        }
+
+        if background_error < foreground_error {
+            self.foreground.copy_from_slice(&self.background);
+        } else if background_error > foreground_error * DIVERGENCE {
+            self.background.copy_from_slice(&self.foreground);
+        }
+
+        self.window = window;
+    }
+
+    /// finds the delay at which the reference best matches the recent capture
+    fn estimate_delay(&mut self, offset: i64) {
+        let capture = decimate(self.capture.iter().copied());
+
+        // the reference from the longest delay up to no delay, lined up with the capture
+        let end = self.capture_count + offset;
+        let start = end - (ESTIMATE_LEN + MAX_DELAY) as i64;
+        let reference = decimate((start..end).map(|position| self.reference_at(position)));
+
+        let capture_energy: f32 = capture.iter().map(|x| x * x).sum();
+        if capture_energy < MIN_ENERGY * capture.len() as f32 {
+            return;
+        }
+
+        // the energy of every reference window from prefix sums
+        let mut prefix = vec![0_f32; reference.len() + 1];
+        for (i, x) in reference.iter().enumerate() {
+            prefix[i + 1] = prefix[i] + x * x;
+        }
+
+        let lags = MAX_DELAY / DECIMATION;
+        let best = (0..=lags)
+            .filter_map(|lag| {
+                let first = lags - lag;
+                let reference = &reference[first..first + capture.len()];
+                let energy = prefix[first + capture.len()] - prefix[first];
+                if energy < MIN_ENERGY * capture.len() as f32 {
+                    return None;
+                }
+
+                let correlation = dot(&capture, reference) / (capture_energy * energy).sqrt();
+                Some((lag * DECIMATION, correlation.abs()))
+            })
+            .max_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        let Some((delay, correlation)) = best else {
+            return;
+        };
+        if correlation < MIN_CORRELATION {
+            return;
+        }
+
+        match self.delay {
+            // small changes are followed by moving the filter taps
+            Some(current) if current.abs_diff(delay) <= DELAY_MARGIN / 2 => (),
+            Some(current) if current.abs_diff(delay) < FILTER_LEN => {
+                shift(&mut self.foreground, delay as i64 - current as i64);
+                shift(&mut self.background, delay as i64 - current as i64);
+                self.delay = Some(delay);
+            }
+            _ => {
+                self.foreground.fill(0_f32);
+                self.background.fill(0_f32);
+                self.delay = Some(delay);
+            }
+        }
+    }
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+/// averages blocks of samples
+fn decimate(samples: impl Iterator<Item = f32>) -> Vec<f32> {
+    let samples: Vec<f32> = samples.collect();
+    samples
+        .chunks_exact(DECIMATION)
+        .map(|chunk| chunk.iter().sum::<f32>() / DECIMATION as f32)
+        .collect()
+}
+
+/// moves the taps of a filter towards its end by `by` samples, or towards its start when negative
+fn shift(filter: &mut [f32], by: i64) {
+    let by_abs = by.unsigned_abs() as usize;
+
+    if by > 0 {
+        filter.rotate_right(by_abs);
+        filter[..by_abs].fill(0_f32);
+    } else {
+        filter.rotate_left(by_abs);
+        let len = filter.len();
+        filter[len - by_abs..].fill(0_f32);
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(target_family = "wasm"))]
+mod tests {
+    use super::*;
+    use kanal::unbounded;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    const FRAME: usize = 480;
+    /// the delay of the synthetic echo path
+    const ECHO_DELAY: usize = 3_000;
+
+    fn noise(rng: &mut StdRng, len: usize, level: f32) -> Vec<f32> {
+        (0..len).map(|_| rng.gen_range(-level..level)).collect()
+    }
+
+    /// a delayed, decaying room response over a noise floor
+    fn echo(rng: &mut StdRng, reference: &[f32]) -> Vec<f32> {
+        let response = [(0, 0.6), (7, -0.3), (40, 0.15), (200, 0.05)];
+
+        (0..reference.len())
+            .map(|i| {
+                response
+                    .iter()
+                    .filter_map(|&(tap, gain)| {
+                        let index = i.checked_sub(ECHO_DELAY + tap)?;
+                        Some(reference[index] * gain)
+                    })
+                    .sum::<f32>()
+                    + rng.gen_range(-0.001..0.001)
+            })
+            .collect()
+    }
+
+    fn energy(samples: &[f32]) -> f32 {
+        samples.iter().map(|x| x * x).sum()
+    }
+
+    /// echo return loss enhancement in decibels
+    fn erle(captured: &[f32], processed: &[f32]) -> f32 {
+        10_f32 * (energy(captured) / energy(processed)).log10()
+    }
+
+    #[test]
+    fn cancels_delayed_echo() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let frames = 150;
+        let reference = noise(&mut rng, FRAME * frames, 0.5);
+        let captured = echo(&mut rng, &reference);
+
+        let (sender, receiver) = unbounded();
+        let mut canceller = EchoCanceller::new(receiver, 1_f64, FRAME, 1).unwrap();
+        let mut processed = Vec::new();
+
+        // the output runs two frames ahead of the capture
+        for chunk in reference[..FRAME * 2].iter() {
+            sender.send(*chunk).unwrap();
+        }
+
+        for i in 0..frames {
+            if let Some(chunk) = reference.get(FRAME * (i + 2)..FRAME * (i + 3)) {
+                chunk
+                    .iter()
+                    .for_each(|sample| sender.send(*sample).unwrap());
+            }
+
+            let mut frame = [captured[FRAME * i..FRAME * (i + 1)].to_vec()];
+            canceller.process(&mut frame, FRAME).unwrap();
+            processed.extend(&frame[0]);
+        }
+
+        let tail = FRAME * (frames - 50);
+        let erle = erle(&captured[tail..], &processed[tail..]);
+        assert!(erle > 20_f32, "erle {}", erle);
+    }
+
+    #[test]
+    fn cancels_echo_on_every_channel() {
+        let mut rng = StdRng::seed_from_u64(13);
+        let frames = 150;
+        let reference = noise(&mut rng, FRAME * frames, 0.5);
+        // the second microphone picks up a quieter, inverted echo
+        let left = echo(&mut rng, &reference);
+        let right: Vec<f32> = echo(&mut rng, &reference)
+            .iter()
+            .map(|x| x * -0.5)
+            .collect();
+
+        let (sender, receiver) = unbounded();
+        let mut canceller = EchoCanceller::new(receiver, 1_f64, FRAME, 2).unwrap();
+        let mut processed = [Vec::new(), Vec::new()];
+
+        for i in 0..frames {
+            let frame_range = FRAME * i..FRAME * (i + 1);
+            reference[frame_range.clone()]
+                .iter()
+                .for_each(|sample| sender.send(*sample).unwrap());
+
+            let mut frame = [
+                left[frame_range.clone()].to_vec(),
+                right[frame_range].to_vec(),
+            ];
+            canceller.process(&mut frame, FRAME).unwrap();
+            processed[0].extend(&frame[0]);
+            processed[1].extend(&frame[1]);
+        }
+
+        let tail = FRAME * (frames - 50);
+        for (captured, processed) in [left, right].iter().zip(&processed) {
+            let erle = erle(&captured[tail..], &processed[tail..]);
+            assert!(erle > 20_f32, "erle {}", erle);
+        }
+    }
+
+    #[test]
+    fn keeps_near_end_speech() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let frames = 250;
+        let reference = noise(&mut rng, FRAME * frames, 0.5);
+        let echo = echo(&mut rng, &reference);
+
+        // the near end talks over the echo from frame 100 to 200
+        let mut near = vec![0_f32; FRAME * frames];
+        let talk = FRAME * 100..FRAME * 200;
+        near[talk.clone()].copy_from_slice(&noise(&mut rng, talk.len(), 0.3));
+
+        let mut path = EchoPath::new();
+        let mut processed = Vec::new();
+
+        for i in 0..frames {
+            let frame_range = FRAME * i..FRAME * (i + 1);
+            path.push_reference(&reference[frame_range.clone()]);
+
+            let mut frame: Vec<f32> = frame_range.map(|i| echo[i] + near[i]).collect();
+            path.process(&mut frame);
+            processed.extend(frame);
+        }
+
+        // the near end passes through while both talk
+        let residual: Vec<f32> = talk.clone().map(|i| processed[i] - near[i]).collect();
+        let passed = erle(&near[talk.clone()], &residual);
+        assert!(passed > 15_f32, "near end to residual {}", passed);
+
+        // and the echo stays cancelled afterwards
+        let after = FRAME * 210..;
+        let erle = erle(&echo[after.clone()], &processed[after]);
+        assert!(erle > 20_f32, "erle {}", erle);
+    }
+}
@@ -9,6 +9,7 @@ use libp2p::swarm::{DialError, SwarmEvent};
 use libp2p::{TransportBuilderError, TransportError};
 use libp2p_stream::{AlreadyRegistered, OpenStreamError};
 use rubato::{ResampleError, ResamplerConstructionError};
+use sea_codec::SeaError;
 use tokio::task::JoinError;
 use tokio::time::error::Elapsed;
 
@@ -34,6 +35,7 @@ pub(crate) enum ErrorKind {
     KanalSend(kanal::SendError),
     KanalReceive(kanal::ReceiveError),
     KanalClose(kanal::CloseError),
+    Codec(SeaError),
     Join(JoinError),
     AddrParse(AddrParseError),
     Timeout(Elapsed),
@@ -156,6 +158,14 @@ impl From<kanal::CloseError> for Error {
     }
 }
 
+impl From<SeaError> for Error {
+    fn from(err: SeaError) -> Self {
+        Self {
+            kind: ErrorKind::Codec(err),
+        }
+    }
+}
+
 impl From<JoinError> for Error {
     fn from(err: JoinError) -> Self {
         Self {
@@ -311,6 +321,7 @@ impl Display for Error {
                 ErrorKind::KanalSend(ref err) => format!("Kanal send error: {}", err),
                 ErrorKind::KanalReceive(ref err) => format!("Kanal receive error: {}", err),
                 ErrorKind::KanalClose(ref err) => format!("Kanal close error: {}", err),
+                ErrorKind::Codec(ref err) => format!("Codec error: {:?}", err),
                 ErrorKind::Join(ref err) => format!("Join error: {}", err),
                 ErrorKind::Timeout(_) => "The connection timed out".to_string(),
                 #[cfg(target_family = "wasm")]
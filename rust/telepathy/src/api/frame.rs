@@ -0,0 +1,264 @@
+use atomic_float::AtomicF64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering::Relaxed;
+use std::sync::Arc;
+#[cfg(not(target_family = "wasm"))]
+use tokio::time::Instant;
+use tokio_util::bytes::{BufMut, Bytes, BytesMut};
+#[cfg(target_family = "wasm")]
+use wasmtimer::std::Instant;
+
+/// the version of the frame format, frames of other versions are dropped
+pub(crate) const FRAME_VERSION: u8 = 1;
+/// version, frame type, sequence number and timestamp
+pub(crate) const HEADER_SIZE: usize = 1 + 1 + 4 + 4;
+/// the jitter estimate moves 1/16 of the way towards each new deviation, as in RFC 3550
+const JITTER_SMOOTHING: f64 = 16_f64;
+/// gaps longer than this are not concealed frame by frame, the output catches up by itself
+const MAX_CONCEALED: u32 = 8;
+
+/// what the payload of a frame holds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum FrameType {
+    /// raw PCM or a SEA packet
+    Audio = 0,
+    /// the sender is silent, there is no payload
+    Silence = 1,
+}
+
+/// the header every audio frame on the transport starts with
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct FrameHeader {
+    pub(crate) frame_type: FrameType,
+    /// counts every frame the sender sends, wrapping around
+    pub(crate) sequence: u32,
+    /// the sender's clock when the frame left its input processor in microseconds, wrapping around
+    pub(crate) timestamp: u32,
+}
+
+impl FrameHeader {
+    /// splits a frame into its header and payload, None for frames this version does not understand
+    pub(crate) fn parse(mut frame: Bytes) -> Option<(Self, Bytes)> {
+        if frame.len() < HEADER_SIZE || frame[0] != FRAME_VERSION {
+            return None;
+        }
+
+        let frame_type = match frame[1] {
+            0 => FrameType::Audio,
+            1 => FrameType::Silence,
+            _ => return None,
+        };
+
+        let header = Self {
+            frame_type,
+            sequence: u32::from_be_bytes(frame[2..6].try_into().ok()?),
+            timestamp: u32::from_be_bytes(frame[6..10].try_into().ok()?),
+        };
+
+        Some((header, frame.split_off(HEADER_SIZE)))
+    }
+}
+
+/// stamps outgoing frames with a sequence number and a timestamp
+pub(crate) struct FrameWriter {
+    sequence: u32,
+    start: Instant,
+}
+
+impl FrameWriter {
+    pub(crate) fn new() -> Self {
+        Self {
+            sequence: 0,
+            start: Instant::now(),
+        }
+    }
+
+    pub(crate) fn write(&mut self, frame_type: FrameType, payload: &[u8]) -> Bytes {
+        let mut frame = BytesMut::with_capacity(HEADER_SIZE + payload.len());
+        frame.put_u8(FRAME_VERSION);
+        frame.put_u8(frame_type as u8);
+        frame.put_u32(self.sequence);
+        frame.put_u32(self.start.elapsed().as_micros() as u32);
+        frame.put_slice(payload);
+
+        self.sequence = self.sequence.wrapping_add(1);
+        frame.freeze()
+    }
+}
+
+/// what the receiver does with an arriving frame
+#[derive(Debug, PartialEq)]
+pub(crate) enum Arrival {
+    /// the frame is next in sequence after this many lost frames which should be concealed
+    InOrder { lost: u32 },
+    /// the frame was already received or concealed and is dropped
+    Late,
+}
+
+/// follows the sequence numbers and timestamps of incoming frames
+pub(crate) struct FrameReceiver {
+    /// the sequence number expected next, None before the first frame
+    expected: Option<u32>,
+    start: Instant,
+    /// the arrival time and timestamp of the previous in order frame
+    previous: Option<(u32, u32)>,
+    statistics: Arc<FrameStatistics>,
+}
+
+impl FrameReceiver {
+    pub(crate) fn new(statistics: Arc<FrameStatistics>) -> Self {
+        Self {
+            expected: None,
+            start: Instant::now(),
+            previous: None,
+            statistics,
+        }
+    }
+
+    pub(crate) fn receive(&mut self, header: &FrameHeader) -> Arrival {
+        self.receive_at(header, Instant::now())
+    }
+
+    fn receive_at(&mut self, header: &FrameHeader, now: Instant) -> Arrival {
+        let lost = match self.expected {
+            // serial number arithmetic, frames behind the expected one are late or duplicated
+            Some(expected) if (header.sequence.wrapping_sub(expected) as i32) < 0 => {
+                self.statistics.late.fetch_add(1, Relaxed);
+                return Arrival::Late;
+            }
+            Some(expected) => header.sequence.wrapping_sub(expected),
+            None => 0,
+        };
+
+        self.expected = Some(header.sequence.wrapping_add(1));
+        self.statistics.received.fetch_add(1, Relaxed);
+        self.statistics.lost.fetch_add(lost as usize, Relaxed);
+
+        // the interarrival jitter of RFC 3550, the variation of the one-way delay
+        let arrival = now.saturating_duration_since(self.start).as_micros() as u32;
+        if let Some((previous_arrival, previous_timestamp)) = self.previous {
+            let arrived = arrival.wrapping_sub(previous_arrival) as i32 as f64;
+            let sent = header.timestamp.wrapping_sub(previous_timestamp) as i32 as f64;
+            let deviation = (arrived - sent).abs() / 1_000_f64;
+
+            let jitter = self.statistics.jitter.load(Relaxed);
+            self.statistics
+                .jitter
+                .store(jitter + (deviation - jitter) / JITTER_SMOOTHING, Relaxed);
+        }
+        self.previous = Some((arrival, header.timestamp));
+
+        Arrival::InOrder {
+            lost: lost.min(MAX_CONCEALED),
+        }
+    }
+}
+
+/// counters shared between the receiving socket and the statistics
+#[derive(Default)]
+pub(crate) struct FrameStatistics {
+    pub(crate) received: AtomicUsize,
+    /// frames which never arrived in sequence
+    pub(crate) lost: AtomicUsize,
+    /// frames which arrived after a later frame, or more than once
+    pub(crate) late: AtomicUsize,
+    /// the smoothed variation of the one-way delay in milliseconds
+    pub(crate) jitter: AtomicF64,
+}
+
+#[cfg(test)]
+#[cfg(not(target_family = "wasm"))]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn roundtrip() {
+        let mut writer = FrameWriter::new();
+        writer.write(FrameType::Silence, &[]);
+        let frame = writer.write(FrameType::Audio, &[1, 2, 3]);
+        assert_eq!(frame.len(), HEADER_SIZE + 3);
+
+        let (header, payload) = FrameHeader::parse(frame.clone()).unwrap();
+        assert_eq!(header.frame_type, FrameType::Audio);
+        assert_eq!(header.sequence, 1);
+        assert_eq!(&payload[..], &[1, 2, 3]);
+
+        // other versions and truncated frames are not understood
+        let mut other = frame.to_vec();
+        other[0] = FRAME_VERSION + 1;
+        assert!(FrameHeader::parse(Bytes::from(other)).is_none());
+        assert!(FrameHeader::parse(frame.slice(..HEADER_SIZE - 1)).is_none());
+    }
+
+    #[test]
+    fn detects_loss_and_late_frames() {
+        let statistics: Arc<FrameStatistics> = Default::default();
+        let mut receiver = FrameReceiver::new(Arc::clone(&statistics));
+        let now = Instant::now();
+        let header = |sequence: u32| FrameHeader {
+            frame_type: FrameType::Audio,
+            sequence,
+            timestamp: sequence.wrapping_mul(10_000),
+        };
+
+        // the sequence starts wherever the receiver joins and wraps around
+        let start = u32::MAX - 2;
+        for i in 0..3 {
+            let arrival = receiver.receive_at(&header(start.wrapping_add(i)), now);
+            assert_eq!(arrival, Arrival::InOrder { lost: 0 });
+        }
+
+        // two frames go missing, one arrives late and one twice
+        assert_eq!(
+            receiver.receive_at(&header(2), now),
+            Arrival::InOrder { lost: 2 }
+        );
+        assert_eq!(receiver.receive_at(&header(1), now), Arrival::Late);
+        assert_eq!(receiver.receive_at(&header(2), now), Arrival::Late);
+
+        // long gaps are counted but only partly concealed
+        assert_eq!(
+            receiver.receive_at(&header(103), now),
+            Arrival::InOrder {
+                lost: MAX_CONCEALED
+            }
+        );
+
+        assert_eq!(statistics.received.load(Relaxed), 5);
+        assert_eq!(statistics.lost.load(Relaxed), 102);
+        assert_eq!(statistics.late.load(Relaxed), 2);
+    }
+
+    #[test]
+    fn jitter_follows_delay_variation() {
+        let statistics: Arc<FrameStatistics> = Default::default();
+        let mut receiver = FrameReceiver::new(Arc::clone(&statistics));
+        let start = Instant::now();
+        let frame = Duration::from_millis(10);
+
+        // a constant delay has no jitter
+        for i in 0..100 {
+            let header = FrameHeader {
+                frame_type: FrameType::Audio,
+                sequence: i,
+                timestamp: i * 10_000,
+            };
+            receiver.receive_at(&header, start + frame * i);
+        }
+        assert!(statistics.jitter.load(Relaxed) < 0.01);
+
+        // the delay alternates by 8ms
+        for i in 100..300 {
+            let header = FrameHeader {
+                frame_type: FrameType::Audio,
+                sequence: i,
+                timestamp: i * 10_000,
+            };
+            let delay = Duration::from_millis(8 * (i % 2) as u64);
+            receiver.receive_at(&header, start + frame * i + delay);
+        }
+        let jitter = statistics.jitter.load(Relaxed);
+        assert!((jitter - 8_f64).abs() < 0.5, "jitter {}", jitter);
+    }
+}
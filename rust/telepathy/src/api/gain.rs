@@ -0,0 +1,204 @@
+use crate::api::utils::{calculate_rms, db_to_multiplier};
+use atomic_float::AtomicF32;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering::Relaxed;
+use std::sync::Arc;
+
+/// the input processor works on samples scaled to the i16 range
+const FULL_SCALE: f32 = i16::MAX as f32;
+/// the limiter keeps peaks below this level, about -0.5 dBFS
+const LIMIT: f32 = 0.95 * FULL_SCALE;
+/// the time constant the limiter recovers with in seconds
+const LIMITER_RELEASE: f32 = 0.05;
+/// loud talkers are attenuated by at most 20 dB
+const MIN_GAIN: f32 = 0.1;
+
+/// The automatic gain control settings, changes apply to the running input processor
+pub(crate) struct AgcSettings {
+    pub(crate) enabled: AtomicBool,
+    /// the rms level speech is brought to as a fraction of full scale
+    pub(crate) target_level: AtomicF32,
+    /// the largest multiplier applied to quiet input
+    pub(crate) max_gain: AtomicF32,
+    /// the time constant of lowering the gain in seconds
+    pub(crate) attack: AtomicF32,
+    /// the time constant of raising the gain in seconds
+    pub(crate) release: AtomicF32,
+}
+
+impl Default for AgcSettings {
+    fn default() -> Self {
+        Self {
+            enabled: Default::default(),
+            target_level: AtomicF32::new(db_to_multiplier(-18_f32)),
+            max_gain: AtomicF32::new(db_to_multiplier(30_f32)),
+            attack: AtomicF32::new(0.05),
+            release: AtomicF32::new(1_f32),
+        }
+    }
+}
+
+/// Brings speech to a steady level and limits peaks so the output never clips
+pub(crate) struct AutomaticGain {
+    settings: Arc<AgcSettings>,
+    /// the gain applied at the end of the previous frame
+    gain: f32,
+    /// the peak envelope followed by the limiter
+    envelope: f32,
+    /// the current gain in decibels for the statistics
+    applied: Arc<AtomicF32>,
+    sample_rate: f32,
+}
+
+impl AutomaticGain {
+    pub(crate) fn new(
+        settings: Arc<AgcSettings>,
+        applied: Arc<AtomicF32>,
+        sample_rate: f64,
+    ) -> Self {
+        Self {
+            settings,
+            gain: 1_f32,
+            envelope: 0_f32,
+            applied,
+            sample_rate: sample_rate as f32,
+        }
+    }
+
+    /// applies the gain and limiter to a frame in place, the gain only adapts to speech
+    pub(crate) fn process(&mut self, frame: &mut [f32], speech: bool) {
+        let previous = self.gain;
+
+        if self.settings.enabled.load(Relaxed) {
+            let max_gain = self.settings.max_gain.load(Relaxed);
+            let level = calculate_rms(frame) / FULL_SCALE;
+
+            if speech && level > 0_f32 {
+                let target = self.settings.target_level.load(Relaxed);
+                let desired = (target / level).min(max_gain).max(MIN_GAIN);
+
+                let time = if desired < self.gain {
+                    self.settings.attack.load(Relaxed)
+                } else {
+                    self.settings.release.load(Relaxed)
+                };
+                let duration = frame.len() as f32 / self.sample_rate;
+                let coefficient = 1_f32 - (-duration / time.max(f32::EPSILON)).exp();
+
+                // moving in decibels makes raising and lowering equally smooth
+                let gain = self.gain.ln() + (desired.ln() - self.gain.ln()) * coefficient;
+                self.gain = gain.exp();
+            }
+
+            // the max gain may have been lowered since the last frame
+            self.gain = self.gain.min(max_gain).max(MIN_GAIN);
+        } else {
+            self.gain = 1_f32;
+        }
+
+        self.applied.store(20_f32 * self.gain.log10(), Relaxed);
+
+        // ramp between the gains of the frames and limit every sample
+        let step = (self.gain - previous) / frame.len() as f32;
+        let release = (-1_f32 / (LIMITER_RELEASE * self.sample_rate)).exp();
+
+        for (i, sample) in frame.iter_mut().enumerate() {
+            *sample *= previous + step * (i + 1) as f32;
+
+            // the envelope rises instantly, so no sample can pass above the limit
+            self.envelope = sample.abs().max(self.envelope * release);
+            if self.envelope > LIMIT {
+                *sample *= LIMIT / self.envelope;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_RATE: f64 = 48_000_f64;
+    const FRAME: usize = 480;
+
+    /// a sine at a level in dBFS, its rms is 3 dB below its peak
+    fn sine(level: f32, frames: usize) -> Vec<f32> {
+        let amplitude = db_to_multiplier(level + 3_f32) * FULL_SCALE;
+        (0..frames * FRAME)
+            .map(|i| {
+                (i as f32 * std::f32::consts::TAU * 220_f32 / SAMPLE_RATE as f32).sin() * amplitude
+            })
+            .collect()
+    }
+
+    fn run(agc: &mut AutomaticGain, mut samples: Vec<f32>) -> Vec<f32> {
+        for frame in samples.chunks_mut(FRAME) {
+            agc.process(frame, true);
+        }
+
+        samples
+    }
+
+    fn level(samples: &[f32]) -> f32 {
+        20_f32 * (calculate_rms(samples) / FULL_SCALE).log10()
+    }
+
+    fn enabled() -> (AutomaticGain, Arc<AtomicF32>) {
+        let settings = AgcSettings::default();
+        settings.enabled.store(true, Relaxed);
+        let applied: Arc<AtomicF32> = Default::default();
+        let agc = AutomaticGain::new(Arc::new(settings), Arc::clone(&applied), SAMPLE_RATE);
+        (agc, applied)
+    }
+
+    #[test]
+    fn reaches_target_level() {
+        for input in [-40_f32, -30_f32, -6_f32] {
+            let (mut agc, applied) = enabled();
+            let output = run(&mut agc, sine(input, 500));
+
+            let output_level = level(&output[output.len() - FRAME * 50..]);
+            assert!(
+                (output_level + 18_f32).abs() < 1_f32,
+                "{} -> {}",
+                input,
+                output_level
+            );
+            assert!((applied.load(Relaxed) - (-18_f32 - input)).abs() < 1_f32);
+        }
+    }
+
+    #[test]
+    fn gain_is_capped() {
+        let (mut agc, applied) = enabled();
+        run(&mut agc, sine(-70_f32, 1000));
+        assert!((applied.load(Relaxed) - 30_f32).abs() < 0.01);
+    }
+
+    #[test]
+    fn attacks_faster_than_it_releases() {
+        let (mut agc, applied) = enabled();
+        run(&mut agc, sine(-18_f32, 100));
+
+        // 200ms after getting 12 dB louder most of the change has been made
+        run(&mut agc, sine(-6_f32, 20));
+        assert!(applied.load(Relaxed) < -11_f32, "{}", applied.load(Relaxed));
+
+        // while getting quieter by as much is followed much more slowly
+        run(&mut agc, sine(-6_f32, 100));
+        run(&mut agc, sine(-18_f32, 20));
+        assert!(applied.load(Relaxed) < -8_f32, "{}", applied.load(Relaxed));
+    }
+
+    #[test]
+    fn limiter_prevents_clipping() {
+        let applied: Arc<AtomicF32> = Default::default();
+        let mut agc = AutomaticGain::new(Default::default(), applied, SAMPLE_RATE);
+
+        // 6 dB over full scale with the gain control off
+        let output = run(&mut agc, sine(3_f32, 10));
+        let peak = output.iter().fold(0_f32, |peak, x| peak.max(x.abs()));
+        // the peaks are held at the limit rather than squashed further
+        assert!((peak - LIMIT).abs() < 1_f32, "peak {}", peak);
+    }
+}
@@ -0,0 +1,315 @@
+// the web output plays frames as they arrive and only uses the stretching types
+#![cfg_attr(target_family = "wasm", allow(dead_code))]
+
+use crate::api::frame::FrameStatistics;
+use sea_codec::ProcessorMessage;
+use std::collections::VecDeque;
+use std::sync::atomic::Ordering::Relaxed;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// the playout delay covers this many times the estimated jitter
+const JITTER_MARGIN: f64 = 3_f64;
+/// the least number of frames held before playing
+const MIN_TARGET: usize = 1;
+/// the most number of frames the target grows to
+const MAX_TARGET: usize = 15;
+/// frames beyond this depth are dropped instead of being caught up on by shrinking
+const MAX_DEPTH: usize = 30;
+
+/// how a frame is played to move the buffer towards its target depth
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Stretch {
+    None,
+    /// the frame is played longer, the buffer fills up
+    Grow,
+    /// the frame is played shorter, the buffer drains
+    Shrink,
+}
+
+/// what the output should play next
+#[derive(Debug)]
+pub(crate) enum Playout {
+    Frame(ProcessorMessage, Stretch),
+    /// the next frame has not arrived in time and should be concealed
+    Missing,
+    /// the buffer is filling up before playing for the first time
+    Buffering,
+}
+
+/// holds received frames for a playout delay which follows the arrival jitter
+pub(crate) struct JitterBuffer {
+    frames: VecDeque<ProcessorMessage>,
+    /// the duration of audio in each frame
+    frame_duration: Duration,
+    /// the jitter the receiving socket estimates from the frame timestamps
+    statistics: Arc<FrameStatistics>,
+    /// the depth in frames the buffer stretches and shrinks towards
+    target: usize,
+    /// false until the buffer reaches its target, and again once it has run dry
+    playing: bool,
+}
+
+impl JitterBuffer {
+    pub(crate) fn new(frame_duration: Duration, statistics: Arc<FrameStatistics>) -> Self {
+        Self {
+            frames: VecDeque::with_capacity(MAX_DEPTH),
+            frame_duration,
+            statistics,
+            target: MIN_TARGET,
+            playing: false,
+        }
+    }
+
+    /// follows a change of the remote sample rate
+    pub(crate) fn set_frame_duration(&mut self, frame_duration: Duration) {
+        self.frame_duration = frame_duration;
+    }
+
+    /// adds a frame, the target follows the latest jitter estimate
+    pub(crate) fn push(&mut self, message: ProcessorMessage) {
+        let jitter = self.statistics.jitter.load(Relaxed) / 1_000_f64;
+        self.target = Self::target(jitter, self.frame_duration);
+
+        self.frames.push_back(message);
+        if self.frames.len() > MAX_DEPTH {
+            self.frames.pop_front();
+        }
+    }
+
+    /// takes the next frame when the output needs one
+    pub(crate) fn pop(&mut self) -> Playout {
+        if !self.playing {
+            if self.frames.len() < self.target {
+                return Playout::Buffering;
+            }
+
+            self.playing = true;
+        }
+
+        let depth = self.frames.len();
+        let Some(message) = self.frames.pop_front() else {
+            // after an underrun the buffer refills to its target instead of playing each frame
+            // as soon as it arrives
+            self.playing = false;
+            return Playout::Missing;
+        };
+
+        let stretch = if depth > self.target + 1 {
+            Stretch::Shrink
+        } else if depth < self.target {
+            Stretch::Grow
+        } else {
+            Stretch::None
+        };
+
+        Playout::Frame(message, stretch)
+    }
+
+    /// the audio waiting to be played
+    pub(crate) fn depth(&self) -> Duration {
+        self.frame_duration * self.frames.len() as u32
+    }
+
+    /// the number of frames needed to cover the jitter in seconds
+    fn target(jitter: f64, frame_duration: Duration) -> usize {
+        let frames = (JITTER_MARGIN * jitter / frame_duration.as_secs_f64()).ceil();
+        (frames as usize + MIN_TARGET).min(MAX_TARGET)
+    }
+}
+
+/// changes the length of a frame without clicks by splicing it at the offset where it best
+/// matches itself, the frame grows or shrinks by between a tenth and a half of its length
+/// interleaved channels are spliced at the same offset, found on their sum
+pub(crate) fn time_stretch(frame: &[f32], channels: usize, stretch: Stretch) -> Vec<f32> {
+    let len = frame.len() / channels;
+    let fade = len / 4;
+    let (min_shift, max_shift) = (len / 10, len / 2);
+
+    if stretch == Stretch::None || fade == 0 {
+        return frame.to_vec();
+    }
+
+    let mixed: Vec<f32>;
+    let mono = if channels == 1 {
+        frame
+    } else {
+        mixed = frame
+            .chunks(channels)
+            .map(|samples| samples.iter().sum())
+            .collect();
+        &mixed
+    };
+
+    // the splice starts at `start`, then fades from the frame into the frame `shift` samples
+    // later when shrinking or earlier when growing
+    let start = match stretch {
+        Stretch::Shrink => len / 8,
+        _ => len / 2,
+    };
+    let source = |shift: usize| match stretch {
+        Stretch::Shrink => start + shift,
+        _ => start - shift,
+    };
+
+    let reference = &mono[start..start + fade];
+    let shift = (min_shift..=max_shift)
+        .map(|shift| {
+            let candidate = &mono[source(shift)..source(shift) + fade];
+            (shift, similarity(reference, candidate))
+        })
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map_or(min_shift, |(shift, _)| shift);
+    let source = source(shift);
+
+    let mut output = Vec::with_capacity((len + max_shift) * channels);
+    output.extend_from_slice(&frame[..start * channels]);
+    output.extend((0..fade * channels).map(|i| {
+        let weight = (i / channels) as f32 / fade as f32;
+        frame[start * channels + i] * (1_f32 - weight) + frame[source * channels + i] * weight
+    }));
+    output.extend_from_slice(&frame[(source + fade) * channels..]);
+    output
+}
+
+/// the normalized cross correlation of two equally long slices
+fn similarity(a: &[f32], b: &[f32]) -> f32 {
+    let (mut product, mut energy_a, mut energy_b) = (0_f32, 0_f32, 0_f32);
+
+    for (a, b) in a.iter().zip(b) {
+        product += a * b;
+        energy_a += a * a;
+        energy_b += b * b;
+    }
+
+    let energy = (energy_a * energy_b).sqrt();
+    if energy > 0_f32 {
+        product / energy
+    } else {
+        0_f32
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(target_family = "wasm"))]
+mod tests {
+    use super::*;
+
+    const FRAME: Duration = Duration::from_millis(10);
+
+    fn sine(len: usize, period: f32) -> Vec<f32> {
+        (0..len)
+            .map(|i| (i as f32 * std::f32::consts::TAU / period).sin())
+            .collect()
+    }
+
+    #[test]
+    fn buffers_to_target_before_playing() {
+        let mut buffer = JitterBuffer::new(FRAME, Default::default());
+        assert!(matches!(buffer.pop(), Playout::Buffering));
+
+        buffer.push(ProcessorMessage::silence());
+        assert!(matches!(
+            buffer.pop(),
+            Playout::Frame(ProcessorMessage::Silence, Stretch::None)
+        ));
+        assert!(matches!(buffer.pop(), Playout::Missing));
+    }
+
+    #[test]
+    fn refills_to_target_after_underrun() {
+        let statistics: Arc<FrameStatistics> = Default::default();
+        statistics.jitter.store(25_f64, Relaxed);
+        let mut buffer = JitterBuffer::new(FRAME, statistics);
+
+        // 25ms of jitter hold nine frames
+        let target = MIN_TARGET + 8;
+        for _ in 0..target {
+            buffer.push(ProcessorMessage::silence());
+        }
+        for _ in 0..target {
+            assert!(matches!(buffer.pop(), Playout::Frame(..)));
+        }
+        assert!(matches!(buffer.pop(), Playout::Missing));
+
+        // a single late frame is held until the buffer is back at its target
+        for _ in 1..target {
+            buffer.push(ProcessorMessage::silence());
+            assert!(matches!(buffer.pop(), Playout::Buffering));
+        }
+        buffer.push(ProcessorMessage::silence());
+        assert!(matches!(buffer.pop(), Playout::Frame(..)));
+    }
+
+    #[test]
+    fn target_follows_jitter() {
+        let statistics: Arc<FrameStatistics> = Default::default();
+        let mut buffer = JitterBuffer::new(FRAME, Arc::clone(&statistics));
+
+        // without jitter the target stays at its minimum
+        for _ in 0..50 {
+            buffer.push(ProcessorMessage::silence());
+        }
+        assert_eq!(buffer.target, MIN_TARGET);
+
+        // the playout delay covers three times the jitter, rounded up to whole frames
+        statistics.jitter.store(25_f64, Relaxed);
+        buffer.push(ProcessorMessage::silence());
+        assert_eq!(buffer.target, MIN_TARGET + 8);
+
+        statistics.jitter.store(1_000_f64, Relaxed);
+        buffer.push(ProcessorMessage::silence());
+        assert_eq!(buffer.target, MAX_TARGET);
+
+        // a deep buffer shrinks its frames, then frames are dropped beyond the max depth
+        statistics.jitter.store(0_f64, Relaxed);
+        for _ in 0..50 {
+            buffer.push(ProcessorMessage::silence());
+        }
+        assert!(matches!(buffer.pop(), Playout::Frame(_, Stretch::Shrink)));
+        assert!(buffer.depth() <= FRAME * MAX_DEPTH as u32);
+    }
+
+    #[test]
+    fn stretches_without_clicks() {
+        let frame = sine(480, 96_f32);
+
+        for (stretch, longer) in [(Stretch::Grow, true), (Stretch::Shrink, false)] {
+            let output = time_stretch(&frame, 1, stretch);
+            assert_eq!(output.len() > frame.len(), longer);
+            assert!(output.len().abs_diff(frame.len()) >= 48);
+
+            // a periodic signal is spliced a whole number of periods apart
+            assert_eq!(output.len().abs_diff(frame.len()) % 96, 0);
+            let largest_step = output
+                .windows(2)
+                .map(|pair| (pair[1] - pair[0]).abs())
+                .fold(0_f32, f32::max);
+            assert!(largest_step < 0.1, "step {}", largest_step);
+        }
+
+        assert_eq!(time_stretch(&frame, 1, Stretch::None), frame);
+    }
+
+    #[test]
+    fn stretches_channels_alike() {
+        // not periodic within the frame, so there is a single best splice
+        let left: Vec<f32> = sine(480, 96_f32)
+            .iter()
+            .zip(sine(480, 41.7))
+            .map(|(a, b)| a + b * 0.5)
+            .collect();
+        let stereo: Vec<f32> = left.iter().flat_map(|x| [*x, *x * 0.5]).collect();
+
+        for stretch in [Stretch::Grow, Stretch::Shrink] {
+            let expected = time_stretch(&left, 1, stretch);
+            let output = time_stretch(&stereo, 2, stretch);
+            assert_eq!(output.len(), expected.len() * 2);
+
+            for (pair, x) in output.chunks(2).zip(expected) {
+                assert!((pair[0] - x).abs() < 1e-6);
+                assert!((pair[1] - x * 0.5).abs() < 1e-6);
+            }
+        }
+    }
+}
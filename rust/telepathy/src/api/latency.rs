@@ -0,0 +1,91 @@
+use atomic_float::AtomicF32;
+use std::sync::atomic::Ordering::Relaxed;
+
+/// The delay each stage of a call adds in milliseconds, written by the stages as they run
+#[derive(Default)]
+pub(crate) struct LatencyStages {
+    /// the buffer the input device fills before the input callback runs
+    pub(crate) input_buffer: AtomicF32,
+    /// the captured samples waiting for the input processor
+    pub(crate) input_queue: AtomicF32,
+    /// filling a frame, resampling it and the frames waiting for the encoder or socket
+    pub(crate) encoding: AtomicF32,
+    /// the decoded frames waiting to play and resampling them
+    pub(crate) decoding: AtomicF32,
+    /// the samples waiting for the output device
+    pub(crate) output_queue: AtomicF32,
+    /// the buffer the output callback fills before the device plays it
+    pub(crate) output_buffer: AtomicF32,
+}
+
+/// The estimated one-way delay of a call split into stages, in milliseconds
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct LatencyBreakdown {
+    pub(crate) capture: f32,
+    pub(crate) processing: f32,
+    pub(crate) network: f32,
+    pub(crate) jitter_buffer: f32,
+    pub(crate) playout: f32,
+}
+
+impl LatencyStages {
+    /// Combines the local stages with the network. The remote's capture and encoding cannot be
+    /// measured here, so the estimate assumes its pipeline adds what the local one does
+    pub(crate) fn breakdown(&self, round_trip: usize, jitter_buffer: usize) -> LatencyBreakdown {
+        LatencyBreakdown {
+            capture: self.input_buffer.load(Relaxed) + self.input_queue.load(Relaxed),
+            processing: self.encoding.load(Relaxed) + self.decoding.load(Relaxed),
+            network: round_trip as f32 / 2_f32,
+            jitter_buffer: jitter_buffer as f32,
+            playout: self.output_queue.load(Relaxed) + self.output_buffer.load(Relaxed),
+        }
+    }
+}
+
+impl LatencyBreakdown {
+    /// the mouth to ear delay
+    pub(crate) fn total(&self) -> f32 {
+        self.capture + self.processing + self.network + self.jitter_buffer + self.playout
+    }
+}
+
+/// the time `samples` take to play at `sample_rate` in milliseconds
+pub(crate) fn milliseconds(samples: usize, sample_rate: f64) -> f32 {
+    (samples as f64 / sample_rate * 1_000_f64) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_samples() {
+        assert_eq!(milliseconds(480, 48_000_f64), 10_f32);
+        assert_eq!(milliseconds(441, 44_100_f64), 10_f32);
+        assert_eq!(milliseconds(0, 48_000_f64), 0_f32);
+    }
+
+    #[test]
+    fn sums_stages() {
+        let stages = LatencyStages::default();
+        stages.input_buffer.store(5_f32, Relaxed);
+        stages.input_queue.store(1_f32, Relaxed);
+        stages.encoding.store(12_f32, Relaxed);
+        stages.decoding.store(2_f32, Relaxed);
+        stages.output_queue.store(20_f32, Relaxed);
+        stages.output_buffer.store(10_f32, Relaxed);
+
+        let breakdown = stages.breakdown(60, 40);
+        assert_eq!(
+            breakdown,
+            LatencyBreakdown {
+                capture: 6_f32,
+                processing: 14_f32,
+                network: 30_f32,
+                jitter_buffer: 40_f32,
+                playout: 30_f32,
+            }
+        );
+        assert_eq!(breakdown.total(), 120_f32);
+    }
+}
@@ -1,17 +1,37 @@
 mod codec;
 pub mod contact;
 pub mod crypto;
+/// flutter_rust_bridge:ignore
+mod echo;
 pub mod error;
+/// flutter_rust_bridge:ignore
+mod frame;
+/// flutter_rust_bridge:ignore
+mod gain;
 #[cfg(target_os = "ios")]
 mod ios;
+/// flutter_rust_bridge:ignore
+mod jitter;
+/// flutter_rust_bridge:ignore
+mod latency;
 pub mod logger;
+/// flutter_rust_bridge:ignore
+mod noise;
 pub mod overlay;
 pub mod player;
 /// flutter_rust_bridge:ignore
+mod recorder;
+/// flutter_rust_bridge:ignore
 mod screenshare;
+/// flutter_rust_bridge:ignore
+mod stream_config;
 pub mod telepathy;
 /// flutter_rust_bridge:ignore
 mod utils;
 /// flutter_rust_bridge:ignore
+mod vad;
+/// flutter_rust_bridge:ignore
+mod wav;
+/// flutter_rust_bridge:ignore
 #[cfg(target_family = "wasm")]
 mod web_audio;
@@ -0,0 +1,377 @@
+use sea_codec::ProcessorMessage;
+use tokio_util::bytes::Bytes;
+
+/// the order of the spectral envelope, enough for the broad shape of background noise
+const ORDER: usize = 8;
+/// the level followed by one byte per reflection coefficient
+pub(crate) const DESCRIPTOR_SIZE: usize = 1 + ORDER;
+/// the input processor works on samples scaled to the i16 range
+const FULL_SCALE: f32 = i16::MAX as f32;
+/// the quietest level a descriptor holds in -dBFS, it stands for digital silence
+const MIN_LEVEL: u8 = 127;
+/// the estimate follows the noise over about 20 frames
+const SMOOTHING: f32 = 0.05;
+/// a descriptor goes out with the first silence frame and every 50th after it
+const DESCRIPTOR_INTERVAL: usize = 50;
+/// comfort noise fades out when no descriptor refreshed it for this many frames, as when the peer mutes
+const STALE_AFTER: usize = 3 * DESCRIPTOR_INTERVAL;
+/// keeps the synthesis filter stable after quantization
+const MAX_REFLECTION: f32 = 0.99;
+
+/// the level and coarse spectrum of the background noise, in the spirit of RFC 3389
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct NoiseDescriptor {
+    /// the rms of the noise as a fraction of full scale
+    level: f32,
+    /// the reflection coefficients of an all-pole model of the noise spectrum
+    reflection: [f32; ORDER],
+}
+
+impl NoiseDescriptor {
+    pub(crate) fn to_bytes(&self) -> [u8; DESCRIPTOR_SIZE] {
+        let mut bytes = [0; DESCRIPTOR_SIZE];
+
+        bytes[0] = if self.level > 0_f32 {
+            (-20_f32 * self.level.log10())
+                .round()
+                .clamp(0_f32, MIN_LEVEL as f32) as u8
+        } else {
+            MIN_LEVEL
+        };
+
+        for (byte, k) in bytes[1..].iter_mut().zip(self.reflection) {
+            *byte = ((k.clamp(-1_f32, 1_f32) + 1_f32) * 127.5).round() as u8;
+        }
+
+        bytes
+    }
+
+    /// None when the bytes are not a descriptor
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != DESCRIPTOR_SIZE {
+            return None;
+        }
+
+        let level = if bytes[0] >= MIN_LEVEL {
+            0_f32
+        } else {
+            10_f32.powf(-(bytes[0] as f32) / 20_f32)
+        };
+
+        let mut reflection = [0_f32; ORDER];
+        for (k, byte) in reflection.iter_mut().zip(&bytes[1..]) {
+            *k = (*byte as f32 / 127.5 - 1_f32).clamp(-MAX_REFLECTION, MAX_REFLECTION);
+        }
+
+        Some(Self { level, reflection })
+    }
+}
+
+/// Follows the background noise between speech and describes it in the silence frames
+pub(crate) struct NoiseEstimator {
+    /// the smoothed autocorrelation of the noise, None before the first frame
+    autocorrelation: Option<[f32; ORDER + 1]>,
+    /// the silence frames sent since the last transmitted frame
+    silent_frames: usize,
+    /// the number of interleaved channels in a frame
+    channels: usize,
+}
+
+impl NoiseEstimator {
+    pub(crate) fn new(channels: usize) -> Self {
+        Self {
+            autocorrelation: None,
+            silent_frames: 0,
+            channels,
+        }
+    }
+
+    /// adds a frame without speech to the estimate, the first channel stands for all of them
+    pub(crate) fn analyze(&mut self, frame: &[f32]) {
+        let samples = || frame.iter().step_by(self.channels);
+        let len = frame.len() / self.channels;
+
+        let mut current = [0_f32; ORDER + 1];
+        for (lag, value) in current.iter_mut().enumerate() {
+            *value = samples()
+                .zip(samples().skip(lag))
+                .map(|(a, b)| a * b)
+                .sum::<f32>()
+                / len as f32;
+        }
+
+        match self.autocorrelation.as_mut() {
+            Some(autocorrelation) => {
+                for (value, current) in autocorrelation.iter_mut().zip(current) {
+                    *value += (current - *value) * SMOOTHING;
+                }
+            }
+            None => self.autocorrelation = Some(current),
+        }
+    }
+
+    /// the message for a frame which is not sent, a descriptor goes out at a low rate
+    pub(crate) fn silence(&mut self) -> ProcessorMessage {
+        let due = self.silent_frames.is_multiple_of(DESCRIPTOR_INTERVAL);
+        self.silent_frames += 1;
+
+        match self.descriptor().filter(|_| due) {
+            Some(descriptor) => {
+                ProcessorMessage::ComfortNoise(Bytes::copy_from_slice(&descriptor.to_bytes()))
+            }
+            None => ProcessorMessage::silence(),
+        }
+    }
+
+    /// a frame was sent, the next silence starts with a fresh descriptor
+    pub(crate) fn transmitted(&mut self) {
+        self.silent_frames = 0;
+    }
+
+    pub(crate) fn descriptor(&self) -> Option<NoiseDescriptor> {
+        let autocorrelation = self.autocorrelation?;
+        let power = autocorrelation[0];
+
+        let mut reflection = [0_f32; ORDER];
+        if power > 0_f32 {
+            // levinson-durbin recursion, a slight white noise floor keeps it well conditioned
+            let mut error = power * 1.0001;
+            let mut predictor = [0_f32; ORDER + 1];
+            predictor[0] = 1_f32;
+
+            for m in 1..=ORDER {
+                let correlation: f32 = (0..m).map(|j| predictor[j] * autocorrelation[m - j]).sum();
+                let k = (-correlation / error).clamp(-MAX_REFLECTION, MAX_REFLECTION);
+
+                let previous = predictor;
+                for j in 1..m {
+                    predictor[j] = previous[j] + k * previous[m - j];
+                }
+                predictor[m] = k;
+
+                reflection[m - 1] = k;
+                error *= 1_f32 - k * k;
+            }
+        }
+
+        Some(NoiseDescriptor {
+            level: power.max(0_f32).sqrt() / FULL_SCALE,
+            reflection,
+        })
+    }
+}
+
+/// Synthesizes noise matching the descriptors the sender includes with its silence frames
+pub(crate) struct ComfortNoise {
+    descriptor: Option<NoiseDescriptor>,
+    /// the frames generated since the descriptor arrived
+    age: usize,
+    /// the backward prediction errors of the lattice filter
+    state: [f32; ORDER],
+    /// the current output level, it ramps towards the descriptor's level
+    level: f32,
+    /// xorshift state for the excitation
+    seed: u32,
+}
+
+impl ComfortNoise {
+    pub(crate) fn new() -> Self {
+        Self {
+            descriptor: None,
+            age: 0,
+            state: [0_f32; ORDER],
+            level: 0_f32,
+            seed: 0x9E37_79B9,
+        }
+    }
+
+    /// takes a new descriptor, invalid ones are ignored
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        if let Some(descriptor) = NoiseDescriptor::from_bytes(bytes) {
+            self.descriptor = Some(descriptor);
+            self.age = 0;
+        }
+    }
+
+    /// whether there is noise to play, a stale descriptor plays until it has faded out
+    pub(crate) fn is_active(&self) -> bool {
+        self.target() > 0_f32 || self.level > 0_f32
+    }
+
+    /// fills a frame with noise in the -1 to 1 range
+    pub(crate) fn fill(&mut self, frame: &mut [f32]) {
+        let target = self.target();
+        let step = (target - self.level) / frame.len() as f32;
+        self.age += 1;
+
+        let Some(descriptor) = self.descriptor.as_ref() else {
+            frame.fill(0_f32);
+            return;
+        };
+
+        // the all-pole filter amplifies white noise by the inverse of its prediction gain
+        let prediction_gain: f32 = descriptor
+            .reflection
+            .iter()
+            .map(|k| 1_f32 - k * k)
+            .product();
+        // uniform noise between -1 and 1 has a variance of 1/3
+        let excitation = (3_f32 * prediction_gain).sqrt();
+
+        for sample in frame.iter_mut() {
+            self.seed ^= self.seed << 13;
+            self.seed ^= self.seed >> 17;
+            self.seed ^= self.seed << 5;
+            let white = self.seed as f32 / u32::MAX as f32 * 2_f32 - 1_f32;
+
+            // the lattice form of the synthesis filter, from the last stage to the first
+            let mut forward = white * excitation;
+            for m in (1..=ORDER).rev() {
+                let k = descriptor.reflection[m - 1];
+                forward -= k * self.state[m - 1];
+
+                if m < ORDER {
+                    self.state[m] = self.state[m - 1] + k * forward;
+                }
+            }
+            self.state[0] = forward;
+
+            self.level += step;
+            *sample = forward * self.level;
+        }
+
+        self.level = target;
+    }
+
+    /// the level a fresh descriptor asks for, nothing once it is stale
+    fn target(&self) -> f32 {
+        match self.descriptor.as_ref() {
+            Some(descriptor) if self.age < STALE_AFTER => descriptor.level,
+            _ => 0_f32,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    const FRAME: usize = 480;
+
+    /// white noise through a one pole lowpass, at the i16 scale of the input processor
+    fn background(frames: usize, amplitude: f32) -> Vec<f32> {
+        let mut rng = rand::thread_rng();
+        let mut previous = 0_f32;
+
+        (0..frames * FRAME)
+            .map(|_| {
+                previous = 0.9 * previous + rng.gen_range(-1_f32..1_f32) * amplitude;
+                previous
+            })
+            .collect()
+    }
+
+    fn rms(samples: &[f32]) -> f32 {
+        (samples.iter().map(|x| x * x).sum::<f32>() / samples.len() as f32).sqrt()
+    }
+
+    /// the correlation of neighbouring samples, a measure of the spectral tilt
+    fn tilt(samples: &[f32]) -> f32 {
+        let lagged: f32 = samples.iter().zip(&samples[1..]).map(|(a, b)| a * b).sum();
+        lagged / samples.iter().map(|x| x * x).sum::<f32>()
+    }
+
+    fn estimate(samples: &[f32]) -> NoiseEstimator {
+        let mut estimator = NoiseEstimator::new(1);
+        for frame in samples.chunks(FRAME) {
+            estimator.analyze(frame);
+        }
+
+        estimator
+    }
+
+    #[test]
+    fn descriptor_roundtrip() {
+        let descriptor = estimate(&background(50, 100_f32)).descriptor().unwrap();
+        let decoded = NoiseDescriptor::from_bytes(&descriptor.to_bytes()).unwrap();
+
+        let difference = 20_f32 * (decoded.level / descriptor.level).log10();
+        assert!(difference.abs() <= 0.5, "level off by {} dB", difference);
+        for (a, b) in descriptor.reflection.iter().zip(decoded.reflection) {
+            assert!((a - b).abs() <= 1_f32 / 127.5);
+        }
+
+        assert!(NoiseDescriptor::from_bytes(&[0; DESCRIPTOR_SIZE - 1]).is_none());
+    }
+
+    #[test]
+    fn matches_background() {
+        let samples = background(100, 50_f32);
+        let bytes = estimate(&samples).descriptor().unwrap().to_bytes();
+
+        let mut comfort_noise = ComfortNoise::new();
+        comfort_noise.update(&bytes);
+        let mut generated = vec![0_f32; 100 * FRAME];
+        for frame in generated.chunks_mut(FRAME) {
+            comfort_noise.fill(frame);
+        }
+
+        // the first frame ramps up
+        let generated: Vec<f32> = generated[FRAME..].iter().map(|x| x * FULL_SCALE).collect();
+
+        let difference = 20_f32 * (rms(&generated) / rms(&samples)).log10();
+        assert!(difference.abs() < 1.5, "level off by {} dB", difference);
+        assert!(
+            (tilt(&generated) - tilt(&samples)).abs() < 0.05,
+            "tilt {} instead of {}",
+            tilt(&generated),
+            tilt(&samples)
+        );
+    }
+
+    #[test]
+    fn descriptors_go_out_at_a_low_rate() {
+        let mut estimator = NoiseEstimator::new(1);
+        // nothing to describe before any noise was heard
+        assert!(matches!(estimator.silence(), ProcessorMessage::Silence));
+        estimator.transmitted();
+
+        estimator.analyze(&background(1, 100_f32));
+        let described = (0..2 * DESCRIPTOR_INTERVAL)
+            .filter(|_| matches!(estimator.silence(), ProcessorMessage::ComfortNoise(_)))
+            .count();
+        assert_eq!(described, 2);
+
+        // the first silence after speech is described right away
+        estimator.transmitted();
+        assert!(matches!(
+            estimator.silence(),
+            ProcessorMessage::ComfortNoise(_)
+        ));
+    }
+
+    #[test]
+    fn fades_out_when_stale() {
+        let bytes = estimate(&background(10, 100_f32))
+            .descriptor()
+            .unwrap()
+            .to_bytes();
+        let mut comfort_noise = ComfortNoise::new();
+        assert!(!comfort_noise.is_active());
+
+        comfort_noise.update(&bytes);
+        let mut frame = [0_f32; FRAME];
+        for _ in 0..STALE_AFTER {
+            assert!(comfort_noise.is_active());
+            comfort_noise.fill(&mut frame);
+        }
+        assert!(rms(&frame) > 0_f32);
+
+        // one more frame fades out to silence
+        comfort_noise.fill(&mut frame);
+        assert!(!comfort_noise.is_active());
+        comfort_noise.fill(&mut frame);
+        assert_eq!(rms(&frame), 0_f32);
+    }
+}
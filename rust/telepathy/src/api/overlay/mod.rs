@@ -22,6 +22,8 @@ mod windows;
 
 lazy_static! {
     pub(crate) static ref LATENCY: Arc<AtomicUsize> = Default::default();
+    /// the estimated one-way delay of the call in milliseconds
+    pub(crate) static ref DELAY: Arc<AtomicUsize> = Default::default();
     pub(crate) static ref LOSS: Arc<AtomicF64> = Default::default();
     pub(crate) static ref CONNECTED: Arc<AtomicBool> = Default::default();
     static ref FONT_HEIGHT: Arc<AtomicI32> = Default::default();
@@ -4,7 +4,7 @@ use std::sync::atomic::Ordering::Relaxed;
 
 use crate::api::overlay::color::{percent_to_color, BAD_COLOR};
 use crate::api::overlay::{
-    Result, BACKGROUND_COLOR, CONNECTED, FONT_COLOR, FONT_HEIGHT, LATENCY, LOSS,
+    Result, BACKGROUND_COLOR, CONNECTED, DELAY, FONT_COLOR, FONT_HEIGHT, LATENCY, LOSS,
 };
 use log::{error, info};
 use widestring::U16CString;
@@ -216,6 +216,26 @@ unsafe fn draw_overlay(hwnd: HWND) {
         string_format,
     );
 
+    bounding = draw_text(
+        "Delay:",
+        (bounding.X + bounding.Width + 30.0, 0.0),
+        FONT_COLOR.load(Relaxed),
+        graphics,
+        font,
+        string_format,
+    );
+
+    let delay = DELAY.load(Relaxed);
+    let color = percent_to_color(delay as f64 / 400.0);
+    bounding = draw_text(
+        &delay.to_string(),
+        (bounding.X + bounding.Width, 0.0),
+        color.argb(),
+        graphics,
+        font,
+        string_format,
+    );
+
     bounding = draw_text(
         "Loss:",
         (bounding.X + bounding.Width + 30.0, 0.0),
@@ -1,15 +1,15 @@
 use atomic_float::AtomicF32;
 use core::time::Duration;
 use cpal::traits::{DeviceTrait, StreamTrait};
-use cpal::{Host, SampleFormat};
+use cpal::Host;
 use flutter_rust_bridge::spawn;
 use flutter_rust_bridge::{frb, spawn_blocking_with};
 #[cfg(not(target_family = "wasm"))]
 use kanal::{bounded, Sender};
-use kanal::{unbounded, Receiver};
 use log::error;
 use nnnoiseless::FRAME_SIZE;
 use rubato::Resampler;
+use std::io::Cursor;
 use std::mem;
 use std::path::Path;
 #[cfg(target_family = "wasm")]
@@ -23,22 +23,19 @@ use tokio::sync::Notify;
 use tokio::task::spawn_blocking;
 #[cfg(not(target_family = "wasm"))]
 use tokio::time::sleep;
-use tokio_util::bytes::Bytes;
 #[cfg(target_family = "wasm")]
 use wasm_sync::{Condvar, Mutex};
 #[cfg(target_family = "wasm")]
 use wasmtimer::tokio::sleep;
 
-use crate::api::error::{DartError, Error, ErrorKind};
+use crate::api::error::{DartError, Error};
 use crate::api::telepathy::DeviceName;
 use crate::api::utils::{db_to_multiplier, get_output_device, mul, resampler_factory, SendStream};
+use crate::api::wav::{default_channel_mask, downmix, to_i16, Wav};
 use crate::frb_generated::FLUTTER_RUST_BRIDGE_HANDLER;
-use messages::AudioHeader;
-use sea_codec::decoder::SeaDecoder;
-use sea_codec::encoder::{EncoderSettings, SeaEncoder};
-use sea_codec::ProcessorMessage;
-
-type DecodedReceiver = (Receiver<ProcessorMessage>, usize);
+use sea_codec::encoder::EncoderSettings;
+use sea_codec::io::{SeaReader, SeaWriter};
+use sea_codec::SeaMetadata;
 
 #[frb(opaque)]
 pub struct SoundPlayer {
@@ -135,7 +132,12 @@ pub async fn load_ringtone(path: String) -> Result<(), DartError> {
                 .read_to_end(&mut wav_bytes)
                 .await
                 .map_err(|error| error.to_string())?;
-            let sea_bytes = wav_to_sea(&wav_bytes, 3.0)
+            let metadata = SeaMetadata {
+                title: path.file_stem().map(|stem| stem.to_string_lossy().into()),
+                ..Default::default()
+            };
+
+            let sea_bytes = wav_to_sea(&wav_bytes, 3.0, metadata)
                 .await
                 .map_err(|error| error.to_string())?;
             output_file
@@ -157,61 +159,42 @@ async fn play_sound(
     output_volume: Arc<AtomicF32>,
     output_device: DeviceName,
 ) -> Result<(), Error> {
-    if bytes.len() < 44 {
-        return Err(ErrorKind::InvalidWav.into());
-    }
-
     // get the output device & config
-    let output_device = get_output_device(&output_device, &host).await?;
+    let output_device = get_output_device(&output_device, &host).await?.device;
     let output_config = output_device.default_output_config()?;
 
-    // parse the input spec
-    let mut spec = AudioHeader::from(&bytes[0..44]);
-    let mut samples = None;
-
-    let sample_format = if spec.is_valid() {
-        // match the correct sample format
-        match spec.sample_format.as_str() {
-            "u8" => SampleFormat::U8,
-            "i16" => SampleFormat::I16,
-            "i32" => SampleFormat::I32,
-            "f32" => SampleFormat::F32,
-            "f64" => SampleFormat::F64,
-            _ => return Err(ErrorKind::UnknownSampleFormat.into()),
-        }
-    } else {
-        let (input_sender, input_receiver) = unbounded();
-        let (output_sender, output_receiver) = unbounded();
-        let input_sender = input_sender.to_async();
-
-        input_sender
-            .send(ProcessorMessage::Data(Bytes::copy_from_slice(&bytes[..14])))
-            .await?;
-
-        let decoder_handle =
-            spawn_blocking(move || SeaDecoder::new(input_receiver, output_sender).unwrap());
-
-        let mut decoder = decoder_handle.await?;
-        let header = decoder.get_header();
-        let sample_count =
-            (bytes.len() - 14) / header.chunk_size as usize * FRAME_SIZE / header.channels as usize;
-        spec.sample_rate = header.sample_rate;
-        spec.channels = header.channels as u32;
-
-        std::thread::spawn(move || while decoder.decode_frame().is_ok() {});
-
-        for chunk in bytes[14..].chunks(header.chunk_size as usize) {
-            input_sender
-                .send(ProcessorMessage::Data(Bytes::copy_from_slice(chunk)))
-                .await?;
+    // sound effects are short, so the whole file is decoded up front
+    let (sample_rate, channels, samples) = spawn_blocking(move || {
+        if Wav::detect(&bytes) {
+            let wav = Wav::parse(&bytes)?;
+            let (samples, channels) = wav.stereo_samples();
+            Ok::<_, Error>((wav.sample_rate, channels, samples))
+        } else {
+            let mut reader = SeaReader::new(bytes.as_slice())?;
+            let header = reader.header();
+            let (sample_rate, channels) = (header.sample_rate, header.channels);
+
+            let scale = 1_f32 / i16::MAX as f32;
+            let samples: Vec<f32> = reader
+                .read_all()?
+                .into_iter()
+                .map(|sample| sample as f32 * scale)
+                .collect();
+
+            // sea files can carry any number of channels, they are mixed down like wav files
+            if channels > 2 {
+                let channels = channels as usize;
+                let mask = default_channel_mask(channels);
+                Ok((sample_rate, 2, downmix(&samples, channels, mask)))
+            } else {
+                Ok((sample_rate, channels as usize, samples))
+            }
         }
-
-        samples = Some((output_receiver, sample_count));
-        SampleFormat::I16 // codec mode is 16 bit only
-    };
+    })
+    .await??;
 
     // the resampling ratio used by the processor
-    let ratio = output_config.sample_rate().0 as f64 / spec.sample_rate as f64;
+    let ratio = output_config.sample_rate().0 as f64 / sample_rate as f64;
 
     // sends samples from the processor to the output stream
     #[cfg(not(target_family = "wasm"))]
@@ -303,9 +286,8 @@ async fn play_sound(
     let processor_future = spawn_blocking_with(
         move || {
             processor(
-                (samples.is_none().then_some(bytes), samples),
-                sample_format,
-                spec,
+                samples,
+                channels,
                 output_volume,
                 sender,
                 output_channels,
@@ -342,27 +324,18 @@ async fn play_sound(
     Ok(())
 }
 
-/// Processes the WAV data
+/// Processes interleaved samples in -1..1 for playback
 fn processor(
-    input: (Option<Vec<u8>>, Option<DecodedReceiver>),
-    sample_format: SampleFormat,
-    spec: AudioHeader,
+    samples: Vec<f32>,
+    channels: usize,
     output_volume: Arc<AtomicF32>,
     #[cfg(not(target_family = "wasm"))] processed_sender: Sender<Vec<f32>>,
     #[cfg(target_family = "wasm")] audio_buffer: Arc<AudioBuffer>,
     output_channels: usize,
     ratio: f64,
 ) -> Result<(), Error> {
-    let (bytes, samples) = input;
-    let sample_size = sample_format.sample_size();
-    let channels_usize = spec.channels as usize;
-
     // the number of samples in the file
-    let sample_count = bytes
-        .as_ref()
-        .map(|b| (b.len() - 44) / sample_size / channels_usize)
-        .or_else(|| samples.as_ref().map(|(_, l)| *l))
-        .unwrap_or_default();
+    let sample_count = samples.len() / channels;
     // the number of audio samples which will be played
     let audio_len = (sample_count as f64 * ratio) as f32;
     let mut position = 0_f32; // the playback position
@@ -372,100 +345,32 @@ fn processor(
     let fade_out = fade_basis;
     let fade_in = audio_len - fade_basis;
 
+    // the frames handed to the resampler at once
+    let frames_per_chunk = FRAME_SIZE / channels;
+
     // rubato requires 10 extra bytes in the output buffer as a safety margin
-    let post_len = (FRAME_SIZE as f64 / spec.channels as f64 * ratio + 10.0) as usize;
+    let post_len = (frames_per_chunk as f64 * ratio + 10.0) as usize;
 
     // the output for the resampler
-    let mut post_buf = vec![vec![0_f32; post_len]; channels_usize];
+    let mut post_buf = vec![vec![0_f32; post_len]; channels];
     // the input for the resampler
-    let mut pre_buf = vec![vec![0_f32; FRAME_SIZE / spec.channels as usize]; channels_usize];
+    let mut pre_buf = vec![vec![0_f32; frames_per_chunk]; channels];
     // groups of samples ready to be sent to the output
     let mut out_buf = Vec::with_capacity(output_channels);
 
-    let mut resampler =
-        resampler_factory(ratio, channels_usize, FRAME_SIZE / spec.channels as usize)?;
+    let mut resampler = resampler_factory(ratio, channels, frames_per_chunk)?;
     let output_volume = output_volume.load(Relaxed);
 
-    let mut byte_chunks = bytes
-        .as_ref()
-        .map(|bytes| bytes[44..].chunks(FRAME_SIZE * sample_size));
-
-    loop {
-        match (byte_chunks.as_mut(), samples.as_ref()) {
-            (None, Some((samples, _))) => {
-                let samples = if let Ok(ProcessorMessage::Samples(samples)) = samples.recv() {
-                    samples
-                } else {
-                    break;
-                };
-
-                let scale = 1_f32 / i16::MAX as f32;
-
-                for (i, sample) in samples.chunks(channels_usize).enumerate() {
-                    for (j, channel) in sample.iter().enumerate() {
-                        let sample = *channel as f32 * scale;
-                        pre_buf[j][i] = sample;
-                    }
-                }
-            }
-            (Some(chunks), None) => {
-                let chunk = if let Some(chunk) = chunks.next() {
-                    chunk
-                } else {
-                    break;
-                };
-
-                match sample_format {
-                    SampleFormat::U8 => {
-                        let scale = 1_f32 / u8::MAX as f32;
+    for chunk in samples.chunks(frames_per_chunk * channels) {
+        // the last chunk is padded with silence
+        if chunk.len() < frames_per_chunk * channels {
+            pre_buf.iter_mut().for_each(|channel| channel.fill(0_f32));
+        }
 
-                        for (i, sample) in chunk.chunks(channels_usize).enumerate() {
-                            for (j, channel) in sample.iter().enumerate() {
-                                let sample = *channel as f32 * scale;
-                                pre_buf[j][i] = sample;
-                            }
-                        }
-                    }
-                    SampleFormat::I16 => {
-                        let scale = 1_f32 / i16::MAX as f32;
-
-                        for (i, sample) in chunk.chunks(2 * channels_usize).enumerate() {
-                            for (j, channel) in sample.chunks(2).enumerate() {
-                                let sample = i16::from_le_bytes(channel.try_into()?) as f32 * scale;
-                                pre_buf[j][i] = sample;
-                            }
-                        }
-                    }
-                    SampleFormat::I32 => {
-                        let scale = 1_f32 / i32::MAX as f32;
-
-                        for (i, sample) in chunk.chunks(4 * channels_usize).enumerate() {
-                            for (j, channel) in sample.chunks(4).enumerate() {
-                                let sample = i32::from_le_bytes(channel.try_into()?) as f32 * scale;
-                                pre_buf[j][i] = sample;
-                            }
-                        }
-                    }
-                    SampleFormat::F32 => {
-                        for (i, sample) in chunk.chunks(4 * channels_usize).enumerate() {
-                            for (j, channel) in sample.chunks(4).enumerate() {
-                                let sample = f32::from_le_bytes(channel.try_into()?);
-                                pre_buf[j][i] = sample;
-                            }
-                        }
-                    }
-                    SampleFormat::F64 => {
-                        for (i, sample) in chunk.chunks(8 * channels_usize).enumerate() {
-                            for (j, channel) in sample.chunks(8).enumerate() {
-                                let sample = f64::from_le_bytes(channel.try_into()?) as f32;
-                                pre_buf[j][i] = sample;
-                            }
-                        }
-                    }
-                    _ => return Err(ErrorKind::UnknownSampleFormat.into()),
-                }
+        for (i, frame) in chunk.chunks(channels).enumerate() {
+            for (j, sample) in frame.iter().enumerate() {
+                pre_buf[j][i] = *sample;
             }
-            _ => break,
         }
 
         for channel in pre_buf.iter_mut() {
@@ -476,7 +381,7 @@ fn processor(
             let processed = resampler.process_into_buffer(&pre_buf, &mut post_buf, None)?;
             (&mut post_buf, processed.1)
         } else {
-            (&mut pre_buf, FRAME_SIZE)
+            (&mut pre_buf, frames_per_chunk)
         };
 
         for i in 0..len {
@@ -500,7 +405,7 @@ fn processor(
 
             for j in 0..output_channels {
                 // this handles when there are more output channels than input channels
-                let sample = if j >= channels_usize {
+                let sample = if j >= channels {
                     target_buffer[0][i]
                 } else {
                     target_buffer[j][i]
@@ -527,7 +432,7 @@ fn processor(
                     drop(
                         audio_buffer
                             .condvar
-                            .wait_while(data, |d| d.len() > (10_000 * channels_usize)),
+                            .wait_while(data, |d| d.len() > (10_000 * channels)),
                     );
                 }
 
@@ -549,83 +454,37 @@ fn processor(
 }
 
 /// accepts the bytes of a wav file, returns the bytes of a sea file
-/// encoding is performed in a blocking thread
-async fn wav_to_sea(bytes: &[u8], residual_bits: f32) -> Result<Vec<u8>, Error> {
-    if bytes.len() < 44 {
-        return Err(ErrorKind::InvalidWav.into());
-    }
-
-    let spec = AudioHeader::from(&bytes[0..44]);
-    let channels = spec.channels;
-    let sample_rate = spec.sample_rate;
-
-    let sample_size = match spec.sample_format.as_str() {
-        "u8" => 1,
-        "i16" => 2,
-        "i32" | "f32" => 4,
-        "f64" => 8,
-        _ => 1,
-    };
-
-    let (input_sender, input_receiver) = unbounded();
-    let (output_sender, output_receiver) = unbounded();
-    let input_sender = input_sender.to_async();
-    let output_receiver = output_receiver.to_async();
+/// surround files are mixed down to stereo, encoding is performed in a blocking thread
+async fn wav_to_sea(
+    bytes: &[u8],
+    residual_bits: f32,
+    metadata: SeaMetadata,
+) -> Result<Vec<u8>, Error> {
+    let wav = Wav::parse(bytes)?;
+    let sample_rate = wav.sample_rate;
+    let (samples, channels) = wav.stereo_samples();
+
+    let frames_per_chunk = (FRAME_SIZE / channels) as u16;
 
     spawn_blocking(move || {
         let settings = EncoderSettings {
-            frames_per_chunk: FRAME_SIZE as u16 / channels as u16,
+            frames_per_chunk,
             vbr: true,
             residual_bits,
             ..Default::default()
         };
 
-        let mut encoder = SeaEncoder::new(
+        let mut writer = SeaWriter::new(
+            Cursor::new(Vec::new()),
             channels as u8,
             sample_rate,
             settings,
-            input_receiver,
-            output_sender,
-        )
-        .unwrap();
-        while encoder.encode_frame().is_ok() {}
-        encoder
-    });
-
-    let mut buffer = [0; FRAME_SIZE];
-
-    for chunk in bytes[44..].chunks(FRAME_SIZE * sample_size) {
-        let written = match spec.sample_format.as_str() {
-            "u8" => {
-                for (j, sample) in chunk.iter().enumerate() {
-                    buffer[j] = ((*sample as i16) - 128) << 8;
-                }
-                chunk.len()
-            }
-            "i16" => {
-                for (i, sample_bytes) in chunk.chunks_exact(2).enumerate() {
-                    buffer[i] = i16::from_le_bytes([sample_bytes[0], sample_bytes[1]]);
-                }
-                chunk.len() / 2
-            }
-            _ => break,
-        };
-
-        if written < FRAME_SIZE {
-            buffer[written..].fill(0);
-        }
-
-        input_sender.send(ProcessorMessage::samples(buffer)).await?;
-    }
-
-    drop(input_sender);
-    let mut data: Vec<u8> = Vec::new();
-
-    while let Ok(ProcessorMessage::Data(bytes)) = output_receiver.recv().await {
-        data.extend(bytes.iter());
-    }
-
-    Ok(data)
+            metadata,
+        )?;
+        writer.write_samples(&to_i16(&samples))?;
+        Ok(writer.finish()?.into_inner())
+    })
+    .await?
 }
 
 #[cfg(test)]
@@ -648,7 +507,9 @@ mod tests {
         wav_file.read_to_end(&mut wav_bytes).await.unwrap();
 
         let now = Instant::now();
-        let other_data = wav_to_sea(&wav_bytes, 5.0).await.unwrap();
+        let other_data = wav_to_sea(&wav_bytes, 5.0, Default::default())
+            .await
+            .unwrap();
         info!("wav to sea took {:?}", now.elapsed());
 
         info!("{}%", other_data.len() as f32 / wav_bytes.len() as f32);
@@ -684,7 +545,9 @@ mod tests {
             wav_file.read_to_end(&mut wav_bytes).await.unwrap();
 
             let now = Instant::now();
-            let other_data = wav_to_sea(&wav_bytes, 5.0).await.unwrap();
+            let other_data = wav_to_sea(&wav_bytes, 5.0, Default::default())
+                .await
+                .unwrap();
             info!("wav to sea took {:?}", now.elapsed());
             info!("{}%", other_data.len() as f32 / wav_bytes.len() as f32);
 
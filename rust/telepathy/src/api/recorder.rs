@@ -0,0 +1,302 @@
+use crate::api::error::Error;
+use crate::api::utils::resampler_factory;
+use crate::api::wav::{to_i16, WavWriter};
+use kanal::{bounded, Receiver, Sender};
+use nnnoiseless::FRAME_SIZE;
+use parking_lot::Mutex;
+use rubato::{Resampler, SincFixedIn};
+use sea_codec::encoder::EncoderSettings;
+use sea_codec::io::SeaWriter;
+use sea_codec::SeaMetadata;
+use std::collections::{HashMap, VecDeque};
+use std::fs::File;
+use std::io::BufWriter;
+use std::str::FromStr;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering::Relaxed;
+use std::sync::Arc;
+
+/// the sample rate recordings are written at
+const RECORDING_RATE: u32 = 48_000;
+
+/// a party which stops sending frames is filled with silence once the other is 200ms ahead
+const MAX_SKEW: usize = RECORDING_RATE as usize / 5;
+
+/// the frames waiting for the writer, about five seconds of both parties
+const QUEUE_SIZE: usize = 1_000;
+
+/// the file formats a call can be recorded to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum RecordingFormat {
+    Wav,
+    Sea,
+}
+
+impl FromStr for RecordingFormat {
+    type Err = ();
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "wav" => Ok(Self::Wav),
+            "sea" => Ok(Self::Sea),
+            _ => Err(()),
+        }
+    }
+}
+
+/// a mono frame of one party in -1..1
+enum Tap {
+    Local(Vec<f32>, f64),
+    /// each remote stream of a room is its own source
+    Remote(usize, Vec<f32>, f64),
+}
+
+/// Shares the active recording with the processors, frames are dropped while nothing records
+#[derive(Clone, Default)]
+pub(crate) struct Recording {
+    sender: Arc<Mutex<Option<Sender<Tap>>>>,
+    sources: Arc<AtomicUsize>,
+}
+
+impl Recording {
+    pub(crate) fn is_active(&self) -> bool {
+        self.sender.lock().is_some()
+    }
+
+    /// starts taking frames and returns the work of writing them for a blocking thread,
+    /// it ends once the recording stops
+    pub(crate) fn start(&self, mut writer: RecordingWriter) -> impl FnOnce() -> Result<(), Error> {
+        let (sender, receiver) = bounded(QUEUE_SIZE);
+        *self.sender.lock() = Some(sender);
+
+        move || {
+            record(&receiver, &mut writer)?;
+            writer.finish()
+        }
+    }
+
+    /// closes the queue, the writer finishes the file once it has written the queued frames.
+    /// Returns whether a recording was active
+    pub(crate) fn stop(&self) -> bool {
+        self.sender.lock().take().is_some()
+    }
+
+    /// an identifier for a remote stream
+    pub(crate) fn source(&self) -> usize {
+        self.sources.fetch_add(1, Relaxed)
+    }
+
+    /// records an interleaved frame of the local party at i16 scale, None records silence
+    pub(crate) fn local(&self, frame: Option<&[f32]>, channels: usize, sample_rate: f64) {
+        let Some(sender) = self.sender.lock().clone() else {
+            return;
+        };
+
+        let samples = match frame {
+            Some(frame) => frame
+                .chunks(channels)
+                .map(|frame| frame.iter().sum::<f32>() / channels as f32 / i16::MAX as f32)
+                .collect(),
+            None => vec![0_f32; FRAME_SIZE],
+        };
+
+        _ = sender.try_send(Tap::Local(samples, sample_rate));
+    }
+
+    /// records a decoded frame of a remote party, one buffer per channel, None records silence
+    pub(crate) fn remote(&self, source: usize, buffers: Option<&[Vec<f32>]>, sample_rate: f64) {
+        let Some(sender) = self.sender.lock().clone() else {
+            return;
+        };
+
+        let samples = match buffers {
+            Some(buffers) => (0..FRAME_SIZE)
+                .map(|i| buffers.iter().map(|buffer| buffer[i]).sum::<f32>() / buffers.len() as f32)
+                .collect(),
+            None => vec![0_f32; FRAME_SIZE],
+        };
+
+        _ = sender.try_send(Tap::Remote(source, samples, sample_rate));
+    }
+}
+
+/// the file a recording is written to
+pub(crate) enum RecordingWriter {
+    Wav(WavWriter<BufWriter<File>>),
+    Sea(Box<SeaWriter<BufWriter<File>>>),
+}
+
+impl RecordingWriter {
+    /// creates a stereo file, the local party is on the left and the remote parties on the right
+    pub(crate) fn create(path: &str, format: RecordingFormat) -> Result<Self, Error> {
+        let file = BufWriter::new(File::create(path)?);
+
+        Ok(match format {
+            RecordingFormat::Wav => Self::Wav(WavWriter::new(file, 2, RECORDING_RATE)?),
+            RecordingFormat::Sea => {
+                // a chunk holds as many samples as a frame, like the sound files
+                let settings = EncoderSettings {
+                    frames_per_chunk: (FRAME_SIZE / 2) as u16,
+                    vbr: true,
+                    ..Default::default()
+                };
+
+                Self::Sea(Box::new(SeaWriter::new(
+                    file,
+                    2,
+                    RECORDING_RATE,
+                    settings,
+                    SeaMetadata::default(),
+                )?))
+            }
+        })
+    }
+
+    fn write_samples(&mut self, samples: &[i16]) -> Result<(), Error> {
+        match self {
+            Self::Wav(writer) => writer.write_samples(samples),
+            Self::Sea(writer) => Ok(writer.write_samples(samples)?),
+        }
+    }
+
+    fn finish(self) -> Result<(), Error> {
+        match self {
+            Self::Wav(writer) => writer.finish().map(drop),
+            Self::Sea(writer) => Ok(writer.finish().map(drop)?),
+        }
+    }
+}
+
+/// the samples of one party at the recording rate waiting to be written
+#[derive(Default)]
+struct Party {
+    samples: VecDeque<f32>,
+    /// the rate the party's frames arrive at
+    sample_rate: f64,
+    resampler: Option<SincFixedIn<f32>>,
+}
+
+impl Party {
+    fn push(&mut self, frame: Vec<f32>, sample_rate: f64) -> Result<(), Error> {
+        if sample_rate != self.sample_rate {
+            let ratio = RECORDING_RATE as f64 / sample_rate;
+            self.resampler = resampler_factory(ratio, 1, frame.len())?;
+            self.sample_rate = sample_rate;
+        }
+
+        match self.resampler.as_mut() {
+            Some(resampler) => {
+                let resampled = resampler.process(&[frame], None)?;
+                self.samples.extend(&resampled[0]);
+            }
+            None => self.samples.extend(frame),
+        }
+
+        Ok(())
+    }
+
+    /// the next `len` samples, padded with silence
+    fn take(&mut self, len: usize) -> impl Iterator<Item = f32> + '_ {
+        let available = len.min(self.samples.len());
+        self.samples
+            .drain(..available)
+            .chain(std::iter::repeat_n(0_f32, len - available))
+    }
+}
+
+/// writes the parties side by side as their frames arrive until the recording stops
+fn record(receiver: &Receiver<Tap>, writer: &mut RecordingWriter) -> Result<(), Error> {
+    let mut local = Party::default();
+    let mut remotes: HashMap<usize, Party> = HashMap::new();
+
+    let mut closed = false;
+
+    while !closed {
+        match receiver.recv() {
+            Ok(Tap::Local(frame, sample_rate)) => local.push(frame, sample_rate)?,
+            Ok(Tap::Remote(source, frame, sample_rate)) => remotes
+                .entry(source)
+                .or_default()
+                .push(frame, sample_rate)?,
+            Err(_) => closed = true,
+        }
+
+        let remote_len = remotes.values().map(|r| r.samples.len()).max().unwrap_or(0);
+        let len = writable(local.samples.len(), remote_len, closed);
+        if len == 0 {
+            continue;
+        }
+
+        let mut right = vec![0_f32; len];
+        for remote in remotes.values_mut() {
+            for (mixed, sample) in right.iter_mut().zip(remote.take(len)) {
+                *mixed += sample;
+            }
+        }
+
+        let interleaved: Vec<f32> = local
+            .take(len)
+            .zip(right)
+            .flat_map(|(left, right)| [left, right])
+            .collect();
+
+        writer.write_samples(&to_i16(&interleaved))?;
+    }
+
+    Ok(())
+}
+
+/// the samples which can be written, both parties' once they overlap, a stalled party's once
+/// the other is too far ahead, and everything once the recording stopped
+fn writable(local: usize, remote: usize, closed: bool) -> usize {
+    let ahead = local.max(remote);
+
+    if closed {
+        ahead
+    } else {
+        local.min(remote).max(ahead.saturating_sub(MAX_SKEW))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aligns_parties() {
+        // both parties overlap
+        assert_eq!(writable(480, 960, false), 480);
+        // the remote has not sent anything yet
+        assert_eq!(writable(480, 0, false), 0);
+        assert_eq!(writable(MAX_SKEW + 480, 0, false), 480);
+        // the rest is written when the recording stops
+        assert_eq!(writable(480, 960, true), 960);
+    }
+
+    #[test]
+    fn resamples_parties() {
+        let mut party = Party::default();
+
+        party.push(vec![0.5; 480], RECORDING_RATE as f64).unwrap();
+        assert_eq!(party.samples.len(), 480);
+        assert!(party.resampler.is_none());
+
+        let samples: Vec<f32> = party.take(500).collect();
+        assert_eq!(samples[..480], [0.5; 480]);
+        assert_eq!(samples[480..], [0_f32; 20]);
+
+        // a 44.1kHz party is brought to the recording rate
+        for _ in 0..10 {
+            party.push(vec![0_f32; 441], 44_100_f64).unwrap();
+        }
+        assert!(party.resampler.is_some());
+        assert!(party.samples.len().abs_diff(4_800) < 480);
+    }
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!("wav".parse(), Ok(RecordingFormat::Wav));
+        assert_eq!("sea".parse(), Ok(RecordingFormat::Sea));
+        assert_eq!("mp3".parse::<RecordingFormat>(), Err(()));
+    }
+}
@@ -0,0 +1,160 @@
+use cpal::{SampleRate, SupportedBufferSize, SupportedStreamConfig, SupportedStreamConfigRange};
+use std::sync::atomic::Ordering::Relaxed;
+use std::sync::atomic::{AtomicU16, AtomicU32};
+
+/// The stream configuration the user asks for, zero leaves a value to the device's default
+#[derive(Default)]
+pub(crate) struct StreamSettings {
+    pub(crate) sample_rate: AtomicU32,
+    pub(crate) channels: AtomicU16,
+    /// a fixed buffer size in frames
+    pub(crate) buffer_size: AtomicU32,
+}
+
+/// Chooses the configuration a device is opened with. The requested sample rate and channel count
+/// are used when the device supports them together, then either one alone, otherwise the default.
+/// The requested buffer size is kept within the range the device reports
+pub(crate) fn choose_config(
+    configs: &[SupportedStreamConfigRange],
+    default: SupportedStreamConfig,
+    settings: &StreamSettings,
+) -> (SupportedStreamConfig, Option<u32>) {
+    let default_rate = default.sample_rate().0;
+    let default_channels = default.channels();
+
+    let sample_rate = match settings.sample_rate.load(Relaxed) {
+        0 => default_rate,
+        sample_rate => sample_rate,
+    };
+
+    let channels = match settings.channels.load(Relaxed) {
+        0 => default_channels,
+        channels => channels,
+    };
+
+    let config = [
+        (sample_rate, channels),
+        (sample_rate, default_channels),
+        (default_rate, channels),
+    ]
+    .into_iter()
+    .find_map(|(sample_rate, channels)| supported(configs, &default, sample_rate, channels))
+    .unwrap_or(default);
+
+    let buffer_size = match settings.buffer_size.load(Relaxed) {
+        0 => None,
+        frames => match *config.buffer_size() {
+            SupportedBufferSize::Range { min, max } => Some(frames.max(min).min(max)),
+            SupportedBufferSize::Unknown => Some(frames),
+        },
+    };
+
+    (config, buffer_size)
+}
+
+/// Moves the default config to the sample rate a running call uses when the device supports it,
+/// so the processors can keep their resamplers when the call switches devices
+pub(crate) fn preferred_config(
+    configs: &[SupportedStreamConfigRange],
+    default: SupportedStreamConfig,
+    sample_rate: u32,
+) -> SupportedStreamConfig {
+    supported(configs, &default, sample_rate, default.channels()).unwrap_or(default)
+}
+
+/// a configuration with the sample rate and channel count in the default's sample format
+fn supported(
+    configs: &[SupportedStreamConfigRange],
+    default: &SupportedStreamConfig,
+    sample_rate: u32,
+    channels: u16,
+) -> Option<SupportedStreamConfig> {
+    let sample_rate = SampleRate(sample_rate);
+
+    configs
+        .iter()
+        .find(|config| {
+            config.channels() == channels
+                && config.sample_format() == default.sample_format()
+                && config.min_sample_rate() <= sample_rate
+                && sample_rate <= config.max_sample_rate()
+        })
+        .map(|config| config.with_sample_rate(sample_rate))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cpal::SampleFormat;
+
+    const BUFFER: SupportedBufferSize = SupportedBufferSize::Range { min: 64, max: 4096 };
+
+    fn range(channels: u16, min: u32, max: u32) -> SupportedStreamConfigRange {
+        SupportedStreamConfigRange::new(
+            channels,
+            SampleRate(min),
+            SampleRate(max),
+            BUFFER,
+            SampleFormat::F32,
+        )
+    }
+
+    /// a stereo device at 44.1kHz which also runs at 48kHz and has a mono 16kHz mode
+    fn device() -> (Vec<SupportedStreamConfigRange>, SupportedStreamConfig) {
+        let configs = vec![range(2, 44_100, 48_000), range(1, 16_000, 16_000)];
+        let default = SupportedStreamConfig::new(2, SampleRate(44_100), BUFFER, SampleFormat::F32);
+        (configs, default)
+    }
+
+    fn settings(sample_rate: u32, channels: u16, buffer_size: u32) -> StreamSettings {
+        StreamSettings {
+            sample_rate: AtomicU32::new(sample_rate),
+            channels: AtomicU16::new(channels),
+            buffer_size: AtomicU32::new(buffer_size),
+        }
+    }
+
+    #[test]
+    fn uses_supported_requests() {
+        let (configs, default) = device();
+
+        let (config, buffer_size) = choose_config(&configs, default.clone(), &settings(0, 0, 0));
+        assert_eq!(config, default);
+        assert_eq!(buffer_size, None);
+
+        let (config, buffer_size) = choose_config(&configs, default, &settings(16_000, 1, 256));
+        assert_eq!(config.sample_rate(), SampleRate(16_000));
+        assert_eq!(config.channels(), 1);
+        assert_eq!(buffer_size, Some(256));
+    }
+
+    #[test]
+    fn falls_back_to_defaults() {
+        let (configs, default) = device();
+
+        // mono is not available at 48kHz, the sample rate is kept
+        let (config, _) = choose_config(&configs, default.clone(), &settings(48_000, 1, 0));
+        assert_eq!(config.sample_rate(), SampleRate(48_000));
+        assert_eq!(config.channels(), 2);
+
+        // 96kHz is not available at all, the channel count is kept
+        let (config, _) = choose_config(&configs, default.clone(), &settings(96_000, 2, 0));
+        assert_eq!(config.sample_rate(), SampleRate(44_100));
+        assert_eq!(config.channels(), 2);
+
+        // nothing requested is available
+        let (config, _) = choose_config(&configs, default.clone(), &settings(96_000, 6, 0));
+        assert_eq!(config, default);
+    }
+
+    #[test]
+    fn clamps_buffer_size() {
+        let (configs, default) = device();
+
+        let (_, buffer_size) = choose_config(&configs, default.clone(), &settings(0, 0, 16));
+        assert_eq!(buffer_size, Some(64));
+
+        let (_, buffer_size) = choose_config(&configs, default, &settings(0, 0, 100_000));
+        assert_eq!(buffer_size, Some(4096));
+    }
+}
@@ -5,22 +5,36 @@ use std::mem;
 #[cfg(not(target_family = "wasm"))]
 use std::net::Ipv4Addr;
 pub use std::net::{IpAddr, SocketAddr};
+use std::ops::RangeInclusive;
 use std::str::FromStr;
 use std::sync::atomic::Ordering::Relaxed;
 use std::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize};
 use std::sync::Arc;
 use std::time::Duration;
 
-use crate::api::codec::{decoder, encoder};
+use crate::api::codec::encoder;
 use crate::api::contact::Contact;
+use crate::api::echo::EchoCanceller;
 use crate::api::error::{DartError, Error, ErrorKind};
+use crate::api::frame::{
+    Arrival, FrameHeader, FrameReceiver, FrameStatistics, FrameType, FrameWriter, HEADER_SIZE,
+};
+use crate::api::gain::{AgcSettings, AutomaticGain};
 #[cfg(target_os = "ios")]
 use crate::api::ios::{configure_audio_session, deactivate_audio_session};
+#[cfg(not(target_family = "wasm"))]
+use crate::api::jitter::JitterBuffer;
+use crate::api::jitter::{time_stretch, Playout, Stretch};
+use crate::api::latency::{milliseconds, LatencyStages};
+use crate::api::noise::{ComfortNoise, NoiseEstimator};
 use crate::api::overlay::overlay::Overlay;
-use crate::api::overlay::{CONNECTED, LATENCY, LOSS};
+use crate::api::overlay::{CONNECTED, DELAY, LATENCY, LOSS};
+use crate::api::recorder::{Recording, RecordingFormat, RecordingWriter};
 use crate::api::screenshare;
 use crate::api::screenshare::{Decoder, Encoder};
+use crate::api::stream_config::{choose_config, preferred_config, StreamSettings};
 use crate::api::utils::*;
+use crate::api::vad::{SilenceDetection, VadSettings, VoiceActivity};
 #[cfg(target_family = "wasm")]
 use crate::api::web_audio::{WebAudioWrapper, WebInput};
 use crate::frb_generated::FLUTTER_RUST_BRIDGE_HANDLER;
@@ -28,17 +42,16 @@ use crate::{Behaviour, BehaviourEvent};
 use atomic_float::AtomicF32;
 use chrono::{DateTime, Local};
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
-#[cfg(not(target_family = "wasm"))]
 use cpal::Device;
 pub use cpal::Host;
-use cpal::SupportedStreamConfig;
+use cpal::{BufferSize, BuildStreamError, StreamConfig, StreamError, SupportedStreamConfig};
 use flutter_rust_bridge::for_generated::futures::stream::{SplitSink, SplitStream};
 use flutter_rust_bridge::for_generated::futures::SinkExt;
 use flutter_rust_bridge::{frb, spawn, spawn_blocking_with, DartFnFuture};
-#[cfg(not(target_family = "wasm"))]
-use kanal::bounded;
 pub use kanal::AsyncReceiver;
-use kanal::{bounded_async, unbounded_async, AsyncSender, Receiver, Sender};
+#[cfg(not(target_family = "wasm"))]
+use kanal::ReceiveErrorTimeout;
+use kanal::{bounded, bounded_async, unbounded_async, AsyncSender, Receiver, Sender};
 use libp2p::futures::StreamExt;
 use libp2p::identity::Keypair;
 use libp2p::multiaddr::Protocol;
@@ -48,12 +61,13 @@ use libp2p::tcp;
 use libp2p::{
     autonat, dcutr, identify, noise, ping, yamux, Multiaddr, PeerId, Stream, StreamProtocol,
 };
-use libp2p_stream::Control;
+use libp2p_stream::{Control, OpenStreamError};
 use log::{debug, error, info, warn};
 use messages::{Attachment, AudioHeader, Message};
 use nnnoiseless::{DenoiseState, RnnModel, FRAME_SIZE};
 use rubato::Resampler;
-use sea_codec::ProcessorMessage;
+use sea_codec::decoder::SeaChunkDecoder;
+use sea_codec::{ProcessorMessage, SeaFileHeader};
 use serde::{Deserialize, Serialize};
 use tokio::fs::File;
 use tokio::io::AsyncReadExt;
@@ -79,17 +93,33 @@ pub type Transport<T> = Framed<T, LengthDelimitedCodec>;
 type StartScreenshare = (PeerId, Option<Message>);
 type AudioSocket = SplitSink<Transport<TransportStream>, Bytes>;
 type RoomJoin = (Transport<TransportStream>, EarlyCallState);
+type DevicesChanged = Box<dyn Fn(Vec<DeviceInfo>, Vec<DeviceInfo>) -> DartFnFuture<()> + Send>;
+type RecordingChanged = Box<dyn Fn(String, bool) -> DartFnFuture<()> + Send>;
 
+/// Stereo calls carry two channels
+const MAX_CHANNELS: usize = 2;
 /// The number of bytes in a single network audio frame
-const TRANSFER_BUFFER_SIZE: usize = FRAME_SIZE * size_of::<i16>();
+const TRANSFER_BUFFER_SIZE: usize = HEADER_SIZE + FRAME_SIZE * MAX_CHANNELS * size_of::<i16>();
+/// How often the devices are listed to notice devices appearing or disappearing
+const DEVICE_POLL_INTERVAL: Duration = Duration::from_secs(2);
 /// A timeout used when initializing the call
 const HELLO_TIMEOUT: Duration = Duration::from_secs(10);
 /// A timeout used to detect temporary network issues
 const TIMEOUT_DURATION: Duration = Duration::from_millis(100);
+/// How much audio the output device holds before the output processor waits for it
+#[cfg(not(target_family = "wasm"))]
+const OUTPUT_LOW_WATER: Duration = Duration::from_millis(20);
 /// the number of frames to hold in a channel
 pub(crate) const CHANNEL_SIZE: usize = 2_400;
-/// the protocol identifier for Telepathy
-const CHAT_PROTOCOL: StreamProtocol = StreamProtocol::new("/telepathy/0.0.1");
+/// the protocol identifier for Telepathy, changes whenever the messages or audio frames do
+/// so that peers running an incompatible version can not open a session
+const CHAT_PROTOCOL: StreamProtocol = StreamProtocol::new("/telepathy/0.0.2");
+/// a peer which refuses the protocol this many times in a row runs an incompatible version
+const UNSUPPORTED_PROTOCOL_ATTEMPTS: usize = 5;
+/// frames of an unknown format keep arriving, only one in this many is logged
+const UNKNOWN_FRAME_LOG_INTERVAL: usize = 500;
+/// the sample rates a remote stream may restart at, others would need absurd resampling buffers
+const REMOTE_SAMPLE_RATES: RangeInclusive<u32> = 8_000..=192_000;
 #[cfg(target_family = "wasm")]
 const SILENCE: [f32; FRAME_SIZE] = [0_f32; FRAME_SIZE];
 
@@ -111,6 +141,21 @@ pub struct Telepathy {
     /// Enables rnnoise denoising
     denoise: Arc<AtomicBool>,
 
+    /// Enables cancelling the echo of the output from the input
+    echo_cancellation: Arc<AtomicBool>,
+
+    /// Controls the automatic gain control of the input
+    agc: Arc<AgcSettings>,
+
+    /// Controls how silence is detected in the input
+    vad: Arc<VadSettings>,
+
+    /// Enables filling silence with comfort noise
+    comfort_noise: Arc<AtomicBool>,
+
+    /// Asks for stereo calls when both devices have two channels
+    stereo: Arc<AtomicBool>,
+
     /// The rnnoise model
     denoise_model: Arc<RwLock<RnnModel>>,
 
@@ -123,6 +168,24 @@ pub struct Telepathy {
     /// Manually set the output device
     output_device: DeviceName,
 
+    /// Moves the input of a running call to a newly set device
+    input_changed: Arc<Notify>,
+
+    /// Moves the output of a running call to a newly set device
+    output_changed: Arc<Notify>,
+
+    /// The sample rate, channel count and buffer size the devices are opened with
+    stream_settings: Arc<StreamSettings>,
+
+    /// The configuration the last input stream opened with
+    opened_input: Arc<parking_lot::Mutex<Option<AudioConfig>>>,
+
+    /// The configuration the last output stream opened with
+    opened_output: Arc<parking_lot::Mutex<Option<AudioConfig>>>,
+
+    /// Records the current call or room, shared with the processors
+    recording: Recording,
+
     /// Private key for signing the handshake
     identity: Arc<RwLock<Keypair>>,
 
@@ -202,6 +265,12 @@ pub struct Telepathy {
     /// Used to report statistics to the frontend
     statistics: Arc<Mutex<dyn Fn(Statistics) -> DartFnFuture<()> + Send>>,
 
+    /// Alerts the UI when devices appear or disappear
+    devices_changed: Arc<Mutex<Option<DevicesChanged>>>,
+
+    /// Alerts the UI when another party starts or stops recording
+    recording_changed: Arc<Mutex<Option<RecordingChanged>>>,
+
     /// Used to send chat messages to the frontend
     message_received: Arc<Mutex<dyn Fn(ChatMessage) -> DartFnFuture<()> + Send>>,
 
@@ -244,10 +313,21 @@ impl Telepathy {
             input_volume: Default::default(),
             output_volume: Default::default(),
             denoise: Default::default(),
+            echo_cancellation: Default::default(),
+            agc: Default::default(),
+            vad: Default::default(),
+            comfort_noise: Default::default(),
+            stereo: Default::default(),
             denoise_model: Default::default(),
             end_call: Default::default(),
             input_device: Default::default(),
             output_device: Default::default(),
+            input_changed: Default::default(),
+            output_changed: Default::default(),
+            stream_settings: Default::default(),
+            opened_input: Default::default(),
+            opened_output: Default::default(),
+            recording: Default::default(),
             identity: Arc::new(RwLock::new(
                 Keypair::from_protobuf_encoding(&identity).unwrap(),
             )),
@@ -278,6 +358,8 @@ impl Telepathy {
             session_status: Arc::new(Mutex::new(session_status)),
             start_sessions: Arc::new(Mutex::new(start_sessions)),
             statistics: Arc::new(Mutex::new(statistics)),
+            devices_changed: Default::default(),
+            recording_changed: Default::default(),
             message_received: Arc::new(Mutex::new(message_received)),
             manager_active: Arc::new(Mutex::new(manager_active)),
             screenshare_started: Arc::new(Mutex::new(screenshare_started)),
@@ -308,6 +390,13 @@ impl Telepathy {
             }
         });
 
+        // watch for devices appearing or disappearing
+        #[cfg(not(target_family = "wasm"))]
+        {
+            let chat_clone = chat.clone();
+            spawn(async move { chat_clone.device_watcher().await });
+        }
+
         // start the sessions
         (chat.start_sessions.lock().await)(chat.clone()).await;
 
@@ -391,6 +480,7 @@ impl Telepathy {
             }
 
             stop_io.notify_waiters();
+            self_clone.recording.stop();
         });
 
         Ok(())
@@ -445,6 +535,7 @@ impl Telepathy {
 
         stop_io.notify_waiters();
         self.in_call.store(false, Relaxed);
+        self.recording.stop();
 
         result
     }
@@ -495,6 +586,58 @@ impl Telepathy {
         Ok(())
     }
 
+    /// Records the current call or room to a "wav" or "sea" file at `path`. The local party is
+    /// on the left channel and the other parties on the right, who are told about the recording
+    pub async fn start_recording(
+        &self,
+        path: String,
+        format: String,
+    ) -> std::result::Result<(), DartError> {
+        let format = RecordingFormat::from_str(&format)
+            .map_err(|_| "Invalid recording format".to_string())?;
+
+        if !self.in_call.load(Relaxed) && self.in_room.read().await.is_none() {
+            return Err("Not in a call".to_string().into());
+        } else if self.recording.is_active() {
+            return Err("Already recording".to_string().into());
+        }
+
+        let writer = RecordingWriter::create(&path, format)?;
+        let record = self.recording.start(writer);
+
+        spawn_blocking_with(
+            move || {
+                if let Err(error) = record() {
+                    error!("Error writing recording: {}", error);
+                }
+            },
+            FLUTTER_RUST_BRIDGE_HANDLER.thread_pool(),
+        );
+
+        self.notify_recording(true).await;
+        Ok(())
+    }
+
+    /// Stops the recording, the file is finished in the background
+    pub async fn stop_recording(&self) {
+        if self.recording.stop() {
+            self.notify_recording(false).await;
+        }
+    }
+
+    #[frb(sync)]
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_active()
+    }
+
+    /// Sets the callback which receives the peer id of another party and whether it is recording
+    pub async fn set_recording_changed(
+        &self,
+        recording_changed: impl Fn(String, bool) -> DartFnFuture<()> + Send + 'static,
+    ) {
+        *self.recording_changed.lock().await = Some(Box::new(recording_changed));
+    }
+
     pub async fn start_screenshare(&self, contact: &Contact) -> std::result::Result<(), DartError> {
         self.start_screenshare
             .send((contact.peer_id, None))
@@ -537,6 +680,101 @@ impl Telepathy {
         self.denoise.store(denoise, Relaxed);
     }
 
+    /// Changing the echo cancellation flag will not affect the current call
+    #[frb(sync)]
+    pub fn set_echo_cancellation(&self, echo_cancellation: bool) {
+        self.echo_cancellation.store(echo_cancellation, Relaxed);
+    }
+
+    #[frb(sync)]
+    pub fn set_agc(&self, enabled: bool) {
+        self.agc.enabled.store(enabled, Relaxed);
+    }
+
+    /// The level the automatic gain control brings speech to in dBFS
+    #[frb(sync)]
+    pub fn set_agc_target_level(&self, decibel: f32) {
+        let level = db_to_multiplier(decibel);
+        self.agc.target_level.store(level, Relaxed);
+    }
+
+    /// The most the automatic gain control amplifies quiet input by in decibels
+    #[frb(sync)]
+    pub fn set_agc_max_gain(&self, decibel: f32) {
+        let multiplier = db_to_multiplier(decibel);
+        self.agc.max_gain.store(multiplier, Relaxed);
+    }
+
+    /// How quickly the gain is lowered when the input gets louder
+    #[frb(sync)]
+    pub fn set_agc_attack(&self, milliseconds: f32) {
+        self.agc.attack.store(milliseconds / 1_000_f32, Relaxed);
+    }
+
+    /// How quickly the gain is raised when the input gets quieter
+    #[frb(sync)]
+    pub fn set_agc_release(&self, milliseconds: f32) {
+        self.agc.release.store(milliseconds / 1_000_f32, Relaxed);
+    }
+
+    /// Selects which input frames are sent: "rms", "vad" or "always"
+    #[frb(sync)]
+    pub fn set_silence_detection(&self, mode: String) -> std::result::Result<(), DartError> {
+        let mode = SilenceDetection::from_str(&mode)
+            .map_err(|_| "Invalid silence detection mode".to_string())?;
+        self.vad.mode.store(mode as u8, Relaxed);
+        Ok(())
+    }
+
+    /// The voice probability between 0 and 1 above which a frame is speech
+    #[frb(sync)]
+    pub fn set_vad_threshold(&self, probability: f32) {
+        self.vad.threshold.store(probability, Relaxed);
+    }
+
+    /// How long frames keep being sent after speech
+    #[frb(sync)]
+    pub fn set_vad_hangover(&self, milliseconds: u32) {
+        self.vad.hangover.store(milliseconds, Relaxed);
+    }
+
+    /// Changing the comfort noise flag will not affect the current call
+    #[frb(sync)]
+    pub fn set_comfort_noise(&self, comfort_noise: bool) {
+        self.comfort_noise.store(comfort_noise, Relaxed);
+    }
+
+    /// Changing the stereo flag will not affect the current call
+    #[frb(sync)]
+    pub fn set_stereo(&self, stereo: bool) {
+        self.stereo.store(stereo, Relaxed);
+    }
+
+    /// The sample rate devices are opened with when they support it, None uses the default
+    #[frb(sync)]
+    pub fn set_preferred_sample_rate(&self, sample_rate: Option<u32>) {
+        self.stream_settings
+            .sample_rate
+            .store(sample_rate.unwrap_or_default(), Relaxed);
+    }
+
+    /// The channel count devices are opened with when they support it, None uses the default
+    #[frb(sync)]
+    pub fn set_preferred_channels(&self, channels: Option<u16>) {
+        self.stream_settings
+            .channels
+            .store(channels.unwrap_or_default(), Relaxed);
+    }
+
+    /// A fixed buffer size in frames, lower sizes reduce latency at the risk of underruns.
+    /// None lets the host choose
+    #[frb(sync)]
+    pub fn set_buffer_size(&self, frames: Option<u32>) {
+        self.stream_settings
+            .buffer_size
+            .store(frames.unwrap_or_default(), Relaxed);
+    }
+
     #[frb(sync)]
     pub fn set_play_custom_ringtones(&self, play: bool) {
         self.play_custom_ringtones.store(play, Relaxed);
@@ -552,12 +790,16 @@ impl Telepathy {
         self.efficiency_mode.store(enabled, Relaxed);
     }
 
+    /// Selects a device by its identifier or name, a running call switches to the new device
     pub async fn set_input_device(&self, device: Option<String>) {
         *self.input_device.lock().await = device;
+        self.input_changed.notify_one();
     }
 
+    /// Selects a device by its identifier or name, a running call switches to the new device
     pub async fn set_output_device(&self, device: Option<String>) {
         *self.output_device.lock().await = device;
+        self.output_changed.notify_one();
     }
 
     /// Lists the input and output devices
@@ -576,6 +818,30 @@ impl Telepathy {
         Ok((input_devices, output_devices))
     }
 
+    /// Lists the input and output devices with their identifiers and supported configurations
+    pub fn list_device_info(
+        &self,
+    ) -> std::result::Result<(Vec<DeviceInfo>, Vec<DeviceInfo>), DartError> {
+        Ok(self.describe_devices()?)
+    }
+
+    /// The configurations the last input and output streams were opened with
+    #[frb(sync)]
+    pub fn opened_audio_configs(&self) -> (Option<AudioConfig>, Option<AudioConfig>) {
+        (
+            self.opened_input.lock().clone(),
+            self.opened_output.lock().clone(),
+        )
+    }
+
+    /// Sets the callback which receives the devices when devices appear or disappear
+    pub async fn set_devices_changed(
+        &self,
+        devices_changed: impl Fn(Vec<DeviceInfo>, Vec<DeviceInfo>) -> DartFnFuture<()> + Send + 'static,
+    ) {
+        *self.devices_changed.lock().await = Some(Box::new(devices_changed));
+    }
+
     pub async fn set_model(&self, model: Option<Vec<u8>>) -> std::result::Result<(), DartError> {
         let model = if let Some(mode_bytes) = model {
             RnnModel::from_bytes(&mode_bytes).ok_or(String::from("invalid model"))?
@@ -1125,6 +1391,8 @@ impl Telepathy {
         peer_states: &mut HashMap<PeerId, PeerState>,
     ) {
         // it may take multiple tries to open the stream because the of the (dumb) RNG in the stream handler
+        let mut unsupported = 0;
+
         loop {
             match control.open_stream(peer_id, CHAT_PROTOCOL).await {
                 Ok(stream) => {
@@ -1138,7 +1406,26 @@ impl Telepathy {
                     peer_states.remove(&peer_id);
                     break;
                 }
+                Err(OpenStreamError::UnsupportedProtocol(_))
+                    if unsupported + 1 >= UNSUPPORTED_PROTOCOL_ATTEMPTS =>
+                {
+                    error!(
+                        "{} does not support {}, it runs an incompatible version",
+                        peer_id, CHAT_PROTOCOL
+                    );
+
+                    peer_states.remove(&peer_id);
+                    (self.session_status.lock().await)(peer_id.to_string(), "Inactive".to_string())
+                        .await;
+                    break;
+                }
                 Err(error) => {
+                    if matches!(error, OpenStreamError::UnsupportedProtocol(_)) {
+                        unsupported += 1;
+                    } else {
+                        unsupported = 0;
+                    }
+
                     error!("error opening stream {}: {}", peer_id, error);
                 }
             }
@@ -1270,6 +1557,9 @@ impl Telepathy {
     ) -> Result<()> {
         info!("[{}] session waiting for event", contact.nickname);
 
+        // room members have no call controller to send their messages
+        let in_room = self.is_in_room(&contact.peer_id).await;
+
         select! {
             result = read_message::<Message, _>(transport) => {
                 let mut other_ringtone = None;
@@ -1290,6 +1580,11 @@ impl Telepathy {
                         warn!("session for {} ending expected Hello", contact.nickname);
                         return Ok::<(), Error>(());
                     },
+                    // room members send their control messages between calls
+                    Message::Recording { active } => {
+                        self.recording_changed(&contact.peer_id, active).await;
+                        return Ok::<(), Error>(());
+                    },
                     message => {
                         warn!("received unexpected {:?} from {}", message, contact.nickname);
                         return Ok::<(), Error>(());
@@ -1429,6 +1724,10 @@ impl Telepathy {
 
                 Ok(())
             }
+            Ok(message) = message_channel.1.recv(), if in_room => {
+                write_message(transport, &message).await?;
+                Ok(())
+            },
             // state will never notify while a call is active
             _ = state.stop_session.notified() => {
                 info!("session state stop notified for {}", contact.nickname);
@@ -1480,6 +1779,8 @@ impl Telepathy {
 
         // the call has ended
         self.in_call.store(false, Relaxed);
+        // finish the recording of the call
+        self.recording.stop();
         // hide the overlay
         self.overlay.hide();
 
@@ -1535,28 +1836,78 @@ impl Telepathy {
         let download_bandwidth = state
             .map(|state| Arc::clone(&state.download_bandwidth))
             .unwrap_or_default();
+        let jitter_depth: Arc<AtomicUsize> = Default::default();
+        let frame_statistics: Arc<FrameStatistics> = Default::default();
+        let input_gain: Arc<AtomicF32> = Default::default();
+        let speaking: Arc<AtomicBool> = Default::default();
+        let latency_stages: Arc<LatencyStages> = Default::default();
 
         // the two clients agree on these codec options
-        let codec_config = call_state.codec_config();
+        let codec_enabled = call_state.codec_enabled();
+        // local codec changes during the call stay within the remote's options
+        let remote_codec_options = (
+            call_state.remote_configuration.vbr,
+            call_state.remote_configuration.residual_bits as f32,
+        );
+        let fec = call_state.fec_enabled();
+        let comfort_noise = call_state.comfort_noise_enabled();
+        let channels = call_state.channels();
 
-        let (input_receiver, input_sender) = self
-            .setup_input(
-                call_state.local_configuration.sample_rate as f64,
-                codec_config,
-                input_rms_sender,
-            )
-            .await?;
+        // the echo canceller runs at the rate the input is processed at
+        let echo_sample_rate = self
+            .echo_cancellation
+            .load(Relaxed)
+            .then_some(call_state.local_configuration.sample_rate as f64);
+
+        // streams which lose their device move to another one, on web the call ends
+        #[cfg(not(target_family = "wasm"))]
+        let (input_lost, output_lost): (Arc<Notify>, Arc<Notify>) = Default::default();
+        #[cfg(target_family = "wasm")]
+        let (input_lost, output_lost) = (Arc::clone(&self.end_call), Arc::clone(&self.end_call));
 
-        let (output_sender, output_stream) = self
+        #[allow(unused_mut)]
+        let (output_sender, mut output_stream, playback, echo_canceller) = self
             .setup_output(
                 call_state.remote_configuration.sample_rate as f64,
-                codec_config.0,
+                channels,
+                codec_enabled,
+                fec,
+                comfort_noise,
                 output_rms_sender,
+                echo_sample_rate,
+                #[cfg(not(target_family = "wasm"))]
+                Arc::clone(&jitter_depth),
+                #[cfg(not(target_family = "wasm"))]
+                Arc::clone(&frame_statistics),
+                output_lost,
+                Arc::clone(&latency_stages),
+            )
+            .await?;
+
+        let (input_receiver, capture) = self
+            .setup_input(
+                call_state.local_configuration.sample_rate as f64,
+                channels,
+                codec_enabled,
+                remote_codec_options,
+                fec,
+                comfort_noise,
+                input_rms_sender,
+                echo_canceller,
+                Arc::clone(&input_gain),
+                Arc::clone(&speaking),
+                input_lost,
+                Arc::clone(&latency_stages),
             )
             .await?;
 
         #[cfg(not(target_family = "wasm"))]
-        let input_stream = self.setup_input_stream(&call_state, input_sender)?;
+        let mut input_stream = self.build_input_stream(
+            &call_state.input_device,
+            &call_state.input_config,
+            call_state.input_buffer_size,
+            &capture,
+        )?;
 
         // play the output stream
         output_stream.stream.play()?;
@@ -1577,6 +1928,11 @@ impl Telepathy {
             latency,
             Arc::clone(&upload_bandwidth),
             Arc::clone(&download_bandwidth),
+            jitter_depth,
+            Arc::clone(&frame_statistics),
+            input_gain,
+            speaking,
+            latency_stages,
             Arc::clone(&self.statistics),
             Arc::clone(stop_io),
         ));
@@ -1600,6 +1956,8 @@ impl Telepathy {
                     read,
                     Arc::clone(stop_io),
                     download_bandwidth,
+                    Arc::clone(&frame_statistics),
+                    fec,
                     Some(receiving_sender),
                 ));
 
@@ -1607,12 +1965,28 @@ impl Telepathy {
                     transport,
                     message_receiver,
                     receiving_receiver,
+                    frame_statistics,
                     call_state.peer,
                 );
 
                 info!("call controller starting");
 
-                match controller_future.await {
+                // the devices may change or be lost while the call runs
+                #[cfg(not(target_family = "wasm"))]
+                let result = select! {
+                    result = controller_future => result,
+                    Err(error) = self.device_manager(
+                        &mut input_stream,
+                        &capture,
+                        &mut output_stream,
+                        &playback,
+                        call_state.input_device.id.clone(),
+                    ) => Err(error),
+                };
+                #[cfg(target_family = "wasm")]
+                let result = controller_future.await;
+
+                match result {
                     Ok(message) => {
                         (self.call_ended.lock().await)(message.unwrap_or_default(), true).await
                     }
@@ -1627,6 +2001,19 @@ impl Telepathy {
             }
             _ => {
                 spawn(loopback(input_receiver, output_sender, Arc::clone(stop_io)));
+
+                #[cfg(not(target_family = "wasm"))]
+                select! {
+                    _ = self.end_call.notified() => (),
+                    Err(error) = self.device_manager(
+                        &mut input_stream,
+                        &capture,
+                        &mut output_stream,
+                        &playback,
+                        call_state.input_device.id.clone(),
+                    ) => error!("audio device error: {}", error),
+                }
+                #[cfg(target_family = "wasm")]
                 self.end_call.notified().await;
             }
         }
@@ -1650,6 +2037,7 @@ impl Telepathy {
         transport: &mut Transport<TransportStream>,
         receiver: AsyncReceiver<Message>,
         receiving: AsyncReceiver<bool>,
+        frame_statistics: Arc<FrameStatistics>,
         peer: PeerId,
     ) -> Result<Option<String>> {
         let identity = self.identity.read().await.public().to_peer_id();
@@ -1668,10 +2056,10 @@ impl Telepathy {
         // the instant the session stopped receiving audio
         let mut disconnected_at = Instant::now();
 
-        // the instant the disconnect started and the duration of the disconnect
-        let mut disconnect_durations: VecDeque<(Instant, Duration)> = VecDeque::new();
-        // ticks to update the connection quality and remove old entries from `disconnect_durations`
-        let mut update_durations = interval(Duration::from_secs(1));
+        // the received and lost frame counters at each of the last ticks
+        let mut loss_window: VecDeque<(usize, usize)> = VecDeque::new();
+        // ticks to update the connection quality and move the window of `loss_window`
+        let mut update_loss = interval(Duration::from_secs(1));
 
         // constant durations used in the connection quality algorithm
         let window_duration = Duration::from_secs(10);
@@ -1720,6 +2108,7 @@ impl Telepathy {
                             info!("received screenshare header {:?}", message);
                             self.start_screenshare.send((peer, Some(message))).await?;
                         }
+                        Message::Recording { active } => self.recording_changed(&peer, active).await,
                         _ => error!("call controller unexpected message: {:?}", message),
                     }
                 },
@@ -1744,16 +2133,12 @@ impl Telepathy {
                         // notify the ui in 2 seconds if the disconnect hasn't ended
                         notify_ui = disconnected_at + disconnect_duration;
                     } else if is_receiving && remote_is_receiving {
-                        let elapsed = disconnected_at.elapsed();
-
                         // update the overlay to connected
                         if !CONNECTED.swap(true, Relaxed) {
                             // update the call state in the UI
                             (self.call_state.lock().await)(false).await;
                         }
 
-                        // record the disconnect
-                        disconnect_durations.push_back((disconnected_at, elapsed));
                         // prevents any notification to the ui as audio is being received
                         notify_ui = Instant::now() + Duration::from_secs(86400 * 365 * 30);
                     }
@@ -1787,26 +2172,35 @@ impl Telepathy {
                     // set the overlay to disconnected
                     CONNECTED.store(false, Relaxed);
                 },
-                _ = update_durations.tick() => {
-                    let now = Instant::now();
+                _ = update_loss.tick() => {
+                    let counters = (
+                        frame_statistics.received.load(Relaxed),
+                        frame_statistics.lost.load(Relaxed),
+                    );
 
-                    // check for disconnects outside the 10-second window
-                    while let Some((start, _)) = disconnect_durations.front() {
-                        if now - *start > window_duration {
-                            disconnect_durations.pop_front();
-                        } else {
-                            break;
-                        }
+                    // the frames received and lost within the 10-second window
+                    loss_window.push_back(counters);
+                    if loss_window.len() > window_duration.as_secs() as usize {
+                        loss_window.pop_front();
                     }
+                    let (received, lost) = loss_window
+                        .front()
+                        .map(|first| (counters.0 - first.0, counters.1 - first.1))
+                        .unwrap_or_default();
 
-                    let mut total_disconnect = disconnect_durations.iter().fold(Duration::default(), |acc, (_, duration)| acc + *duration).as_millis();
+                    let mut loss = if received + lost > 0 {
+                        lost as f64 / (received + lost) as f64
+                    } else {
+                        0_f64
+                    };
 
-                    // if not receiving, add the current disconnect duration
+                    // frames lost to an ongoing disconnect are only counted once the next frame arrives
                     if !is_receiving || !remote_is_receiving {
-                        total_disconnect += disconnected_at.elapsed().as_millis();
+                        let disconnect = disconnected_at.elapsed().as_secs_f64() / window_duration.as_secs_f64();
+                        loss = loss.max(disconnect.min(1_f64));
                     }
 
-                    LOSS.store(total_disconnect as f64 / 10_000_f64, Relaxed);
+                    LOSS.store(loss, Relaxed);
                 }
             }
         }
@@ -1842,16 +2236,30 @@ impl Telepathy {
         let download_bandwidth: Arc<AtomicUsize> = Default::default();
         let mut output_streams = Vec::new();
 
-        let (input_receiver, input_sender) = self
+        let (input_receiver, capture) = self
             .setup_input(
                 call_state.local_configuration.sample_rate as f64,
-                (true, true, 5_f32),
+                1,
+                true,
+                (true, 5_f32),
+                false,
+                false,
                 None,
+                None,
+                Default::default(),
+                Default::default(),
+                Arc::clone(&self.end_call),
+                Default::default(),
             )
             .await?;
 
         #[cfg(not(target_family = "wasm"))]
-        let input_stream = self.setup_input_stream(&call_state, input_sender)?;
+        let input_stream = self.build_input_stream(
+            &call_state.input_device,
+            &call_state.input_config,
+            call_state.input_buffer_size,
+            &capture,
+        )?;
 
         // play the input stream (non web)
         #[cfg(not(target_family = "wasm"))]
@@ -1876,9 +2284,24 @@ impl Telepathy {
 
             let (write, read) = transport.split();
             socket_sender.send(write).await?; // TODO write always needs a SEA header sent to it i think
+            let frame_statistics: Arc<FrameStatistics> = Default::default();
 
-            let (output_sender, output_stream) = self
-                .setup_output(state.remote_configuration.sample_rate as f64, true, None)
+            let (output_sender, output_stream, _, _) = self
+                .setup_output(
+                    state.remote_configuration.sample_rate as f64,
+                    1,
+                    true,
+                    false,
+                    false,
+                    None,
+                    None,
+                    #[cfg(not(target_family = "wasm"))]
+                    Default::default(),
+                    #[cfg(not(target_family = "wasm"))]
+                    Arc::clone(&frame_statistics),
+                    Arc::clone(&self.end_call),
+                    Default::default(),
+                )
                 .await?;
 
             output_stream.stream.play()?;
@@ -1889,6 +2312,8 @@ impl Telepathy {
                 read,
                 Arc::clone(stop_io),
                 download_bandwidth.clone(),
+                frame_statistics,
+                false,
                 None,
             ));
         }
@@ -1897,15 +2322,25 @@ impl Telepathy {
     }
 
     /// helper method to set up audio input stack between the network and device layers
+    #[allow(clippy::too_many_arguments)]
     async fn setup_input(
         &self,
         sample_rate: f64,
-        codec_options: (bool, bool, f32),
+        channels: usize,
+        codec_enabled: bool,
+        remote_codec_options: (bool, f32),
+        fec: bool,
+        comfort_noise: bool,
         input_rms_sender: Option<Sender<f32>>,
-    ) -> Result<(AsyncReceiver<ProcessorMessage>, Sender<f32>)> {
+        echo_canceller: Option<EchoCanceller>,
+        input_gain: Arc<AtomicF32>,
+        speaking: Arc<AtomicBool>,
+        lost: Arc<Notify>,
+        latency: Arc<LatencyStages>,
+    ) -> Result<(AsyncReceiver<ProcessorMessage>, InputCapture)> {
         // input stream -> input processor
         #[cfg(not(target_family = "wasm"))]
-        let (input_sender, input_receiver) = bounded::<f32>(CHANNEL_SIZE);
+        let (input_sender, input_receiver) = bounded::<f32>(CHANNEL_SIZE * channels);
 
         #[cfg(target_family = "wasm")]
         let input_receiver = {
@@ -1923,7 +2358,6 @@ impl Telepathy {
         // encoder -> sending socket
         let (encoded_input_sender, encoded_input_receiver) = unbounded_async::<ProcessorMessage>();
 
-        let (codec_enabled, vbr, residual_bits) = codec_options;
         let denoise = self.denoise.load(Relaxed);
         // get a reference to input volume for the processor
         let input_volume = Arc::clone(&self.input_volume);
@@ -1933,10 +2367,32 @@ impl Telepathy {
         let muted = Arc::clone(&self.muted);
         // get a sync version of the processed input sender
         let processed_input_sender = processed_input_sender.to_sync();
-        // the rnnoise denoiser
-        let denoiser = denoise.then_some(DenoiseState::from_model(
-            self.denoise_model.read().await.clone(),
-        ));
+        // an rnnoise denoiser for each channel
+        let denoisers = if denoise {
+            let model = self.denoise_model.read().await.clone();
+            Some(
+                (0..channels)
+                    .map(|_| DenoiseState::from_model(model.clone()))
+                    .collect(),
+            )
+        } else {
+            None
+        };
+        // the input is processed at 48kHz when denoising
+        let processing_rate = if denoise { 48_000_f64 } else { sample_rate };
+        // interleaved channels pass through the gain control like a higher sample rate
+        let agc = AutomaticGain::new(
+            Arc::clone(&self.agc),
+            input_gain,
+            processing_rate * channels as f64,
+        );
+        let vad = VoiceActivity::new(Arc::clone(&self.vad), speaking, FRAME_SIZE, processing_rate);
+        // the input processor follows the sample rate of the input device
+        let capture_rate = Arc::new(AtomicU32::new(sample_rate as u32));
+        let processor_capture_rate = Arc::clone(&capture_rate);
+        let processor_latency = Arc::clone(&latency);
+        // the processed input is the local party of a recording
+        let recording = self.recording.clone();
 
         // spawn the input processor thread
         spawn_blocking_with(
@@ -1944,13 +2400,20 @@ impl Telepathy {
                 input_processor(
                     input_receiver,
                     processed_input_sender,
-                    sample_rate,
+                    processor_capture_rate,
+                    channels,
                     input_volume,
                     rms_threshold,
                     muted,
-                    denoiser,
+                    denoisers,
+                    echo_canceller,
+                    agc,
+                    vad,
+                    comfort_noise.then(|| NoiseEstimator::new(channels)),
                     input_rms_sender,
                     codec_enabled,
+                    processor_latency,
+                    recording,
                 )
             },
             FLUTTER_RUST_BRIDGE_HANDLER.thread_pool(),
@@ -1960,15 +2423,20 @@ impl Telepathy {
         if codec_enabled {
             let encoder_receiver = processed_input_receiver.clone_sync();
             let encoder_sender = encoded_input_sender.clone_sync();
+            // the encoder follows changes to the codec config for the rest of the call
+            let codec_config = self.codec_config.clone();
 
             spawn_blocking_with(
                 move || {
                     encoder(
                         encoder_receiver,
                         encoder_sender,
+                        channels as u8,
+                        FRAME_SIZE as u16,
                         if denoise { 48_000 } else { sample_rate as u32 },
-                        vbr,
-                        residual_bits,
+                        codec_config,
+                        remote_codec_options,
+                        fec,
                     );
                 },
                 FLUTTER_RUST_BRIDGE_HANDLER.thread_pool(),
@@ -1981,28 +2449,44 @@ impl Telepathy {
             } else {
                 processed_input_receiver
             },
-            input_sender,
+            InputCapture {
+                sender: input_sender,
+                channels,
+                sample_rate: capture_rate,
+                lost,
+                latency,
+            },
         ))
     }
 
     /// helper method to set up audio output stack above network layer
+    #[allow(clippy::too_many_arguments)]
     async fn setup_output(
         &self,
         remote_sample_rate: f64,
+        channels: usize,
         codec_enabled: bool,
+        fec: bool,
+        comfort_noise: bool,
         output_rms_sender: Option<Sender<f32>>,
-    ) -> Result<(AsyncSender<ProcessorMessage>, SendStream)> {
-        // receiving socket -> output processor or decoder
+        echo_sample_rate: Option<f64>,
+        #[cfg(not(target_family = "wasm"))] jitter_depth: Arc<AtomicUsize>,
+        #[cfg(not(target_family = "wasm"))] frame_statistics: Arc<FrameStatistics>,
+        lost: Arc<Notify>,
+        latency: Arc<LatencyStages>,
+    ) -> Result<(
+        AsyncSender<ProcessorMessage>,
+        SendStream,
+        OutputPlayback,
+        Option<EchoCanceller>,
+    )> {
+        // receiving socket -> output processor
         let (network_output_sender, network_output_receiver) =
             bounded_async::<ProcessorMessage>(CHANNEL_SIZE / FRAME_SIZE);
 
-        // decoder -> output processor
-        let (decoded_output_sender, decoded_output_receiver) =
-            unbounded_async::<ProcessorMessage>();
-
         // output processor -> output stream
         #[cfg(not(target_family = "wasm"))]
-        let (output_sender, output_receiver) = bounded::<f32>(CHANNEL_SIZE);
+        let (output_sender, output_receiver) = bounded::<f32>(CHANNEL_SIZE * channels);
 
         // output processor -> output stream
         #[cfg(target_family = "wasm")]
@@ -2010,21 +2494,44 @@ impl Telepathy {
         #[cfg(target_family = "wasm")]
         let web_output = output_sender.clone();
 
-        // get the output device and its default configuration
+        // get the output device and the configuration the user asked for
         let output_device = get_output_device(&self.output_device, &self.host).await?;
-        let output_config = output_device.default_output_config()?;
-        info!("output device: {:?}", output_device.name());
+        let configs: Vec<_> = output_device
+            .device
+            .supported_output_configs()
+            .into_iter()
+            .flatten()
+            .collect();
+        let (output_config, buffer_size) = choose_config(
+            &configs,
+            output_device.device.default_output_config()?,
+            &self.stream_settings,
+        );
+
+        let output_sample_rate = output_config.sample_rate().0 as f64;
+        // the output processor follows the sample rate of the output device
+        let playback_rate = Arc::new(AtomicU32::new(output_config.sample_rate().0));
+        let processor_playback_rate = Arc::clone(&playback_rate);
+        let processor_latency = Arc::clone(&latency);
+        // the decoded output is a remote party of a recording
+        let recording = self.recording.clone();
+        let source = recording.source();
+
+        // the played samples are the reference for cancelling their echo from the input
+        let (echo_sender, echo_canceller) = match echo_sample_rate {
+            Some(sample_rate) => {
+                let (sender, receiver) = bounded::<f32>(CHANNEL_SIZE * 4);
+                let ratio = sample_rate / output_sample_rate;
+                let canceller = EchoCanceller::new(receiver, ratio, FRAME_SIZE, channels)?;
+                (Some(sender), Some(canceller))
+            }
+            None => (None, None),
+        };
 
-        // the ratio of the output sample rate to the remote input sample rate
-        let ratio = output_config.sample_rate().0 as f64 / remote_sample_rate;
         // get a reference to output volume for the processor
         let output_volume = Arc::clone(&self.output_volume);
         // do this outside the output processor thread
-        let output_processor_receiver = if codec_enabled {
-            decoded_output_receiver.to_sync()
-        } else {
-            network_output_receiver.clone_sync()
-        };
+        let output_processor_receiver = network_output_receiver.to_sync();
 
         // spawn the output processor thread
         spawn_blocking_with(
@@ -2032,38 +2539,99 @@ impl Telepathy {
                 output_processor(
                     output_processor_receiver,
                     output_sender,
-                    ratio,
+                    remote_sample_rate,
+                    processor_playback_rate,
+                    channels,
                     output_volume,
                     output_rms_sender,
+                    codec_enabled,
+                    fec,
+                    comfort_noise.then(ComfortNoise::new),
+                    #[cfg(not(target_family = "wasm"))]
+                    jitter_depth,
+                    #[cfg(not(target_family = "wasm"))]
+                    frame_statistics,
+                    processor_latency,
+                    recording,
+                    source,
                 )
             },
             FLUTTER_RUST_BRIDGE_HANDLER.thread_pool(),
         );
 
-        if codec_enabled {
-            let decoder_receiver = network_output_receiver.to_sync();
-            let decoder_sender = decoded_output_sender.clone_sync();
+        let playback = OutputPlayback {
+            #[cfg(not(target_family = "wasm"))]
+            receiver: output_receiver,
+            #[cfg(target_family = "wasm")]
+            web_output,
+            channels,
+            sample_rate: playback_rate,
+            echo: echo_sender.map(|sender| (sender, output_config.sample_rate().0)),
+            lost,
+            latency,
+        };
 
-            spawn_blocking_with(
-                move || {
-                    decoder(decoder_receiver, decoder_sender);
-                },
-                FLUTTER_RUST_BRIDGE_HANDLER.thread_pool(),
-            );
-        }
+        let output_stream =
+            self.build_output_stream(&output_device, &output_config, buffer_size, &playback)?;
+
+        Ok((
+            network_output_sender,
+            output_stream,
+            playback,
+            echo_canceller,
+        ))
+    }
+
+    /// Helper method to build the output stream on a device
+    fn build_output_stream(
+        &self,
+        device: &IdentifiedDevice,
+        config: &SupportedStreamConfig,
+        buffer_size: Option<u32>,
+        playback: &OutputPlayback,
+    ) -> Result<SendStream> {
+        info!("output device: {}", device.id);
 
         // get the output channels for chunking the output
-        let output_channels = output_config.channels() as usize;
-        // a reference to the flag for use in the output callback
-        let deafened = Arc::clone(&self.deafened);
-        let end_call = Arc::clone(&self.end_call);
-
-        let output_stream = SendStream {
-            stream: output_device.build_output_stream(
-                &output_config.into(),
+        let output_channels = config.channels() as usize;
+        let channels = playback.channels;
+        // the echo canceller resamples the reference from the sample rate it was built for
+        let echo_sender = playback
+            .echo
+            .as_ref()
+            .filter(|(_, sample_rate)| *sample_rate == config.sample_rate().0)
+            .map(|(sender, _)| sender.clone());
+
+        let build = |stream_config: &StreamConfig| {
+            #[cfg(not(target_family = "wasm"))]
+            let output_receiver = playback.receiver.clone();
+            #[cfg(target_family = "wasm")]
+            let web_output = Arc::clone(&playback.web_output);
+            let echo_sender = echo_sender.clone();
+            // a reference to the flag for use in the output callback
+            let deafened = Arc::clone(&self.deafened);
+            let end_call = Arc::clone(&self.end_call);
+            let lost = Arc::clone(&playback.lost);
+            let latency = Arc::clone(&playback.latency);
+            let sample_rate = stream_config.sample_rate.0 as f64;
+
+            device.device.build_output_stream(
+                stream_config,
                 move |output: &mut [f32], _: &_| {
+                    let frames = output.len() / output_channels;
+                    latency
+                        .output_buffer
+                        .store(milliseconds(frames, sample_rate), Relaxed);
+
                     if deafened.load(Relaxed) {
                         output.fill(0_f32);
+
+                        if let Some(echo_sender) = &echo_sender {
+                            for _ in 0..output.len() / output_channels {
+                                _ = echo_sender.try_send(0_f32);
+                            }
+                        }
+
                         return;
                     }
 
@@ -2078,6 +2646,27 @@ impl Telepathy {
                     let mut samples = data.drain(..(output.len() / output_channels).min(data_len));
 
                     for frame in output.chunks_mut(output_channels) {
+                        // a stereo call plays on the first two channels of the device
+                        #[cfg(not(target_family = "wasm"))]
+                        if channels == 2 {
+                            let left = output_receiver.recv().unwrap_or(0_f32);
+                            let right = output_receiver.recv().unwrap_or(0_f32);
+
+                            if let [first, second, rest @ ..] = frame {
+                                (*first, *second) = (left, right);
+                                rest.fill(0_f32);
+                            } else {
+                                frame.fill((left + right) / 2_f32);
+                            }
+
+                            // both speakers reach every microphone, the reference is their mix
+                            if let Some(echo_sender) = &echo_sender {
+                                _ = echo_sender.try_send((left + right) / 2_f32);
+                            }
+
+                            continue;
+                        }
+
                         #[cfg(not(target_family = "wasm"))]
                         let sample = output_receiver.recv().unwrap_or(0_f32);
                         #[cfg(target_family = "wasm")]
@@ -2087,72 +2676,304 @@ impl Telepathy {
                         for channel in frame.iter_mut() {
                             *channel = sample;
                         }
+
+                        if let Some(echo_sender) = &echo_sender {
+                            _ = echo_sender.try_send(sample);
+                        }
                     }
                 },
                 move |err| {
                     error!("Error in output stream: {}", err);
-                    end_call.notify_one();
+
+                    if let StreamError::DeviceNotAvailable = err {
+                        lost.notify_one();
+                    } else {
+                        end_call.notify_one();
+                    }
                 },
                 None,
-            )?,
+            )
         };
 
-        Ok((network_output_sender, output_stream))
+        self.open_audio_stream(device, config, buffer_size, &self.opened_output, build)
     }
 
-    /// Helper method to set up non-web audio input stream
+    /// Helper method to build the non-web input stream on a device
     #[cfg(not(target_family = "wasm"))]
-    fn setup_input_stream(
+    fn build_input_stream(
         &self,
-        call_state: &EarlyCallState,
-        input_sender: Sender<f32>,
+        device: &IdentifiedDevice,
+        config: &SupportedStreamConfig,
+        buffer_size: Option<u32>,
+        capture: &InputCapture,
     ) -> Result<SendStream> {
-        let input_channels = call_state.local_configuration.channels as usize;
-        let end_call = Arc::clone(&self.end_call);
+        info!("input device: {}", device.id);
+
+        let input_channels = config.channels() as usize;
+        // stereo calls take the first two channels of the device, other calls the first
+        let channels = capture.channels;
+
+        let build = |stream_config: &StreamConfig| {
+            let input_sender = capture.sender.clone();
+            let end_call = Arc::clone(&self.end_call);
+            let lost = Arc::clone(&capture.lost);
+            let latency = Arc::clone(&capture.latency);
+            let sample_rate = stream_config.sample_rate.0 as f64;
+
+            device.device.build_input_stream(
+                stream_config,
+                move |input: &[f32], _: &_| {
+                    let frames = input.len() / input_channels;
+                    latency
+                        .input_buffer
+                        .store(milliseconds(frames, sample_rate), Relaxed);
 
-        Ok(SendStream {
-            stream: call_state.input_device.build_input_stream(
-                &call_state.input_config.clone().into(),
-                move |input, _: &_| {
                     for frame in input.chunks(input_channels) {
-                        _ = input_sender.try_send(frame[0]);
+                        // a mono device fills every channel of a stereo call
+                        for channel in 0..channels {
+                            _ = input_sender.try_send(frame[channel.min(input_channels - 1)]);
+                        }
                     }
                 },
                 move |err| {
                     error!("Error in input stream: {}", err);
-                    end_call.notify_one();
+
+                    if let StreamError::DeviceNotAvailable = err {
+                        lost.notify_one();
+                    } else {
+                        end_call.notify_one();
+                    }
                 },
                 None,
-            )?,
-        })
+            )
+        };
+
+        self.open_audio_stream(device, config, buffer_size, &self.opened_input, build)
     }
 
-    /// helper method to set up EarlyCallState
-    async fn setup_call(&self, peer: PeerId) -> Result<EarlyCallState> {
-        // if there is an early room state, use it w/ the real peer id
-        if let Some(mut state) = self.early_room_state.read().await.clone() {
-            state.peer = peer;
-            return Ok(state);
-        }
+    /// Helper method to open a stream with a fixed buffer size, falling back to the host's buffer
+    /// size when the device refuses it. The configuration the stream opened with is kept for the UI
+    fn open_audio_stream(
+        &self,
+        device: &IdentifiedDevice,
+        config: &SupportedStreamConfig,
+        buffer_size: Option<u32>,
+        opened: &parking_lot::Mutex<Option<AudioConfig>>,
+        build: impl Fn(&StreamConfig) -> std::result::Result<cpal::Stream, BuildStreamError>,
+    ) -> Result<SendStream> {
+        let mut stream_config = config.config();
 
-        #[cfg(not(target_family = "wasm"))]
-        let input_device;
-        #[cfg(not(target_family = "wasm"))]
-        let input_config;
+        let stream = match buffer_size {
+            Some(frames) => {
+                stream_config.buffer_size = BufferSize::Fixed(frames);
 
-        let input_sample_rate;
-        let input_sample_format;
-        let input_channels;
+                build(&stream_config).or_else(|error| {
+                    warn!(
+                        "{} refused a buffer of {} frames: {}",
+                        device.id, frames, error
+                    );
+                    stream_config.buffer_size = BufferSize::Default;
+                    build(&stream_config)
+                })?
+            }
+            None => build(&stream_config)?,
+        };
 
-        #[cfg(not(target_family = "wasm"))]
+        *opened.lock() = Some(AudioConfig {
+            device: device.id.clone(),
+            sample_rate: stream_config.sample_rate.0,
+            channels: stream_config.channels,
+            buffer_size: match stream_config.buffer_size {
+                BufferSize::Fixed(frames) => Some(frames),
+                BufferSize::Default => None,
+            },
+        });
+
+        Ok(SendStream { stream })
+    }
+
+    /// Keeps the audio of a call on the selected devices. When a device is changed or lost its
+    /// stream is rebuilt while the processors, codec and transport keep running
+    #[cfg(not(target_family = "wasm"))]
+    async fn device_manager(
+        &self,
+        input_stream: &mut SendStream,
+        capture: &InputCapture,
+        output_stream: &mut SendStream,
+        playback: &OutputPlayback,
+        mut input_id: String,
+    ) -> Result<()> {
+        let mut output_id = get_output_device(&self.output_device, &self.host).await?.id;
+
+        loop {
+            select! {
+                _ = self.input_changed.notified() => {
+                    // selecting the device in use changes nothing
+                    if self.get_input_device().await?.id != input_id {
+                        input_id = self.switch_input(input_stream, capture).await?;
+                    }
+                }
+                _ = capture.lost.notified() => {
+                    warn!("input device {} was lost", input_id);
+                    input_id = self.switch_input(input_stream, capture).await?;
+                }
+                _ = self.output_changed.notified() => {
+                    if get_output_device(&self.output_device, &self.host).await?.id != output_id {
+                        output_id = self.switch_output(output_stream, playback).await?;
+                    }
+                }
+                _ = playback.lost.notified() => {
+                    warn!("output device {} was lost", output_id);
+                    output_id = self.switch_output(output_stream, playback).await?;
+                }
+            }
+        }
+    }
+
+    /// Moves the input stream to the selected device, or the default device if the selected
+    /// device cannot be opened. Returns the identifier of the new device
+    #[cfg(not(target_family = "wasm"))]
+    async fn switch_input(
+        &self,
+        stream: &mut SendStream,
+        capture: &InputCapture,
+    ) -> Result<String> {
+        // the old stream lets go of its device before a device is opened
+        _ = stream.stream.pause();
+
+        let open = |device: &IdentifiedDevice| -> Result<(SendStream, u32)> {
+            let configs: Vec<_> = device
+                .device
+                .supported_input_configs()
+                .into_iter()
+                .flatten()
+                .collect();
+            let default = device.device.default_input_config()?;
+            let (chosen, buffer_size) = choose_config(&configs, default, &self.stream_settings);
+            let config = preferred_config(&configs, chosen, capture.sample_rate.load(Relaxed));
+
+            let stream = self.build_input_stream(device, &config, buffer_size, capture)?;
+            stream.stream.play()?;
+            Ok((stream, config.sample_rate().0))
+        };
+
+        let mut device = self.get_input_device().await?;
+
+        let (new_stream, sample_rate) = match open(&device) {
+            Ok(opened) => opened,
+            Err(error) => {
+                warn!("failed to open input device {}: {}", device.id, error);
+                device = select_device(
+                    identify_devices(self.host.input_devices()?),
+                    None,
+                    self.host.default_input_device(),
+                )
+                .ok_or(ErrorKind::NoInputDevice)?;
+                open(&device)?
+            }
+        };
+
+        capture.sample_rate.store(sample_rate, Relaxed);
+        *stream = new_stream;
+        Ok(device.id)
+    }
+
+    /// Moves the output stream to the selected device, or the default device if the selected
+    /// device cannot be opened. Returns the identifier of the new device
+    #[cfg(not(target_family = "wasm"))]
+    async fn switch_output(
+        &self,
+        stream: &mut SendStream,
+        playback: &OutputPlayback,
+    ) -> Result<String> {
+        // the old stream lets go of its device before a device is opened
+        _ = stream.stream.pause();
+
+        let open = |device: &IdentifiedDevice| -> Result<(SendStream, u32)> {
+            let configs: Vec<_> = device
+                .device
+                .supported_output_configs()
+                .into_iter()
+                .flatten()
+                .collect();
+            let default = device.device.default_output_config()?;
+            let (chosen, buffer_size) = choose_config(&configs, default, &self.stream_settings);
+            let config = preferred_config(&configs, chosen, playback.sample_rate.load(Relaxed));
+
+            let stream = self.build_output_stream(device, &config, buffer_size, playback)?;
+            stream.stream.play()?;
+            Ok((stream, config.sample_rate().0))
+        };
+
+        let mut device = get_output_device(&self.output_device, &self.host).await?;
+
+        let (new_stream, sample_rate) = match open(&device) {
+            Ok(opened) => opened,
+            Err(error) => {
+                warn!("failed to open output device {}: {}", device.id, error);
+                device = select_device(
+                    identify_devices(self.host.output_devices()?),
+                    None,
+                    self.host.default_output_device(),
+                )
+                .ok_or(ErrorKind::NoOutputDevice)?;
+                open(&device)?
+            }
+        };
+
+        playback.sample_rate.store(sample_rate, Relaxed);
+        *stream = new_stream;
+        Ok(device.id)
+    }
+
+    /// helper method to set up EarlyCallState
+    async fn setup_call(&self, peer: PeerId) -> Result<EarlyCallState> {
+        // if there is an early room state, use it w/ the real peer id
+        if let Some(mut state) = self.early_room_state.read().await.clone() {
+            state.peer = peer;
+            return Ok(state);
+        }
+
+        #[cfg(not(target_family = "wasm"))]
+        let input_device;
+        #[cfg(not(target_family = "wasm"))]
+        let input_config;
+        #[cfg(not(target_family = "wasm"))]
+        let input_buffer_size;
+
+        let input_sample_rate;
+        let input_sample_format;
+        let input_channels;
+        let stereo;
+
+        #[cfg(not(target_family = "wasm"))]
         {
-            // get the input device and its default configuration
+            // get the input device and the configuration the user asked for
             input_device = self.get_input_device().await?;
-            input_config = input_device.default_input_config()?;
-            info!("input_device: {:?}", input_device.name());
+            let configs: Vec<_> = input_device
+                .device
+                .supported_input_configs()
+                .into_iter()
+                .flatten()
+                .collect();
+            (input_config, input_buffer_size) = choose_config(
+                &configs,
+                input_device.device.default_input_config()?,
+                &self.stream_settings,
+            );
+            info!("input_device: {}", input_device.id);
             input_sample_rate = input_config.sample_rate().0;
             input_sample_format = input_config.sample_format().to_string();
             input_channels = input_config.channels() as usize;
+
+            // stereo needs two channels to capture and two to play back
+            stereo = self.stereo.load(Relaxed)
+                && input_channels >= 2
+                && get_output_device(&self.output_device, &self.host)
+                    .await
+                    .ok()
+                    .and_then(|device| device.device.default_output_config().ok())
+                    .is_some_and(|config| config.channels() >= 2);
         }
 
         #[cfg(target_family = "wasm")]
@@ -2165,12 +2986,14 @@ impl Telepathy {
 
             input_sample_format = String::from("f32");
             input_channels = 1; // only ever 1 channel on web
+            stereo = false;
         }
 
         // load the shared codec config values
         let config_codec_enabled = self.codec_config.enabled.load(Relaxed);
         let config_vbr = self.codec_config.vbr.load(Relaxed);
         let config_residual_bits = self.codec_config.residual_bits.load(Relaxed);
+        let config_fec = self.codec_config.fec.load(Relaxed);
 
         let mut local_configuration = AudioHeader {
             channels: input_channels as u32,
@@ -2179,6 +3002,9 @@ impl Telepathy {
             codec_enabled: config_codec_enabled,
             vbr: config_vbr,
             residual_bits: config_residual_bits as f64,
+            fec: config_fec,
+            comfort_noise: self.comfort_noise.load(Relaxed),
+            stereo,
         };
 
         // rnnoise requires a 48kHz sample rate
@@ -2193,36 +3019,109 @@ impl Telepathy {
             #[cfg(not(target_family = "wasm"))]
             input_config,
             #[cfg(not(target_family = "wasm"))]
+            input_buffer_size,
+            #[cfg(not(target_family = "wasm"))]
             input_device,
         })
     }
 
-    /// helper method to get the user specified device or default as fallback
+    /// helper method to describe the input and output devices
+    fn describe_devices(&self) -> Result<(Vec<DeviceInfo>, Vec<DeviceInfo>)> {
+        let inputs = identify_devices(self.host.input_devices()?);
+        let default = default_position(&inputs, self.host.default_input_device().as_ref());
+
+        let inputs = inputs
+            .iter()
+            .enumerate()
+            .map(|(position, device)| {
+                let configs = device
+                    .device
+                    .supported_input_configs()
+                    .into_iter()
+                    .flatten();
+                device_info(device, Some(position) == default, configs)
+            })
+            .collect();
+
+        let outputs = identify_devices(self.host.output_devices()?);
+        let default = default_position(&outputs, self.host.default_output_device().as_ref());
+
+        let outputs = outputs
+            .iter()
+            .enumerate()
+            .map(|(position, device)| {
+                let configs = device
+                    .device
+                    .supported_output_configs()
+                    .into_iter()
+                    .flatten();
+                device_info(device, Some(position) == default, configs)
+            })
+            .collect();
+
+        Ok((inputs, outputs))
+    }
+
+    /// Lists the devices while the frontend listens for changes, cpal has no hotplug events
     #[cfg(not(target_family = "wasm"))]
-    async fn get_input_device(&self) -> Result<Device> {
-        match *self.input_device.lock().await {
-            Some(ref name) => Ok(self
-                .host
-                .input_devices()?
-                .find(|device| {
-                    if let Ok(ref device_name) = device.name() {
-                        name == device_name
-                    } else {
-                        false
-                    }
-                })
-                .unwrap_or(
-                    self.host
-                        .default_input_device()
-                        .ok_or(ErrorKind::NoInputDevice)?,
-                )),
-            None => self
-                .host
-                .default_input_device()
-                .ok_or(ErrorKind::NoInputDevice.into()),
+    async fn device_watcher(&self) {
+        let mut interval = interval(DEVICE_POLL_INTERVAL);
+        // the identifiers of the input and output devices and which of them are the defaults
+        let mut known = None;
+
+        loop {
+            interval.tick().await;
+
+            let devices_changed = self.devices_changed.lock().await;
+            let Some(devices_changed) = devices_changed.as_ref() else {
+                continue;
+            };
+
+            // listing identifiers is cheaper than describing every device
+            let identifiers = |devices: Vec<IdentifiedDevice>, default: Option<Device>| {
+                let default = default_position(&devices, default.as_ref());
+                let ids: Vec<String> = devices.into_iter().map(|device| device.id).collect();
+                (ids, default)
+            };
+
+            let (Ok(inputs), Ok(outputs)) = (self.host.input_devices(), self.host.output_devices())
+            else {
+                continue;
+            };
+
+            let current = Some((
+                identifiers(identify_devices(inputs), self.host.default_input_device()),
+                identifiers(identify_devices(outputs), self.host.default_output_device()),
+            ));
+
+            // the frontend lists the devices itself when it starts listening
+            if known.is_none() || known == current {
+                known = current;
+                continue;
+            }
+
+            known = current;
+
+            match self.describe_devices() {
+                Ok((inputs, outputs)) => devices_changed(inputs, outputs).await,
+                Err(error) => warn!("failed to describe devices: {}", error),
+            }
         }
     }
 
+    /// helper method to get the user specified device or default as fallback
+    #[cfg(not(target_family = "wasm"))]
+    async fn get_input_device(&self) -> Result<IdentifiedDevice> {
+        let selection = self.input_device.lock().await.clone();
+
+        select_device(
+            identify_devices(self.host.input_devices()?),
+            selection.as_deref(),
+            self.host.default_input_device(),
+        )
+        .ok_or(ErrorKind::NoInputDevice.into())
+    }
+
     /// helper method for building channels that can be disabled by efficiency mode
     fn efficiency_channel(&self) -> (Option<Sender<f32>>, Option<AsyncReceiver<f32>>) {
         if self.efficiency_mode.load(Relaxed) {
@@ -2263,6 +3162,34 @@ impl Telepathy {
             .map(|m| m.contains(peer_id))
             .unwrap_or(false)
     }
+
+    /// tells the parties of the call or room whether the user is recording
+    async fn notify_recording(&self, active: bool) {
+        for (peer, state) in self.session_states.read().await.iter() {
+            if state.in_call.load(Relaxed) || self.is_in_room(peer).await {
+                if let Err(error) = state
+                    .message_sender
+                    .send(Message::Recording { active })
+                    .await
+                {
+                    error!("Error sending recording message to {}: {}", peer, error);
+                }
+            }
+        }
+    }
+
+    /// alerts the UI that another party started or stopped recording
+    async fn recording_changed(&self, peer: &PeerId, active: bool) {
+        info!(
+            "{} {} recording",
+            peer,
+            if active { "started" } else { "stopped" }
+        );
+
+        if let Some(callback) = self.recording_changed.lock().await.as_ref() {
+            callback(peer.to_string(), active).await;
+        }
+    }
 }
 
 /// state used early in the call before it starts
@@ -2273,21 +3200,72 @@ struct EarlyCallState {
     remote_configuration: AudioHeader,
     #[cfg(not(target_family = "wasm"))]
     input_config: SupportedStreamConfig,
+    /// the fixed buffer size the input device is opened with
+    #[cfg(not(target_family = "wasm"))]
+    input_buffer_size: Option<u32>,
     #[cfg(not(target_family = "wasm"))]
-    input_device: Device,
+    input_device: IdentifiedDevice,
 }
 
 impl EarlyCallState {
-    fn codec_config(&self) -> (bool, bool, f32) {
-        let codec_enabled =
-            self.remote_configuration.codec_enabled || self.local_configuration.codec_enabled;
-        let vbr = self.remote_configuration.vbr || self.local_configuration.vbr;
-        let residual_bits = (self.remote_configuration.residual_bits as f32)
-            .min(self.local_configuration.residual_bits as f32);
-        (codec_enabled, vbr, residual_bits)
+    /// the codec is used when either client asks for it, the encoder works out vbr and residual bits
+    fn codec_enabled(&self) -> bool {
+        self.remote_configuration.codec_enabled || self.local_configuration.codec_enabled
+    }
+
+    /// both clients must understand the packet layout, so forward error correction needs both to ask for it
+    fn fec_enabled(&self) -> bool {
+        self.codec_enabled() && self.remote_configuration.fec && self.local_configuration.fec
+    }
+
+    /// descriptors are only sent to clients which fill silence with them
+    fn comfort_noise_enabled(&self) -> bool {
+        self.remote_configuration.comfort_noise && self.local_configuration.comfort_noise
+    }
+
+    /// the call is stereo when both clients ask for it, otherwise both sides send mono
+    fn channels(&self) -> usize {
+        if self.remote_configuration.stereo && self.local_configuration.stereo {
+            2
+        } else {
+            1
+        }
     }
 }
 
+/// the device end of the input, kept to move the input to another device during a call
+struct InputCapture {
+    /// sends the captured samples to the input processor
+    sender: Sender<f32>,
+    /// the number of channels the call carries
+    channels: usize,
+    /// the sample rate of the captured samples, the input processor follows changes
+    sample_rate: Arc<AtomicU32>,
+    /// notified by the stream when its device is lost
+    lost: Arc<Notify>,
+    /// the stream reports its device buffer here
+    latency: Arc<LatencyStages>,
+}
+
+/// the device end of the output, kept to move the output to another device during a call
+struct OutputPlayback {
+    /// receives the samples from the output processor
+    #[cfg(not(target_family = "wasm"))]
+    receiver: Receiver<f32>,
+    #[cfg(target_family = "wasm")]
+    web_output: Arc<wasm_sync::Mutex<Vec<f32>>>,
+    /// the number of channels the call carries
+    channels: usize,
+    /// the sample rate of the output device, the output processor follows changes
+    sample_rate: Arc<AtomicU32>,
+    /// the echo canceller's reference and the output sample rate it resamples from
+    echo: Option<(Sender<f32>, u32)>,
+    /// notified by the stream when its device is lost
+    lost: Arc<Notify>,
+    /// the stream reports its device buffer here
+    latency: Arc<LatencyStages>,
+}
+
 /// a state used for session negotiation
 #[derive(Debug)]
 struct PeerState {
@@ -2454,6 +3432,73 @@ pub struct Statistics {
 
     /// a value between 0 and 1 representing the percent of audio lost in a sliding window
     pub loss: f64,
+
+    /// the audio waiting in the jitter buffer in milliseconds
+    pub jitter_buffer: usize,
+
+    /// the variation of the one-way network delay in milliseconds
+    pub jitter: f64,
+
+    /// the gain applied by the automatic gain control in decibels
+    pub gain: f32,
+
+    /// whether the user is speaking, including the hangover after speech
+    pub speaking: bool,
+
+    /// the estimated delay of the input device and the captured samples waiting in milliseconds
+    pub capture_delay: usize,
+
+    /// the estimated delay of framing, resampling and coding the audio in milliseconds
+    pub processing_delay: usize,
+
+    /// half of the round trip time in milliseconds
+    pub network_delay: usize,
+
+    /// the estimated delay of the samples waiting for the output device and its buffer in milliseconds
+    pub playout_delay: usize,
+
+    /// the estimated mouth to ear delay of the call in milliseconds
+    pub one_way_delay: usize,
+}
+
+/// an audio device for the frontend
+pub struct DeviceInfo {
+    /// selects the device, unlike the name it is unique. Devices sharing a name are told apart
+    /// by a fingerprint of the configurations they support rather than by the order the host
+    /// lists them in
+    pub id: String,
+
+    /// the name shown to the user
+    pub name: String,
+
+    /// whether the host uses this device by default
+    pub is_default: bool,
+
+    /// the sample rates the device supports
+    pub sample_rates: Vec<u32>,
+
+    /// the channel counts the device supports
+    pub channels: Vec<u16>,
+
+    /// the smallest buffer size in frames, when the host reports one
+    pub min_buffer_size: Option<u32>,
+
+    /// the largest buffer size in frames, when the host reports one
+    pub max_buffer_size: Option<u32>,
+}
+
+/// the configuration a device was opened with
+#[derive(Clone)]
+pub struct AudioConfig {
+    /// the identifier of the device
+    pub device: String,
+
+    pub sample_rate: u32,
+
+    pub channels: u16,
+
+    /// the fixed buffer size in frames, None when the host chose it
+    pub buffer_size: Option<u32>,
 }
 
 #[frb(opaque)]
@@ -2717,6 +3762,9 @@ pub struct CodecConfig {
 
     /// the compression level
     residual_bits: Arc<AtomicF32>,
+
+    /// whether to send and accept redundant copies of the previous frame, applies to the next call
+    fec: Arc<AtomicBool>,
 }
 
 impl CodecConfig {
@@ -2726,6 +3774,7 @@ impl CodecConfig {
             enabled: Arc::new(AtomicBool::new(enabled)),
             vbr: Arc::new(AtomicBool::new(vbr)),
             residual_bits: Arc::new(AtomicF32::new(residual_bits)),
+            fec: Default::default(),
         }
     }
 
@@ -2744,6 +3793,16 @@ impl CodecConfig {
         self.residual_bits.store(residual_bits, Relaxed);
     }
 
+    #[frb(sync)]
+    pub fn set_fec(&self, fec: bool) {
+        self.fec.store(fec, Relaxed);
+    }
+
+    #[frb(sync)]
+    pub fn fec(&self) -> bool {
+        self.fec.load(Relaxed)
+    }
+
     #[frb(sync)]
     pub fn to_values(&self) -> (bool, bool, f32) {
         (
@@ -2817,8 +3876,8 @@ async fn audio_input(
     stop_io: Arc<Notify>,
     bandwidth: Arc<AtomicUsize>,
 ) -> Result<()> {
-    // a static byte used as the silence signal
-    let silence_byte = Bytes::from_static(&[0]);
+    // stamps every frame with a sequence number and timestamp
+    let mut frame_writer = FrameWriter::new();
     let mut sockets: Vec<AudioSocket> = Vec::new();
 
     let future = async {
@@ -2829,12 +3888,23 @@ async fn audio_input(
                 }
                 Ok(message) = input_receiver.recv() => {
                     let bytes = match message {
-                        ProcessorMessage::Silence => silence_byte.clone(),
-                        ProcessorMessage::Data(bytes) => bytes,
+                        ProcessorMessage::Silence => frame_writer.write(FrameType::Silence, &[]),
+                        ProcessorMessage::ComfortNoise(descriptor) => {
+                            frame_writer.write(FrameType::Silence, &descriptor)
+                        }
+                        ProcessorMessage::Data(bytes) => frame_writer.write(FrameType::Audio, &bytes),
                         ProcessorMessage::Samples(_) => {
                             warn!("audio input received Samples");
                             continue;
                         },
+                        ProcessorMessage::Restart(_) => {
+                            warn!("audio input received Restart");
+                            continue;
+                        },
+                        ProcessorMessage::Lost => {
+                            warn!("audio input received Lost");
+                            continue;
+                        },
                     };
 
                     // send the bytes to all connections
@@ -2860,15 +3930,36 @@ async fn audio_input(
     }
 }
 
+/// The number of lost frames to conceal ahead of a frame which arrived after `lost` frames went
+/// missing. With forward error correction an audio frame repairs the frame right before it
+/// from its redundant copy, concealing that frame first would leave nothing to repair.
+fn concealed_frames(lost: u32, fec: bool, frame_type: FrameType, payload: &[u8]) -> u32 {
+    let repaired =
+        fec && frame_type == FrameType::Audio && SeaChunkDecoder::carries_redundant(payload);
+
+    lost.saturating_sub(repaired as u32)
+}
+
+/// Whether the output can play a remote stream with `header`, its channels are interleaved
+/// into the output's channels and its sample rate is resampled to the output's
+fn supported_stream(header: &SeaFileHeader, channels: usize) -> bool {
+    header.channels as usize == channels && REMOTE_SAMPLE_RATES.contains(&header.sample_rate)
+}
+
 /// Receives audio data from the socket and sends it to the output processor
 async fn audio_output(
     sender: AsyncSender<ProcessorMessage>,
     mut socket: SplitStream<Transport<TransportStream>>,
     stop_io: Arc<Notify>,
     bandwidth: Arc<AtomicUsize>,
+    frame_statistics: Arc<FrameStatistics>,
+    fec: bool,
     receiving: Option<AsyncSender<bool>>,
 ) -> Result<()> {
     let mut is_receiving = false;
+    // detects lost, late and duplicated frames
+    let mut frame_receiver = FrameReceiver::new(frame_statistics);
+    let mut unknown_frames = 0;
 
     let future = async {
         loop {
@@ -2881,16 +3972,40 @@ async fn audio_output(
                         }
                     }
 
-                    let len = message.len();
-                    bandwidth.fetch_add(len, Relaxed);
+                    bandwidth.fetch_add(message.len(), Relaxed);
 
-                    match len {
-                        1 => match message[0] {
-                            0 => _ = sender.try_send(ProcessorMessage::silence())?, // silence
-                            _ => error!("received unknown control signal {}", message[0]),
-                        },
-                        _ => {
-                            sender.try_send(ProcessorMessage::bytes(message.freeze()))?;
+                    let Some((header, payload)) = FrameHeader::parse(message.freeze()) else {
+                        if unknown_frames % UNKNOWN_FRAME_LOG_INTERVAL == 0 {
+                            error!(
+                                "received an audio frame of an unknown format ({} so far)",
+                                unknown_frames + 1
+                            );
+                        }
+
+                        unknown_frames += 1;
+                        continue;
+                    };
+
+                    let Arrival::InOrder { lost } = frame_receiver.receive(&header) else {
+                        debug!("dropped late audio frame {}", header.sequence);
+                        continue;
+                    };
+
+                    // the output conceals the lost frames in their place
+                    for _ in 0..concealed_frames(lost, fec, header.frame_type, &payload) {
+                        sender.try_send(ProcessorMessage::Lost)?;
+                    }
+
+                    match header.frame_type {
+                        FrameType::Silence if payload.is_empty() => {
+                            _ = sender.try_send(ProcessorMessage::silence())?
+                        }
+                        // a silence frame may describe the sender's background noise
+                        FrameType::Silence => {
+                            _ = sender.try_send(ProcessorMessage::ComfortNoise(payload))?
+                        }
+                        FrameType::Audio => {
+                            _ = sender.try_send(ProcessorMessage::bytes(payload))?
                         }
                     }
                 }
@@ -2945,12 +4060,18 @@ async fn loopback(
 }
 
 /// Collects statistics from throughout the application, processes them, and provides them to the frontend
+#[allow(clippy::too_many_arguments)]
 async fn statistics_collector(
     input_receiver: Option<AsyncReceiver<f32>>,
     output_receiver: Option<AsyncReceiver<f32>>,
     latency: Arc<AtomicUsize>,
     upload_bandwidth: Arc<AtomicUsize>,
     download_bandwidth: Arc<AtomicUsize>,
+    jitter_depth: Arc<AtomicUsize>,
+    frame_statistics: Arc<FrameStatistics>,
+    input_gain: Arc<AtomicF32>,
+    speaking: Arc<AtomicBool>,
+    latency_stages: Arc<LatencyStages>,
     callback: Arc<Mutex<dyn Fn(Statistics) -> DartFnFuture<()> + Send>>,
     notify: Arc<Notify>,
 ) -> Result<()> {
@@ -2974,6 +4095,10 @@ async fn statistics_collector(
     while !stop.load(Relaxed) {
         select! {
             _ = update_interval.tick() => {
+                let latency = latency.load(Relaxed);
+                let jitter_buffer = jitter_depth.load(Relaxed);
+                let stages = latency_stages.breakdown(latency, jitter_buffer);
+
                 let statistics = Statistics {
                     input_level: if let Some(r) = input_receiver.as_ref() {
                         level_from_window(r, &mut input_max).await
@@ -2985,13 +4110,23 @@ async fn statistics_collector(
                     } else {
                         0_f32
                     },
-                    latency: latency.load(Relaxed),
+                    latency,
                     upload_bandwidth: upload_bandwidth.load(Relaxed),
                     download_bandwidth: download_bandwidth.load(Relaxed),
                     loss: LOSS.load(Relaxed),
+                    jitter_buffer,
+                    jitter: frame_statistics.jitter.load(Relaxed),
+                    gain: input_gain.load(Relaxed),
+                    speaking: speaking.load(Relaxed),
+                    capture_delay: stages.capture.round() as usize,
+                    processing_delay: stages.processing.round() as usize,
+                    network_delay: stages.network.round() as usize,
+                    playout_delay: stages.playout.round() as usize,
+                    one_way_delay: stages.total().round() as usize,
                 };
 
                 LATENCY.store(statistics.latency, Relaxed);
+                DELAY.store(statistics.one_way_delay, Relaxed);
                 (callback.lock().await)(statistics).await;
             }
             _ = reset_interval.tick() => {
@@ -3006,6 +4141,7 @@ async fn statistics_collector(
     (callback.lock().await)(statistics).await;
 
     LATENCY.store(0, Relaxed);
+    DELAY.store(0, Relaxed);
     LOSS.store(0_f64, Relaxed);
     CONNECTED.store(false, Relaxed);
 
@@ -3019,55 +4155,83 @@ fn input_processor(
     #[cfg(not(target_family = "wasm"))] receiver: Receiver<f32>,
     #[cfg(target_family = "wasm")] web_input: WebInput,
     sender: Sender<ProcessorMessage>,
-    sample_rate: f64,
+    sample_rate: Arc<AtomicU32>,
+    channels: usize,
     input_factor: Arc<AtomicF32>,
     rms_threshold: Arc<AtomicF32>,
     muted: Arc<AtomicBool>,
-    mut denoiser: Option<Box<DenoiseState>>,
+    mut denoisers: Option<Vec<Box<DenoiseState>>>,
+    mut echo_canceller: Option<EchoCanceller>,
+    mut agc: AutomaticGain,
+    mut vad: VoiceActivity,
+    mut noise_estimator: Option<NoiseEstimator>,
     rms_sender: Option<Sender<f32>>,
     codec_enabled: bool,
+    latency: Arc<LatencyStages>,
+    recording: Recording,
 ) -> Result<()> {
-    // the maximum and minimum values for i16 as f32
+    // the maximum value for i16 as f32
     let max_i16_f32 = i16::MAX as f32;
-    let min_i16_f32 = i16::MIN as f32;
     let i16_size = size_of::<i16>();
 
-    let ratio = if denoiser.is_some() {
+    // the sample rate of the captured samples, it changes when the input moves to another device
+    let mut capture_rate = sample_rate.load(Relaxed);
+
+    let processing_rate = if denoisers.is_some() {
         // rnnoise requires a 48kHz sample rate
-        48_000_f64 / sample_rate
+        48_000_f64
     } else {
         // do not resample if not using rnnoise
-        1_f64
+        capture_rate as f64
+    };
+
+    // the captured samples in a frame and the resampler bringing them to the processing rate
+    let resampling = |capture_rate: u32| {
+        let ratio = processing_rate / capture_rate as f64;
+        let in_len = (FRAME_SIZE as f64 / ratio).ceil() as usize;
+        resampler_factory(ratio, channels, in_len).map(|resampler| (in_len, resampler))
     };
 
     // rubato requires 10 extra spaces in the output buffer as a safety margin
     let post_len = (FRAME_SIZE as f64 + 10_f64) as usize;
-    let in_len = (FRAME_SIZE as f64 / ratio).ceil() as usize;
+    let (mut in_len, mut resampler) = resampling(capture_rate)?;
 
-    let mut resampler = resampler_factory(ratio, 1, in_len)?;
-
-    // the input for the resampler
-    let mut pre_buf = [vec![0_f32; in_len]];
+    // the input for the resampler, one buffer per channel
+    let mut pre_buf = vec![vec![0_f32; in_len]; channels];
     // the output for the resampler
-    let mut post_buf = [vec![0_f32; post_len]];
+    let mut post_buf = vec![vec![0_f32; post_len]; channels];
     // the output for rnnoise
     let mut out_buf = [0_f32; FRAME_SIZE];
+    // the processed channels interleaved again
+    let mut frame = vec![0_f32; FRAME_SIZE * channels];
 
-    // output for 16 bit samples. the compiler does not recognize that it is used
-    #[allow(unused_assignments)]
-    let mut int_buffer = [0; FRAME_SIZE];
+    // output for 16 bit samples
+    let mut int_buffer = vec![0_i16; FRAME_SIZE * channels];
 
     // the position in pre_buf
     let mut position = 0;
-    // a counter user for short silence detection
-    let mut silence_length = 0_u8;
+    // the channel of the next interleaved sample
+    #[cfg(not(target_family = "wasm"))]
+    let mut channel = 0;
 
     loop {
+        // the input moved to a device with another sample rate, the next frame is resampled from it
+        if position == 0 && sample_rate.load(Relaxed) != capture_rate {
+            capture_rate = sample_rate.load(Relaxed);
+            (in_len, resampler) = resampling(capture_rate)?;
+            pre_buf = vec![vec![0_f32; in_len]; channels];
+        }
+
         #[cfg(not(target_family = "wasm"))]
         {
             if let Ok(sample) = receiver.recv() {
-                pre_buf[0][position] = sample;
-                position += 1;
+                pre_buf[channel][position] = sample;
+                channel += 1;
+
+                if channel == channels {
+                    channel = 0;
+                    position += 1;
+                }
             } else {
                 break;
             }
@@ -3106,8 +4270,29 @@ fn input_processor(
 
         position = 0;
 
+        // on web the captured samples are not counted
+        #[cfg(not(target_family = "wasm"))]
+        latency.input_queue.store(
+            milliseconds(receiver.len() / channels, capture_rate as f64),
+            Relaxed,
+        );
+
+        // the first sample waited for the frame to fill, then for the resampler and queued frames
+        let resampler_delay = resampler.as_ref().map_or(0, |r| r.output_delay());
+        latency.encoding.store(
+            milliseconds(in_len, capture_rate as f64)
+                + milliseconds(resampler_delay + sender.len() * FRAME_SIZE, processing_rate),
+            Relaxed,
+        );
+
         // sends a silence signal if the input is muted
         if muted.load(Relaxed) {
+            if let Some(ref mut echo_canceller) = echo_canceller {
+                echo_canceller.skip(FRAME_SIZE)?;
+            }
+
+            vad.silence();
+            recording.local(None, channels, processing_rate);
             sender.try_send(ProcessorMessage::silence())?;
             continue;
         }
@@ -3115,55 +4300,91 @@ fn input_processor(
         let (target_buffer, len) = if let Some(resampler) = &mut resampler {
             // resample the data
             let processed = resampler.process_into_buffer(&pre_buf, &mut post_buf, None)?;
-            (&mut post_buf[0], processed.1)
+            (&mut post_buf, processed.1)
         } else {
-            (&mut pre_buf[0], FRAME_SIZE)
+            (&mut pre_buf, FRAME_SIZE)
         };
 
         // the first frame may be smaller than FRAME_SIZE
         if len != FRAME_SIZE {
             warn!("input_processor: len != FRAME_SIZE: {}", len);
+
+            if let Some(ref mut echo_canceller) = echo_canceller {
+                echo_canceller.skip(len)?;
+            }
+
             continue;
         }
 
+        if let Some(ref mut echo_canceller) = echo_canceller {
+            // cancel the echo while the input is at the same scale as the output reference
+            echo_canceller.process(target_buffer, len)?;
+        }
+
         // apply the input volume & scale the samples to -32768.0 to 32767.0
         let factor = max_i16_f32 * input_factor.load(Relaxed);
+        // rnnoise also gives the probability the frame holds a voice, the likeliest channel counts
+        let mut probability: Option<f32> = None;
+
+        for (channel, buffer) in target_buffer.iter_mut().enumerate() {
+            // rescale the samples to -32768.0 to 32767.0 for rnnoise, the limiter catches any peaks over
+            buffer.iter_mut().for_each(|x| *x *= factor);
+
+            let output = if let Some(ref mut denoisers) = denoisers {
+                // denoise the frame
+                let voice = denoisers[channel].process_frame(&mut out_buf, &buffer[..len]);
+                probability = Some(probability.map_or(voice, |p| p.max(voice)));
+                &out_buf[..]
+            } else {
+                &buffer[..len]
+            };
 
-        // rescale the samples to -32768.0 to 32767.0 for rnnoise
-        target_buffer.iter_mut().for_each(|x| {
-            *x *= factor;
-            *x = x.trunc().clamp(min_i16_f32, max_i16_f32);
-        });
-
-        if let Some(ref mut denoiser) = denoiser {
-            // denoise the frame
-            denoiser.process_frame(&mut out_buf, &target_buffer[..len]);
-        } else {
-            out_buf = target_buffer[..len].try_into()?;
-        };
+            for (i, sample) in output.iter().enumerate() {
+                frame[i * channels + channel] = *sample;
+            }
+        }
 
         // calculate the rms
-        let rms = calculate_rms(&out_buf);
+        let rms = calculate_rms(&frame);
         // send the rms to the statistics collector
         rms_sender.as_ref().map(|s| s.send(rms));
 
-        // check if the frame is below the rms threshold
-        if rms < rms_threshold.load(Relaxed) {
-            if silence_length < 80 {
-                silence_length += 1; // short silences are ignored
-            } else {
-                sender.try_send(ProcessorMessage::silence())?;
-                continue;
+        // short silences after speech are still sent
+        let activity = vad.detect(rms, rms_threshold.load(Relaxed), probability);
+
+        if let Some(ref mut noise_estimator) = noise_estimator {
+            // the background noise is heard between words too, not only once the frames stop
+            if !activity.voice {
+                noise_estimator.analyze(&frame);
             }
-        } else {
-            silence_length = 0;
+
+            if activity.transmit {
+                noise_estimator.transmitted();
+            }
+        }
+
+        if !activity.transmit {
+            let message = match noise_estimator {
+                Some(ref mut noise_estimator) => noise_estimator.silence(),
+                None => ProcessorMessage::silence(),
+            };
+
+            recording.local(None, channels, processing_rate);
+            sender.try_send(message)?;
+            continue;
         }
 
+        // bring speech to the target level and limit the peaks, the gain follows speech only
+        agc.process(&mut frame, activity.voice);
+        recording.local(Some(&frame), channels, processing_rate);
+
         // cast the f32 samples to i16
-        int_buffer = out_buf.map(|x| x as i16);
+        for (int, sample) in int_buffer.iter_mut().zip(&frame) {
+            *int = *sample as i16;
+        }
 
         if codec_enabled {
-            sender.send(ProcessorMessage::samples(int_buffer))?;
+            sender.send(ProcessorMessage::samples(int_buffer.as_slice()))?;
         } else {
             // convert the i16 samples to bytes
             let bytes = unsafe {
@@ -3182,46 +4403,203 @@ fn input_processor(
 }
 
 /// Processes the audio data and sends it to the output stream
+/// on native targets frames wait in a jitter buffer and play as the output device needs them
+#[allow(clippy::too_many_arguments)]
 fn output_processor(
     receiver: Receiver<ProcessorMessage>,
     #[cfg(target_family = "wasm")] web_output: Arc<wasm_sync::Mutex<Vec<f32>>>,
     #[cfg(not(target_family = "wasm"))] sender: Sender<f32>,
-    ratio: f64,
+    mut remote_sample_rate: f64,
+    output_sample_rate: Arc<AtomicU32>,
+    channels: usize,
     output_volume: Arc<AtomicF32>,
     rms_sender: Option<Sender<f32>>,
+    codec_enabled: bool,
+    fec: bool,
+    mut comfort_noise: Option<ComfortNoise>,
+    #[cfg(not(target_family = "wasm"))] jitter_depth: Arc<AtomicUsize>,
+    #[cfg(not(target_family = "wasm"))] frame_statistics: Arc<FrameStatistics>,
+    latency: Arc<LatencyStages>,
+    recording: Recording,
+    source: usize,
 ) -> Result<()> {
     let scale = 1_f32 / i16::MAX as f32;
     let i16_size = size_of::<i16>();
 
-    let mut resampler = resampler_factory(ratio, 1, FRAME_SIZE)?;
+    // the sample rate of the output device, it changes when the output moves to another device
+    let mut playback_rate = output_sample_rate.load(Relaxed) as f64;
+    let mut ratio = playback_rate / remote_sample_rate;
+    let mut resampler = resampler_factory(ratio, channels, FRAME_SIZE)?;
 
     // rubato requires 10 extra spaces in the output buffer as a safety margin
-    let post_len = (FRAME_SIZE as f64 * ratio + 10_f64) as usize;
+    let post_len = |ratio: f64| (FRAME_SIZE as f64 * ratio + 10_f64) as usize;
 
-    // the input for the resampler
-    let pre_buf = [&mut [0_f32; FRAME_SIZE]];
+    // the input for the resampler, one buffer per channel
+    let mut pre_buf = vec![vec![0_f32; FRAME_SIZE]; channels];
     // the output for the resampler
-    let mut post_buf = [vec![0_f32; post_len]];
+    let mut post_buf = vec![vec![0_f32; post_len(ratio)]; channels];
+    // the frames arrive with their channels interleaved
+    let deinterleave = |pre_buf: &mut [Vec<f32>], samples: &[i16]| {
+        for (i, &x) in samples.iter().take(FRAME_SIZE * channels).enumerate() {
+            pre_buf[i % channels][i / channels] = x as f32 * scale;
+        }
+    };
+
+    // the decoder runs on this thread so that lost frames can be concealed
+    // it starts with the first header the remote encoder sends
+    let mut decoder: Option<SeaChunkDecoder> = None;
+    // a frame recovered from a redundant copy plays ahead of the frame which carried it
+    let mut decoded: VecDeque<Vec<i16>> = VecDeque::new();
+
+    let frame_duration =
+        |sample_rate: f64| Duration::from_secs_f64(FRAME_SIZE as f64 / sample_rate);
+    #[cfg(not(target_family = "wasm"))]
+    let mut jitter_buffer = JitterBuffer::new(frame_duration(remote_sample_rate), frame_statistics);
+
+    loop {
+        // the output moved to a device with another sample rate
+        if output_sample_rate.load(Relaxed) != playback_rate as u32 {
+            playback_rate = output_sample_rate.load(Relaxed) as f64;
+            ratio = playback_rate / remote_sample_rate;
+            resampler = resampler_factory(ratio, channels, FRAME_SIZE)?;
+            post_buf = vec![vec![0_f32; post_len(ratio)]; channels];
+        }
+
+        let (message, stretch) = if let Some(samples) = decoded.pop_front() {
+            (ProcessorMessage::samples(samples), Stretch::None)
+        } else {
+            #[cfg(not(target_family = "wasm"))]
+            let playout = {
+                // the output holds interleaved samples
+                let output_rate = playback_rate * channels as f64;
+                if !receive_frames(&receiver, &mut jitter_buffer, &sender, output_rate) {
+                    break;
+                }
+
+                let playout = jitter_buffer.pop();
+                jitter_depth.store(jitter_buffer.depth().as_millis() as usize, Relaxed);
+                playout
+            };
+
+            // timers are not available to this thread on web, frames play as they arrive
+            #[cfg(target_family = "wasm")]
+            let playout = match receiver.recv() {
+                Ok(message) => Playout::Frame(message, Stretch::None),
+                Err(_) => break,
+            };
+
+            match playout {
+                Playout::Frame(message, stretch) => (message, stretch),
+                Playout::Missing => (conceal(decoder.as_mut()), Stretch::None),
+                Playout::Buffering => (ProcessorMessage::silence(), Stretch::None),
+            }
+        };
+
+        let message = match message {
+            ProcessorMessage::Data(packet) if codec_enabled => {
+                match SeaChunkDecoder::restart_header(&packet) {
+                    Ok(Some(header)) => {
+                        // the header comes from the remote, a bogus one must not end the call
+                        if !supported_stream(&header, channels) {
+                            warn!(
+                                "ignoring a remote stream restart at {} Hz with {} channels",
+                                header.sample_rate, header.channels
+                            );
+                            continue;
+                        }
+
+                        match decoder.as_mut() {
+                            Some(decoder) => decoder.restart(header.clone()),
+                            None => match SeaChunkDecoder::new(&packet) {
+                                Ok(mut new_decoder) => {
+                                    new_decoder.set_fec(fec);
+                                    decoder = Some(new_decoder);
+                                }
+                                Err(error) => {
+                                    warn!("ignoring an invalid remote stream header: {:?}", error);
+                                    continue;
+                                }
+                            },
+                        }
+
+                        ProcessorMessage::Restart(header)
+                    }
+                    Ok(None) => {
+                        let Some(decoder) = decoder.as_mut() else {
+                            continue; // nothing can be decoded before the header
+                        };
+
+                        match decoder.decode_packet(&packet) {
+                            Ok(frames) => {
+                                decoded.extend(frames);
+
+                                match decoded.pop_front() {
+                                    Some(samples) => ProcessorMessage::samples(samples),
+                                    None => continue, // the frame is older than one already played
+                                }
+                            }
+                            Err(_) => conceal(Some(decoder)),
+                        }
+                    }
+                    Err(_) => conceal(decoder.as_mut()),
+                }
+            }
+            // a frame the sender sent which never arrived
+            ProcessorMessage::Lost => conceal(decoder.as_mut()),
+            // the descriptor updates the comfort noise, the frame itself is silence
+            ProcessorMessage::ComfortNoise(descriptor) => {
+                if let Some(comfort_noise) = comfort_noise.as_mut() {
+                    comfort_noise.update(&descriptor);
+                }
+
+                ProcessorMessage::silence()
+            }
+            message => message,
+        };
 
-    while let Ok(message) = receiver.recv() {
         match message {
             ProcessorMessage::Silence => {
-                #[cfg(not(target_family = "wasm"))]
-                for _ in 0..FRAME_SIZE {
-                    sender.try_send(0_f32)?;
+                if let Some(decoder) = decoder.as_mut() {
+                    decoder.silence();
                 }
 
-                #[cfg(target_family = "wasm")]
-                web_output
-                    .lock()
-                    .map(|mut data| {
-                        if data.len() < CHANNEL_SIZE {
-                            data.extend(SILENCE)
+                // comfort noise plays like any other frame
+                if let Some(comfort_noise) = comfort_noise.as_mut().filter(|c| c.is_active()) {
+                    let (first, others) = pre_buf.split_at_mut(1);
+                    comfort_noise.fill(&mut first[0]);
+                    // the noise is described for one channel and plays on all of them
+                    for other in others {
+                        other.copy_from_slice(&first[0]);
+                    }
+                } else {
+                    recording.remote(source, None, remote_sample_rate);
+
+                    // silence stretches by whole frames
+                    #[cfg(not(target_family = "wasm"))]
+                    {
+                        let frames = match stretch {
+                            Stretch::None => 1,
+                            Stretch::Grow => 2,
+                            Stretch::Shrink => 0,
+                        };
+
+                        for _ in 0..(FRAME_SIZE as f64 * ratio) as usize * frames * channels {
+                            sender.try_send(0_f32)?;
                         }
-                    })
-                    .unwrap();
+                    }
 
-                continue;
+                    #[cfg(target_family = "wasm")]
+                    web_output
+                        .lock()
+                        .map(|mut data| {
+                            if data.len() < CHANNEL_SIZE {
+                                data.extend(SILENCE)
+                            }
+                        })
+                        .unwrap();
+
+                    continue;
+                }
             }
             ProcessorMessage::Data(bytes) => {
                 // convert the bytes to 16-bit integers
@@ -3230,67 +4608,154 @@ fn output_processor(
                 };
 
                 // convert the frame to f32s
-                for (out, &x) in pre_buf[0].iter_mut().zip(ints.iter()) {
-                    *out = x as f32 * scale;
-                }
+                deinterleave(&mut pre_buf, ints);
             }
             ProcessorMessage::Samples(samples) => {
                 // convert the frame to f32s
-                for (out, &x) in pre_buf[0].iter_mut().zip(samples.iter()) {
-                    *out = x as f32 * scale;
+                deinterleave(&mut pre_buf, &samples);
+            }
+            ProcessorMessage::Restart(header) => {
+                // the remote encoder restarted, rebuild the resampler if its sample rate changed
+                let sample_rate = header.sample_rate as f64;
+                if sample_rate != remote_sample_rate {
+                    info!(
+                        "remote stream restarted at {} Hz (was {} Hz)",
+                        sample_rate, remote_sample_rate
+                    );
+
+                    remote_sample_rate = sample_rate;
+                    ratio = playback_rate / remote_sample_rate;
+                    resampler = resampler_factory(ratio, channels, FRAME_SIZE)?;
+                    post_buf = vec![vec![0_f32; post_len(ratio)]; channels];
+
+                    #[cfg(not(target_family = "wasm"))]
+                    jitter_buffer.set_frame_duration(frame_duration(remote_sample_rate));
                 }
+
+                continue;
             }
+            // replaced by their concealment and silence above
+            ProcessorMessage::Lost | ProcessorMessage::ComfortNoise(_) => continue,
         }
 
+        // the frame is recorded as it was decoded
+        recording.remote(source, Some(&pre_buf), remote_sample_rate);
+
         // apply the output volume
-        mul(pre_buf[0], output_volume.load(Relaxed));
+        let volume = output_volume.load(Relaxed);
+        pre_buf.iter_mut().for_each(|buffer| mul(buffer, volume));
 
-        rms_sender
-            .as_ref()
-            .map(|s| s.send(calculate_rms(pre_buf[0])));
+        rms_sender.as_ref().map(|s| {
+            let rms = pre_buf.iter().map(|buffer| calculate_rms(buffer));
+            s.send(rms.fold(0_f32, f32::max))
+        });
 
-        if let Some(resampler) = &mut resampler {
+        let (buffers, len) = if let Some(resampler) = &mut resampler {
             // resample the data
             let processed = resampler.process_into_buffer(&pre_buf, &mut post_buf, None)?;
+            (&post_buf, processed.1)
+        } else {
+            // if no resampling is needed, send the data to the output stream
+            (&pre_buf, FRAME_SIZE)
+        };
 
-            // send the resampled data to the output stream
-            #[cfg(not(target_family = "wasm"))]
-            for sample in &post_buf[0][..processed.1] {
-                sender.try_send(*sample)?;
-            }
+        // interleave the channels for the output stream
+        let interleaved: Vec<f32>;
+        let output: &[f32] = if channels == 1 {
+            &buffers[0][..len]
+        } else {
+            interleaved = (0..len)
+                .flat_map(|i| buffers.iter().map(move |buffer| buffer[i]))
+                .collect();
+            &interleaved
+        };
 
-            #[cfg(target_family = "wasm")]
-            web_output
-                .lock()
-                .map(|mut data| {
-                    if data.len() < CHANNEL_SIZE {
-                        data.extend(&post_buf[0][..processed.1])
-                    }
-                })
-                .unwrap();
+        // stretching moves the jitter buffer towards its target depth
+        let stretched;
+        let output: &[f32] = if stretch == Stretch::None {
+            output
         } else {
-            // if no resampling is needed, send the data to the output stream
-            #[cfg(not(target_family = "wasm"))]
-            for sample in *pre_buf[0] {
-                sender.try_send(sample)?;
-            }
+            stretched = time_stretch(output, channels, stretch);
+            &stretched
+        };
 
-            #[cfg(target_family = "wasm")]
-            web_output
-                .lock()
-                .map(|mut data| {
-                    if data.len() < CHANNEL_SIZE {
-                        data.extend(*pre_buf[0])
-                    }
-                })
-                .unwrap();
+        // the frames decoded ahead of this one and the delay the resampler adds
+        let resampler_delay = resampler.as_ref().map_or(0, |r| r.output_delay());
+        latency.decoding.store(
+            milliseconds(decoded.len() * FRAME_SIZE, remote_sample_rate)
+                + milliseconds(resampler_delay, playback_rate),
+            Relaxed,
+        );
+
+        // on web the played samples are not counted
+        #[cfg(not(target_family = "wasm"))]
+        latency.output_queue.store(
+            milliseconds(sender.len() / channels, playback_rate),
+            Relaxed,
+        );
+
+        // send the data to the output stream
+        #[cfg(not(target_family = "wasm"))]
+        for sample in output {
+            sender.try_send(*sample)?;
         }
+
+        #[cfg(target_family = "wasm")]
+        web_output
+            .lock()
+            .map(|mut data| {
+                if data.len() < CHANNEL_SIZE {
+                    data.extend(output)
+                }
+            })
+            .unwrap();
     }
 
     debug!("Output processor ended");
     Ok(())
 }
 
+/// Receives frames into the jitter buffer until the output device runs low on samples,
+/// returns false once the receiving socket has closed
+#[cfg(not(target_family = "wasm"))]
+fn receive_frames(
+    receiver: &Receiver<ProcessorMessage>,
+    jitter_buffer: &mut JitterBuffer,
+    output: &Sender<f32>,
+    output_sample_rate: f64,
+) -> bool {
+    let low_water = (OUTPUT_LOW_WATER.as_secs_f64() * output_sample_rate) as usize;
+
+    loop {
+        let queued = output.len();
+
+        if queued <= low_water {
+            // the device needs samples, take what already arrived and play
+            loop {
+                match receiver.try_recv() {
+                    Ok(Some(message)) => jitter_buffer.push(message),
+                    Ok(None) => return true,
+                    Err(_) => return false,
+                }
+            }
+        }
+
+        let wait = Duration::from_secs_f64((queued - low_water) as f64 / output_sample_rate);
+        match receiver.recv_timeout(wait) {
+            Ok(message) => jitter_buffer.push(message),
+            Err(ReceiveErrorTimeout::Timeout) => (),
+            Err(_) => return false,
+        }
+    }
+}
+
+/// A replacement for a lost frame, silence once the concealment has faded out
+fn conceal(decoder: Option<&mut SeaChunkDecoder>) -> ProcessorMessage {
+    decoder
+        .and_then(|decoder| decoder.conceal_chunk())
+        .map_or_else(ProcessorMessage::silence, ProcessorMessage::samples)
+}
+
 fn stream_to_audio_transport(stream: Stream) -> Transport<TransportStream> {
     LengthDelimitedCodec::builder()
         .max_frame_length(TRANSFER_BUFFER_SIZE)
@@ -3306,9 +4771,13 @@ pub(crate) mod tests {
     use log::LevelFilter;
     use rand::prelude::SliceRandom;
     use rand::Rng;
+    use sea_codec::encoder::{EncoderSettings, SeaChunkEncoder};
     use std::fs::read;
     use std::thread::spawn;
 
+    /// denoise, codec enabled and echo cancellation
+    type Case = (bool, bool, bool);
+
     struct BenchmarkResult {
         average: Duration,
         min: Duration,
@@ -3333,23 +4802,32 @@ pub(crate) mod tests {
 
         // warmup
         for _ in 0..5 {
-            benchmark_input_stack(false, false, sample_rate, &samples, 2400);
+            benchmark_input_stack(false, false, false, sample_rate, &samples, 2400);
         }
 
         let num_iterations = 10;
-        let mut results: HashMap<(bool, bool), (Vec<Duration>, Duration)> = HashMap::new();
+        let mut results: HashMap<Case, (Vec<Duration>, Duration)> = HashMap::new();
 
         for _ in 0..num_iterations {
-            let mut cases = vec![(false, false), (false, true), (true, false), (true, true)];
+            // every combination of denoise, codec and echo cancellation
+            let mut cases: Vec<Case> = (0..8)
+                .map(|i| (i & 1 != 0, i & 2 != 0, i & 4 != 0))
+                .collect();
             cases.shuffle(&mut rand::thread_rng()); // Shuffle for each iteration
 
-            for (denoise, codec_enabled) in cases {
-                let (durations, end) =
-                    benchmark_input_stack(denoise, codec_enabled, sample_rate, &samples, 2400);
+            for (denoise, codec_enabled, echo) in cases {
+                let (durations, end) = benchmark_input_stack(
+                    denoise,
+                    codec_enabled,
+                    echo,
+                    sample_rate,
+                    &samples,
+                    2400,
+                );
 
                 // Update the results in a cumulative way
                 results
-                    .entry((denoise, codec_enabled))
+                    .entry((denoise, codec_enabled, echo))
                     .and_modify(|(all_durations, total_time)| {
                         all_durations.extend(durations.clone());
                         *total_time += end;
@@ -3359,7 +4837,7 @@ pub(crate) mod tests {
         }
 
         // compute final averages
-        for ((_denoise, _codec_enabled), (_durations, total_time)) in results.iter_mut() {
+        for (_case, (_durations, total_time)) in results.iter_mut() {
             *total_time /= num_iterations as u32; // Average total runtime
         }
 
@@ -3369,6 +4847,7 @@ pub(crate) mod tests {
     fn benchmark_input_stack(
         denoise: bool,
         codec_enabled: bool,
+        echo: bool,
         sample_rate: u32,
         samples: &[f32],
         channel_size: usize,
@@ -3384,19 +4863,50 @@ pub(crate) mod tests {
 
         let model = RnnModel::default();
 
-        let denoiser = denoise.then_some(DenoiseState::from_model(model));
+        let denoisers = denoise.then(|| vec![DenoiseState::from_model(model)]);
+
+        // the samples play on the output and reach the input as a synthetic echo
+        let (echo_sender, echo_receiver) = bounded(channel_size);
+        let processing_rate = if denoise { 48_000 } else { sample_rate };
+        let echo_canceller = echo.then(|| {
+            let ratio = processing_rate as f64 / sample_rate as f64;
+            EchoCanceller::new(echo_receiver, ratio, FRAME_SIZE, 1).unwrap()
+        });
+        let input = if echo {
+            synthetic_echo(samples)
+        } else {
+            samples.to_vec()
+        };
+        let agc = AutomaticGain::new(
+            Default::default(),
+            Default::default(),
+            processing_rate as f64,
+        );
+        let vad = VoiceActivity::new(
+            Default::default(),
+            Default::default(),
+            FRAME_SIZE,
+            processing_rate as f64,
+        );
 
         spawn(move || {
             input_processor(
                 input_receiver,
                 processed_input_sender,
-                sample_rate as f64,
+                Arc::new(AtomicU32::new(sample_rate)),
+                1,
                 Arc::new(AtomicF32::new(1_f32)),
                 Arc::new(AtomicF32::new(15_f32)),
                 Arc::new(AtomicBool::new(false)),
-                denoiser,
+                denoisers,
+                echo_canceller,
+                agc,
+                vad,
+                None,
                 None,
                 codec_enabled,
+                Default::default(),
+                Default::default(),
             )
         });
 
@@ -3405,9 +4915,12 @@ pub(crate) mod tests {
                 encoder(
                     processed_input_receiver,
                     encoded_input_sender,
-                    if denoise { 48_000 } else { sample_rate },
-                    true,
-                    5.0,
+                    1,
+                    FRAME_SIZE as u16,
+                    processing_rate,
+                    CodecConfig::new(true, true, 5.0),
+                    (true, 5.0),
+                    false,
                 );
             });
 
@@ -3418,8 +4931,12 @@ pub(crate) mod tests {
 
         let samples = samples.to_vec();
         spawn(move || {
-            for sample in samples {
-                input_sender.send(sample).unwrap();
+            for (sample, input) in samples.into_iter().zip(input) {
+                if echo {
+                    echo_sender.send(sample).unwrap();
+                }
+
+                input_sender.send(input).unwrap();
             }
         });
 
@@ -3446,13 +4963,13 @@ pub(crate) mod tests {
         (average, min, max)
     }
 
-    fn compare_runs(benchmark_results: HashMap<(bool, bool), (Vec<Duration>, Duration)>) {
-        let mut summary: HashMap<(bool, bool), BenchmarkResult> = HashMap::new();
+    fn compare_runs(benchmark_results: HashMap<Case, (Vec<Duration>, Duration)>) {
+        let mut summary: HashMap<Case, BenchmarkResult> = HashMap::new();
 
-        for ((denoise, codec_enabled), (durations, end)) in benchmark_results {
+        for (case, (durations, end)) in benchmark_results {
             let (average, min, max) = compute_statistics(&durations);
             summary.insert(
-                (denoise, codec_enabled),
+                case,
                 BenchmarkResult {
                     average,
                     min,
@@ -3464,17 +4981,225 @@ pub(crate) mod tests {
 
         info!("\nComparison of Runs:");
         info!("===================================================");
-        info!(" Denoise | Codec Enabled | Avg Duration | Min Duration | Max Duration | Runtime ");
+        info!(" Denoise | Codec Enabled | Echo Cancellation | Avg Duration | Min Duration | Max Duration | Runtime ");
         info!("---------------------------------------------------");
 
-        for ((denoise, codec_enabled), result) in summary {
+        for ((denoise, codec_enabled, echo), result) in summary {
             info!(
-                " {}   | {}     | {:?} | {:?} | {:?} | {:?}",
-                denoise, codec_enabled, result.average, result.min, result.max, result.end
+                " {}   | {}     | {}     | {:?} | {:?} | {:?} | {:?}",
+                denoise, codec_enabled, echo, result.average, result.min, result.max, result.end
             );
         }
     }
 
+    #[test]
+    fn echo_cancellation() {
+        cancels_echo(1);
+    }
+
+    #[test]
+    fn stereo_echo_cancellation() {
+        cancels_echo(2);
+    }
+
+    /// cancels the echo of three seconds of noise captured on every channel
+    fn cancels_echo(channels: usize) {
+        let sample_rate = 48_000;
+        let mut rng = rand::thread_rng();
+        let reference: Vec<f32> = (0..sample_rate * 3)
+            .map(|_| rng.gen_range(-0.5..0.5))
+            .collect();
+        // further microphones pick up a quieter, inverted echo
+        let captured: Vec<Vec<f32>> = (0..channels)
+            .map(|channel| {
+                let gain = if channel == 0 { 1_f32 } else { -0.5_f32 };
+                synthetic_echo(&reference)
+                    .iter()
+                    .map(|x| x * gain)
+                    .collect()
+            })
+            .collect();
+
+        let (input_sender, input_receiver) = bounded(FRAME_SIZE * channels);
+        let (echo_sender, echo_receiver) = bounded(FRAME_SIZE);
+        let (processed_sender, processed_receiver) = unbounded::<ProcessorMessage>();
+        let echo_canceller =
+            EchoCanceller::new(echo_receiver, 1_f64, FRAME_SIZE, channels).unwrap();
+        let agc = AutomaticGain::new(Default::default(), Default::default(), sample_rate as f64);
+        let vad = VoiceActivity::new(
+            Default::default(),
+            Default::default(),
+            FRAME_SIZE,
+            sample_rate as f64,
+        );
+
+        spawn(move || {
+            input_processor(
+                input_receiver,
+                processed_sender,
+                Arc::new(AtomicU32::new(sample_rate)),
+                channels,
+                Arc::new(AtomicF32::new(1_f32)),
+                Arc::new(AtomicF32::new(0_f32)),
+                Arc::new(AtomicBool::new(false)),
+                None,
+                Some(echo_canceller),
+                agc,
+                vad,
+                None,
+                None,
+                false,
+                Default::default(),
+                Default::default(),
+            )
+        });
+
+        // the output plays one frame while the input captures one
+        let mut processed = vec![Vec::new(); channels];
+        for (index, reference) in reference.chunks(FRAME_SIZE).enumerate() {
+            reference.iter().for_each(|x| echo_sender.send(*x).unwrap());
+            for i in index * FRAME_SIZE..(index + 1) * FRAME_SIZE {
+                captured
+                    .iter()
+                    .for_each(|channel| input_sender.send(channel[i]).unwrap());
+            }
+
+            match processed_receiver.recv().unwrap() {
+                ProcessorMessage::Data(bytes) => {
+                    for (i, x) in bytes.chunks(2).enumerate() {
+                        let sample = i16::from_ne_bytes([x[0], x[1]]) as f32 / i16::MAX as f32;
+                        processed[i % channels].push(sample);
+                    }
+                }
+                _ => processed
+                    .iter_mut()
+                    .for_each(|channel| channel.extend([0_f32; FRAME_SIZE])),
+            }
+        }
+
+        // the echo of the last second is cancelled
+        let tail = reference.len() - sample_rate as usize;
+        let energy = |samples: &[f32]| samples.iter().map(|x| x * x).sum::<f32>();
+        for (captured, processed) in captured.iter().zip(&processed) {
+            let erle = 10_f32 * (energy(&captured[tail..]) / energy(&processed[tail..])).log10();
+            assert!(erle > 20_f32, "erle {}", erle);
+        }
+    }
+
+    /// the samples played into a room and captured 50ms later
+    fn synthetic_echo(samples: &[f32]) -> Vec<f32> {
+        let response = [(2_400, 0.5), (2_413, -0.2), (2_461, 0.1)];
+
+        (0..samples.len())
+            .map(|i| {
+                response
+                    .iter()
+                    .filter_map(|&(delay, gain)| Some(samples[i.checked_sub(delay)?] * gain))
+                    .sum()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fec_recovers_a_lost_frame() {
+        let settings = EncoderSettings {
+            frames_per_chunk: FRAME_SIZE as u16,
+            fec_residual_bits: Some(2_f32),
+            ..Default::default()
+        };
+        let mut encoder = SeaChunkEncoder::new(1, 48_000, settings).unwrap();
+        let chunks: Vec<Vec<i16>> = (0..5)
+            .map(|chunk| {
+                (0..FRAME_SIZE)
+                    .map(|i| (((chunk * FRAME_SIZE + i) as f32 * 0.05).sin() * 10_000_f32) as i16)
+                    .collect()
+            })
+            .collect();
+
+        let mut writer = FrameWriter::new();
+        let frames: Vec<_> = chunks
+            .iter()
+            .map(|chunk| writer.write(FrameType::Audio, &encoder.encode_packet(chunk).unwrap()))
+            .collect();
+
+        let mut decoder = SeaChunkDecoder::new(&encoder.header().unwrap().unwrap()).unwrap();
+        decoder.set_fec(true);
+        let mut receiver = FrameReceiver::new(Default::default());
+        let mut played = Vec::new();
+        let mut concealed = 0;
+
+        // the third frame never arrives
+        for (_, frame) in frames.into_iter().enumerate().filter(|(i, _)| *i != 2) {
+            let (header, payload) = FrameHeader::parse(frame).unwrap();
+            let Arrival::InOrder { lost } = receiver.receive(&header) else {
+                panic!("frame {} arrived late", header.sequence);
+            };
+
+            for _ in 0..concealed_frames(lost, true, header.frame_type, &payload) {
+                concealed += 1;
+                decoder.conceal_chunk();
+            }
+
+            played.extend(decoder.decode_packet(&payload).unwrap());
+        }
+
+        assert_eq!(concealed, 0);
+        assert_eq!(played.len(), chunks.len());
+
+        // the recovered copy has fewer bits but follows the original
+        let error: f64 = chunks[2]
+            .iter()
+            .zip(&played[2])
+            .map(|(a, b)| (*a as f64 - *b as f64).powi(2))
+            .sum();
+        let energy: f64 = chunks[2].iter().map(|a| (*a as f64).powi(2)).sum();
+        assert!(error < energy / 10_f64, "error {} energy {}", error, energy);
+    }
+
+    #[test]
+    fn conceals_what_fec_can_not_repair() {
+        let settings = EncoderSettings {
+            frames_per_chunk: FRAME_SIZE as u16,
+            fec_residual_bits: Some(2_f32),
+            ..Default::default()
+        };
+        let mut encoder = SeaChunkEncoder::new(1, 48_000, settings).unwrap();
+        let first = encoder.encode_packet(&[0; FRAME_SIZE]).unwrap();
+        let second = encoder.encode_packet(&[0; FRAME_SIZE]).unwrap();
+
+        // the first packet has no previous chunk to repeat
+        assert_eq!(concealed_frames(2, true, FrameType::Audio, &first), 2);
+        // only the frame right before can be repaired
+        assert_eq!(concealed_frames(2, true, FrameType::Audio, &second), 1);
+        assert_eq!(concealed_frames(1, false, FrameType::Audio, &second), 1);
+        assert_eq!(concealed_frames(1, true, FrameType::Silence, &[]), 1);
+        assert_eq!(concealed_frames(0, true, FrameType::Audio, &second), 0);
+    }
+
+    #[test]
+    fn rejects_unsupported_remote_streams() {
+        let settings = EncoderSettings {
+            frames_per_chunk: FRAME_SIZE as u16,
+            ..Default::default()
+        };
+        let mut encoder = SeaChunkEncoder::new(2, 48_000, settings).unwrap();
+        encoder.encode_chunk(&[0; FRAME_SIZE * 2]).unwrap();
+        let packet = encoder.header().unwrap().unwrap();
+        let header = SeaChunkDecoder::restart_header(&packet).unwrap().unwrap();
+
+        assert!(supported_stream(&header, 2));
+        // the output was set up for a mono stream
+        assert!(!supported_stream(&header, 1));
+
+        for sample_rate in [0, 1, 7_999, 192_001, u32::MAX] {
+            let header = SeaFileHeader {
+                sample_rate,
+                ..header.clone()
+            };
+            assert!(!supported_stream(&header, 2), "{} Hz", sample_rate);
+        }
+    }
+
     /// returns a frame of random samples
     pub(crate) fn dummy_frame() -> [f32; 4096] {
         let mut frame = [0_f32; 4096];
@@ -1,17 +1,19 @@
 use crate::api::error::{Error, ErrorKind};
-use crate::api::telepathy::{DeviceName, Transport};
+use crate::api::telepathy::{DeviceInfo, DeviceName, Transport};
 use bincode::config::standard;
 use bincode::{decode_from_slice, encode_to_vec, Decode, Encode};
 use cpal::traits::{DeviceTrait, HostTrait};
-use cpal::{Device, Host, Stream};
+use cpal::{Device, Host, Stream, SupportedBufferSize, SupportedStreamConfigRange};
 use flutter_rust_bridge::for_generated::futures::{Sink, SinkExt};
 use kanal::AsyncReceiver;
 use libp2p::bytes::Bytes;
 use libp2p::futures::StreamExt;
+use log::warn;
 use rubato::{SincFixedIn, SincInterpolationParameters, SincInterpolationType, WindowFunction};
 use serde::Deserialize;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+use std::collections::{BTreeSet, HashMap};
 use std::sync::atomic::AtomicU32;
 use std::sync::atomic::Ordering::Relaxed;
 use std::sync::Arc;
@@ -118,28 +120,205 @@ pub(crate) fn resampler_factory(
     }
 }
 
+/// The sample rates listed for devices which support a continuous range of rates
+const COMMON_SAMPLE_RATES: [u32; 9] = [
+    8_000, 16_000, 22_050, 24_000, 32_000, 44_100, 48_000, 96_000, 192_000,
+];
+
+/// A device with an identifier which, unlike its name, is unique among the listed devices
+#[derive(Clone)]
+pub(crate) struct IdentifiedDevice {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    pub(crate) device: Device,
+}
+
+/// Gives each device an identifier, its name when no other device shares it
+pub(crate) fn identify_devices(devices: impl Iterator<Item = Device>) -> Vec<IdentifiedDevice> {
+    let devices: Vec<(String, Device)> = devices
+        .filter_map(|device| Some((device.name().ok()?, device)))
+        .collect();
+
+    let mut names: HashMap<&str, usize> = HashMap::new();
+    for (name, _) in &devices {
+        *names.entry(name).or_default() += 1;
+    }
+
+    // only devices sharing a name are probed for their configurations
+    let ids: Vec<String> = devices
+        .iter()
+        .map(|(name, device)| match names[name.as_str()] {
+            1 => name.clone(),
+            _ => device_id(name, &device_configurations(device)),
+        })
+        .collect();
+
+    let mut seen: HashMap<String, usize> = HashMap::new();
+
+    devices
+        .into_iter()
+        .zip(ids)
+        .map(|((name, device), mut id)| {
+            // devices the host can not tell apart at all are numbered in the order it lists them
+            let duplicates = seen.entry(id.clone()).or_default();
+            if *duplicates > 0 {
+                id = format!("{}.{}", id, duplicates);
+            }
+            *duplicates += 1;
+
+            IdentifiedDevice { id, name, device }
+        })
+        .collect()
+}
+
+/// The configurations a device supports in both directions, sorted so that the order the host
+/// reports them in does not matter
+fn device_configurations(device: &Device) -> Vec<String> {
+    let describe = |config: SupportedStreamConfigRange| {
+        format!(
+            "{}:{}-{}:{:?}:{:?}",
+            config.channels(),
+            config.min_sample_rate().0,
+            config.max_sample_rate().0,
+            config.sample_format(),
+            config.buffer_size()
+        )
+    };
+
+    let inputs = device.supported_input_configs().into_iter().flatten();
+    let outputs = device.supported_output_configs().into_iter().flatten();
+    let mut configurations: Vec<String> = inputs.chain(outputs).map(describe).collect();
+    configurations.sort_unstable();
+    configurations
+}
+
+/// cpal 0.15 exposes no persistent device identifier, so devices sharing a name are told apart by
+/// a fingerprint of the configurations they support. Unlike a position in the device list, it stays
+/// the same when other devices sharing the name are added, removed or listed in another order
+fn device_id(name: &str, configurations: &[String]) -> String {
+    // FNV-1a, unlike the std hasher its output is the same across builds
+    let fingerprint = configurations
+        .iter()
+        .flat_map(|configuration| configuration.bytes().chain([b'\n']))
+        .fold(0x811c_9dc5_u32, |hash, byte| {
+            (hash ^ byte as u32).wrapping_mul(0x0100_0193)
+        });
+
+    format!("{}#{:08x}", name, fingerprint)
+}
+
+/// Finds the host's default device among the identified devices. When several devices share the
+/// default's name it is told apart by its configurations, failing that the first one is reported
+pub(crate) fn default_position(
+    devices: &[IdentifiedDevice],
+    default: Option<&Device>,
+) -> Option<usize> {
+    let default = default?;
+    let name = default.name().ok()?;
+
+    let id = match devices.iter().filter(|device| device.name == name).count() {
+        0 | 1 => name.clone(),
+        _ => device_id(&name, &device_configurations(default)),
+    };
+
+    devices
+        .iter()
+        .position(|device| device.id == id)
+        .or_else(|| devices.iter().position(|device| device.name == name))
+}
+
+/// Finds the selected device by its identifier, or by its name for selections saved before devices
+/// had identifiers. A device whose identifier changed, because the configurations it supports did,
+/// is found by the name in its identifier. Without a selection, or when the selected device is
+/// gone, the default is used
+pub(crate) fn select_device(
+    mut devices: Vec<IdentifiedDevice>,
+    selection: Option<&str>,
+    default: Option<Device>,
+) -> Option<IdentifiedDevice> {
+    let position = selection
+        .and_then(|selection| {
+            let position = devices
+                .iter()
+                .position(|device| device.id == selection)
+                .or_else(|| devices.iter().position(|device| device.name == selection));
+            if position.is_some() {
+                return position;
+            }
+
+            let name = selection
+                .rsplit_once('#')
+                .map_or(selection, |(name, _)| name);
+            let position = devices.iter().position(|device| device.name == name);
+            match position {
+                Some(position) => warn!(
+                    "device {} is not listed, using {} which has its name",
+                    selection, devices[position].id
+                ),
+                None => warn!("device {} is not listed, using the default", selection),
+            }
+
+            position
+        })
+        .or_else(|| default_position(&devices, default.as_ref()));
+
+    match position {
+        Some(position) => Some(devices.swap_remove(position)),
+        // a default device the host does not list
+        None => identify_devices(default.into_iter()).pop(),
+    }
+}
+
 /// Gets the output device
 pub(crate) async fn get_output_device(
     output_device: &DeviceName,
     host: &Arc<Host>,
-) -> Result<Device> {
-    match *output_device.lock().await {
-        Some(ref name) => Ok(host
-            .output_devices()?
-            .find(|device| {
-                if let Ok(ref device_name) = device.name() {
-                    name == device_name
-                } else {
-                    false
-                }
-            })
-            .unwrap_or(
-                host.default_output_device()
-                    .ok_or(ErrorKind::NoOutputDevice)?,
-            )),
-        None => host
-            .default_output_device()
-            .ok_or(ErrorKind::NoOutputDevice.into()),
+) -> Result<IdentifiedDevice> {
+    let selection = output_device.lock().await.clone();
+
+    select_device(
+        identify_devices(host.output_devices()?),
+        selection.as_deref(),
+        host.default_output_device(),
+    )
+    .ok_or(ErrorKind::NoOutputDevice.into())
+}
+
+/// Describes a device and the configurations it supports for the frontend
+pub(crate) fn device_info(
+    device: &IdentifiedDevice,
+    is_default: bool,
+    configs: impl Iterator<Item = SupportedStreamConfigRange>,
+) -> DeviceInfo {
+    let mut sample_rates = BTreeSet::new();
+    let mut channels = BTreeSet::new();
+    let mut min_buffer_size: Option<u32> = None;
+    let mut max_buffer_size: Option<u32> = None;
+
+    for config in configs {
+        let rates = config.min_sample_rate().0..=config.max_sample_rate().0;
+        sample_rates.extend([*rates.start(), *rates.end()]);
+        sample_rates.extend(
+            COMMON_SAMPLE_RATES
+                .into_iter()
+                .filter(|rate| rates.contains(rate)),
+        );
+        channels.insert(config.channels());
+
+        if let SupportedBufferSize::Range { min, max } = *config.buffer_size() {
+            min_buffer_size = Some(min_buffer_size.map_or(min, |size| size.min(min)));
+            max_buffer_size = Some(max_buffer_size.map_or(max, |size| size.max(max)));
+        }
+    }
+
+    DeviceInfo {
+        id: device.id.clone(),
+        name: device.name.clone(),
+        is_default,
+        sample_rates: sample_rates.into_iter().collect(),
+        channels: channels.into_iter().collect(),
+        min_buffer_size,
+        max_buffer_size,
     }
 }
 
@@ -254,6 +433,27 @@ pub(crate) mod rwlock_option_recording_config {
 
 #[cfg(test)]
 mod tests {
+    #[test]
+    fn device_ids_follow_configurations() {
+        let mono = ["1:8000-48000:F32:Unknown".to_string()];
+        let stereo = ["2:8000-48000:F32:Unknown".to_string()];
+
+        assert_eq!(
+            super::device_id("Mic", &mono),
+            super::device_id("Mic", &mono)
+        );
+        assert_ne!(
+            super::device_id("Mic", &mono),
+            super::device_id("Mic", &stereo)
+        );
+        assert_ne!(
+            super::device_id("Mic", &mono),
+            super::device_id("Headset", &mono)
+        );
+        // saved selections rely on the fingerprint staying the same across builds
+        assert_eq!(super::device_id("Mic", &[]), "Mic#811c9dc5");
+    }
+
     #[test]
     #[cfg(target_arch = "x86_64")]
     fn test_mul() {
@@ -0,0 +1,234 @@
+use atomic_float::AtomicF32;
+use std::str::FromStr;
+use std::sync::atomic::Ordering::Relaxed;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU8};
+use std::sync::Arc;
+
+/// quiet speech down to 12 dB below the rms threshold passes when rnnoise hears a voice
+const QUIET_SPEECH: f32 = 0.25;
+
+/// how the input processor decides which frames are sent
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) enum SilenceDetection {
+    /// frames above the rms threshold are speech
+    #[default]
+    Rms = 0,
+    /// frames rnnoise hears a voice in are speech, without denoising this falls back to rms
+    Vad = 1,
+    /// every frame is sent
+    Always = 2,
+}
+
+impl From<u8> for SilenceDetection {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Vad,
+            2 => Self::Always,
+            _ => Self::Rms,
+        }
+    }
+}
+
+impl FromStr for SilenceDetection {
+    type Err = ();
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "rms" => Ok(Self::Rms),
+            "vad" => Ok(Self::Vad),
+            "always" => Ok(Self::Always),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The silence detection settings, changes apply to the running input processor
+pub(crate) struct VadSettings {
+    /// a `SilenceDetection` as u8
+    pub(crate) mode: AtomicU8,
+    /// the voice probability from rnnoise above which a frame is speech
+    pub(crate) threshold: AtomicF32,
+    /// how long frames keep being sent after speech in milliseconds
+    pub(crate) hangover: AtomicU32,
+}
+
+impl Default for VadSettings {
+    fn default() -> Self {
+        Self {
+            mode: AtomicU8::new(SilenceDetection::default() as u8),
+            threshold: AtomicF32::new(0.6),
+            // matches the 80 frames of silence the input processor used to wait for
+            hangover: AtomicU32::new(800),
+        }
+    }
+}
+
+/// the decision for one frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Activity {
+    /// the frame itself holds speech
+    pub(crate) voice: bool,
+    /// the frame should be sent, it holds speech, follows speech within the hangover or
+    /// the user always transmits
+    pub(crate) transmit: bool,
+}
+
+/// Decides which input frames hold speech and holds the decision over short pauses
+pub(crate) struct VoiceActivity {
+    settings: Arc<VadSettings>,
+    /// the frames left in the current hangover
+    hangover: u32,
+    /// the length of a frame in milliseconds
+    frame_duration: f32,
+    /// whether the user is speaking for the statistics
+    speaking: Arc<AtomicBool>,
+}
+
+impl VoiceActivity {
+    pub(crate) fn new(
+        settings: Arc<VadSettings>,
+        speaking: Arc<AtomicBool>,
+        frame_size: usize,
+        sample_rate: f64,
+    ) -> Self {
+        Self {
+            settings,
+            hangover: 0,
+            frame_duration: (frame_size as f64 / sample_rate * 1_000_f64) as f32,
+            speaking,
+        }
+    }
+
+    /// classifies a frame from its rms and the voice probability rnnoise gave it, if denoising
+    pub(crate) fn detect(
+        &mut self,
+        rms: f32,
+        rms_threshold: f32,
+        probability: Option<f32>,
+    ) -> Activity {
+        let mode = SilenceDetection::from(self.settings.mode.load(Relaxed));
+
+        let voice = match (mode, probability) {
+            (SilenceDetection::Rms, _) | (_, None) => rms >= rms_threshold,
+            // energy keeps faint background voices out, the probability keeps loud noises out
+            (_, Some(probability)) => {
+                probability >= self.settings.threshold.load(Relaxed)
+                    && rms >= rms_threshold * QUIET_SPEECH
+            }
+        };
+
+        if voice {
+            let hangover = self.settings.hangover.load(Relaxed) as f32;
+            self.hangover = (hangover / self.frame_duration).ceil() as u32;
+        } else {
+            self.hangover = self.hangover.saturating_sub(1);
+        }
+
+        let speaking = voice || self.hangover > 0;
+        self.speaking.store(speaking, Relaxed);
+
+        Activity {
+            voice,
+            transmit: speaking || mode == SilenceDetection::Always,
+        }
+    }
+
+    /// nothing is being captured, the hangover ends
+    pub(crate) fn silence(&mut self) {
+        self.hangover = 0;
+        self.speaking.store(false, Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THRESHOLD: f32 = 100_f32;
+
+    fn detector(mode: SilenceDetection) -> (VoiceActivity, Arc<AtomicBool>) {
+        let settings = VadSettings::default();
+        settings.mode.store(mode as u8, Relaxed);
+        let speaking: Arc<AtomicBool> = Default::default();
+        // 10ms frames
+        let vad = VoiceActivity::new(Arc::new(settings), Arc::clone(&speaking), 480, 48_000_f64);
+        (vad, speaking)
+    }
+
+    #[test]
+    fn hangover_holds_pauses() {
+        let (mut vad, speaking) = detector(SilenceDetection::Rms);
+        vad.settings.hangover.store(200, Relaxed);
+
+        assert!(!vad.detect(10_f32, THRESHOLD, None).transmit);
+        assert!(vad.detect(200_f32, THRESHOLD, None).voice);
+
+        // 200ms of quiet frames are still sent
+        for _ in 0..19 {
+            let activity = vad.detect(10_f32, THRESHOLD, None);
+            assert!(!activity.voice && activity.transmit);
+            assert!(speaking.load(Relaxed));
+        }
+
+        assert!(!vad.detect(10_f32, THRESHOLD, None).transmit);
+        assert!(!speaking.load(Relaxed));
+
+        // muting ends the hangover right away
+        vad.detect(200_f32, THRESHOLD, None);
+        vad.silence();
+        assert!(!vad.detect(10_f32, THRESHOLD, None).transmit);
+    }
+
+    #[test]
+    fn vad_uses_voice_probability() {
+        let (mut vad, _) = detector(SilenceDetection::Vad);
+
+        // a loud keystroke rnnoise does not hear a voice in
+        assert!(!vad.detect(1_000_f32, THRESHOLD, Some(0.05)).voice);
+        // a quiet speaker below the rms threshold
+        assert!(vad.detect(40_f32, THRESHOLD, Some(0.9)).voice);
+        // faint voices in the background
+        assert!(!vad.detect(10_f32, THRESHOLD, Some(0.9)).voice);
+
+        // the rms mode ignores the probability
+        vad.settings
+            .mode
+            .store(SilenceDetection::Rms as u8, Relaxed);
+        assert!(vad.detect(1_000_f32, THRESHOLD, Some(0.05)).voice);
+        assert!(!vad.detect(40_f32, THRESHOLD, Some(0.9)).voice);
+
+        // without denoising the vad mode falls back to rms
+        vad.settings
+            .mode
+            .store(SilenceDetection::Vad as u8, Relaxed);
+        assert!(vad.detect(1_000_f32, THRESHOLD, None).voice);
+    }
+
+    #[test]
+    fn always_transmits() {
+        let (mut vad, speaking) = detector(SilenceDetection::Always);
+
+        let activity = vad.detect(0_f32, THRESHOLD, Some(0_f32));
+        assert!(activity.transmit && !activity.voice);
+        assert!(!speaking.load(Relaxed));
+
+        vad.detect(200_f32, THRESHOLD, Some(1_f32));
+        assert!(speaking.load(Relaxed));
+    }
+
+    #[test]
+    fn parses_modes() {
+        assert_eq!("vad".parse(), Ok(SilenceDetection::Vad));
+        assert_eq!("always".parse(), Ok(SilenceDetection::Always));
+        assert_eq!("rms".parse(), Ok(SilenceDetection::Rms));
+        assert_eq!("other".parse::<SilenceDetection>(), Err(()));
+
+        for mode in [
+            SilenceDetection::Rms,
+            SilenceDetection::Vad,
+            SilenceDetection::Always,
+        ] {
+            assert_eq!(SilenceDetection::from(mode as u8), mode);
+        }
+    }
+}
@@ -0,0 +1,525 @@
+use crate::api::error::{Error, ErrorKind};
+use std::io::{Seek, SeekFrom, Write};
+
+const WAVE_FORMAT_PCM: u16 = 1;
+const WAVE_FORMAT_IEEE_FLOAT: u16 = 3;
+const WAVE_FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+/// the speaker positions of WAVE_FORMAT_EXTENSIBLE channel masks
+const SPEAKER_FRONT_LEFT: u32 = 0x1;
+const SPEAKER_FRONT_RIGHT: u32 = 0x2;
+const SPEAKER_LOW_FREQUENCY: u32 = 0x8;
+const SPEAKER_BACK_LEFT: u32 = 0x10;
+const SPEAKER_BACK_RIGHT: u32 = 0x20;
+const SPEAKER_FRONT_LEFT_OF_CENTER: u32 = 0x40;
+const SPEAKER_FRONT_RIGHT_OF_CENTER: u32 = 0x80;
+const SPEAKER_SIDE_LEFT: u32 = 0x200;
+const SPEAKER_SIDE_RIGHT: u32 = 0x400;
+const SPEAKER_TOP_FRONT_LEFT: u32 = 0x1000;
+const SPEAKER_TOP_FRONT_RIGHT: u32 = 0x4000;
+const SPEAKER_TOP_BACK_LEFT: u32 = 0x8000;
+const SPEAKER_TOP_BACK_RIGHT: u32 = 0x20000;
+
+const LEFT_SPEAKERS: u32 = SPEAKER_FRONT_LEFT
+    | SPEAKER_BACK_LEFT
+    | SPEAKER_FRONT_LEFT_OF_CENTER
+    | SPEAKER_SIDE_LEFT
+    | SPEAKER_TOP_FRONT_LEFT
+    | SPEAKER_TOP_BACK_LEFT;
+const RIGHT_SPEAKERS: u32 = SPEAKER_FRONT_RIGHT
+    | SPEAKER_BACK_RIGHT
+    | SPEAKER_FRONT_RIGHT_OF_CENTER
+    | SPEAKER_SIDE_RIGHT
+    | SPEAKER_TOP_FRONT_RIGHT
+    | SPEAKER_TOP_BACK_RIGHT;
+
+/// the RIFF header, a 16 byte fmt chunk and the data chunk header
+const HEADER_LEN: u32 = 44;
+
+/// centered speakers feed both sides at -3dB
+const CENTER_GAIN: f32 = std::f32::consts::FRAC_1_SQRT_2;
+
+/// the sample encodings found in wav files
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum WavFormat {
+    U8,
+    I16,
+    I24,
+    I32,
+    F32,
+    F64,
+}
+
+impl WavFormat {
+    fn new(format_tag: u16, bits_per_sample: u16) -> Result<Self, Error> {
+        match (format_tag, bits_per_sample) {
+            (WAVE_FORMAT_PCM, 8) => Ok(Self::U8),
+            (WAVE_FORMAT_PCM, 16) => Ok(Self::I16),
+            (WAVE_FORMAT_PCM, 24) => Ok(Self::I24),
+            (WAVE_FORMAT_PCM, 32) => Ok(Self::I32),
+            (WAVE_FORMAT_IEEE_FLOAT, 32) => Ok(Self::F32),
+            (WAVE_FORMAT_IEEE_FLOAT, 64) => Ok(Self::F64),
+            _ => Err(ErrorKind::UnknownSampleFormat.into()),
+        }
+    }
+
+    /// the size of one sample in bytes
+    pub(crate) fn sample_size(&self) -> usize {
+        match self {
+            Self::U8 => 1,
+            Self::I16 => 2,
+            Self::I24 => 3,
+            Self::I32 | Self::F32 => 4,
+            Self::F64 => 8,
+        }
+    }
+
+    /// converts one little endian sample to a float in -1..1
+    fn to_f32(self, bytes: &[u8]) -> f32 {
+        match self {
+            Self::U8 => (bytes[0] as f32 - 128.0) / 128.0,
+            Self::I16 => i16::from_le_bytes([bytes[0], bytes[1]]) as f32 / 32_768.0,
+            // shifting the 24 bits to the top of an i32 sign extends them
+            Self::I24 => {
+                (i32::from_le_bytes([0, bytes[0], bytes[1], bytes[2]]) >> 8) as f32 / 8_388_608.0
+            }
+            Self::I32 => {
+                i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f32
+                    / 2_147_483_648.0
+            }
+            Self::F32 => f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            Self::F64 => f64::from_le_bytes(bytes[..8].try_into().unwrap()) as f32,
+        }
+    }
+}
+
+/// a parsed RIFF/WAVE file borrowing its sample data
+#[derive(Debug)]
+pub(crate) struct Wav<'a> {
+    pub(crate) format: WavFormat,
+    pub(crate) channels: usize,
+    pub(crate) sample_rate: u32,
+    /// the speaker position of every channel
+    channel_mask: u32,
+    /// interleaved little endian samples, trimmed to whole frames
+    data: &'a [u8],
+}
+
+impl<'a> Wav<'a> {
+    /// true when the bytes start like a wav file
+    pub(crate) fn detect(bytes: &[u8]) -> bool {
+        bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WAVE"
+    }
+
+    /// walks the chunks of a wav file, skipping everything but `fmt ` and `data`
+    pub(crate) fn parse(bytes: &'a [u8]) -> Result<Self, Error> {
+        if !Self::detect(bytes) {
+            return Err(ErrorKind::InvalidWav.into());
+        }
+
+        let mut format = None;
+        let mut data = None;
+        let mut position = 12;
+
+        while position + 8 <= bytes.len() {
+            let id = &bytes[position..position + 4];
+            let len = u32::from_le_bytes(bytes[position + 4..position + 8].try_into()?) as usize;
+            let start = position + 8;
+            // streaming writers leave the size of the last chunk unset or too large
+            let end = start.saturating_add(len).min(bytes.len());
+
+            match id {
+                b"fmt " => format = Some(&bytes[start..end]),
+                b"data" => data = Some(&bytes[start..end]),
+                _ => (), // LIST, fact, cue and friends carry nothing needed for playback
+            }
+
+            // chunks are padded to an even length
+            position = end.saturating_add(len % 2);
+        }
+
+        let (Some(format), Some(data)) = (format, data) else {
+            return Err(ErrorKind::InvalidWav.into());
+        };
+
+        if format.len() < 16 {
+            return Err(ErrorKind::InvalidWav.into());
+        }
+
+        let mut format_tag = u16::from_le_bytes([format[0], format[1]]);
+        let channels = u16::from_le_bytes([format[2], format[3]]) as usize;
+        let sample_rate = u32::from_le_bytes(format[4..8].try_into()?);
+        let bits_per_sample = u16::from_le_bytes([format[14], format[15]]);
+        let mut channel_mask = 0;
+
+        if format_tag == WAVE_FORMAT_EXTENSIBLE {
+            // cbSize, valid bits, channel mask, then a sub format guid starting with the format tag
+            if format.len() < 40 {
+                return Err(ErrorKind::InvalidWav.into());
+            }
+
+            channel_mask = u32::from_le_bytes(format[20..24].try_into()?);
+            format_tag = u16::from_le_bytes([format[24], format[25]]);
+        }
+
+        if channels == 0 || sample_rate == 0 {
+            return Err(ErrorKind::InvalidWav.into());
+        }
+
+        // without a mask the channels take the speaker positions in their standard order
+        if channel_mask.count_ones() as usize != channels {
+            channel_mask = default_channel_mask(channels);
+        }
+
+        let format = WavFormat::new(format_tag, bits_per_sample)?;
+        let frame_size = format.sample_size() * channels;
+
+        Ok(Self {
+            format,
+            channels,
+            sample_rate,
+            channel_mask,
+            data: &data[..data.len() - data.len() % frame_size],
+        })
+    }
+
+    /// the interleaved samples as floats in -1..1
+    pub(crate) fn samples(&self) -> Vec<f32> {
+        self.data
+            .chunks_exact(self.format.sample_size())
+            .map(|sample| self.format.to_f32(sample))
+            .collect()
+    }
+
+    /// the samples mixed down to mono or stereo, mono and stereo files are left as they are
+    pub(crate) fn stereo_samples(&self) -> (Vec<f32>, usize) {
+        let samples = self.samples();
+
+        if self.channels <= 2 {
+            (samples, self.channels)
+        } else {
+            (downmix(&samples, self.channels, self.channel_mask), 2)
+        }
+    }
+}
+
+/// the speaker positions of the first `channels` bits in their standard order
+pub(crate) fn default_channel_mask(channels: usize) -> u32 {
+    if channels >= 32 {
+        u32::MAX
+    } else {
+        (1 << channels) - 1
+    }
+}
+
+/// mixes interleaved samples with the speaker positions in `channel_mask` down to stereo
+/// left and right speakers feed their side, centered speakers both, the low frequency channel is dropped
+pub(crate) fn downmix(samples: &[f32], channels: usize, channel_mask: u32) -> Vec<f32> {
+    let mut speakers = (0..32)
+        .map(|bit| 1 << bit)
+        .filter(|speaker| channel_mask & speaker != 0);
+
+    // the weights of every channel on the left and right side
+    let weights: Vec<(f32, f32)> = (0..channels)
+        .map(|_| match speakers.next() {
+            Some(speaker) if speaker & LEFT_SPEAKERS != 0 => (1.0, 0.0),
+            Some(speaker) if speaker & RIGHT_SPEAKERS != 0 => (0.0, 1.0),
+            Some(SPEAKER_LOW_FREQUENCY) => (0.0, 0.0),
+            // centered and unknown positions are spread evenly
+            _ => (CENTER_GAIN, CENTER_GAIN),
+        })
+        .collect();
+
+    // normalizing by the total weight keeps a full scale signal on every channel from clipping
+    let (left_total, right_total) = weights
+        .iter()
+        .fold((0.0, 0.0), |(l, r), (wl, wr)| (l + wl, r + wr));
+    let left_scale = if left_total > 0.0 {
+        1.0 / left_total
+    } else {
+        0.0
+    };
+    let right_scale = if right_total > 0.0 {
+        1.0 / right_total
+    } else {
+        0.0
+    };
+
+    samples
+        .chunks_exact(channels)
+        .flat_map(|frame| {
+            let (left, right) = frame
+                .iter()
+                .zip(&weights)
+                .fold((0.0, 0.0), |(l, r), (sample, (wl, wr))| {
+                    (l + sample * wl, r + sample * wr)
+                });
+
+            [left * left_scale, right * right_scale]
+        })
+        .collect()
+}
+
+/// converts float samples in -1..1 to 16 bit with rounding and clipping
+pub(crate) fn to_i16(samples: &[f32]) -> Vec<i16> {
+    samples
+        .iter()
+        .map(|sample| {
+            (sample * 32_768.0)
+                .round()
+                .clamp(i16::MIN as f32, i16::MAX as f32) as i16
+        })
+        .collect()
+}
+
+/// writes 16 bit pcm to a wav file as it arrives, the chunk sizes are filled in by `finish`
+pub(crate) struct WavWriter<W: Write + Seek> {
+    inner: W,
+    /// the bytes of sample data written so far
+    data_len: u32,
+}
+
+impl<W: Write + Seek> WavWriter<W> {
+    /// writes the header at the start of `inner`
+    pub(crate) fn new(mut inner: W, channels: u16, sample_rate: u32) -> Result<Self, Error> {
+        let block_align = channels * 2;
+
+        let mut header = Vec::with_capacity(HEADER_LEN as usize);
+        header.extend_from_slice(b"RIFF");
+        header.extend_from_slice(&(HEADER_LEN - 8).to_le_bytes());
+        header.extend_from_slice(b"WAVEfmt ");
+        header.extend_from_slice(&16_u32.to_le_bytes());
+        header.extend_from_slice(&WAVE_FORMAT_PCM.to_le_bytes());
+        header.extend_from_slice(&channels.to_le_bytes());
+        header.extend_from_slice(&sample_rate.to_le_bytes());
+        header.extend_from_slice(&(sample_rate * block_align as u32).to_le_bytes());
+        header.extend_from_slice(&block_align.to_le_bytes());
+        header.extend_from_slice(&16_u16.to_le_bytes());
+        header.extend_from_slice(b"data");
+        header.extend_from_slice(&0_u32.to_le_bytes());
+        inner.write_all(&header)?;
+
+        Ok(Self { inner, data_len: 0 })
+    }
+
+    /// writes interleaved samples
+    pub(crate) fn write_samples(&mut self, samples: &[i16]) -> Result<(), Error> {
+        let bytes: Vec<u8> = samples.iter().flat_map(|s| s.to_le_bytes()).collect();
+        self.inner.write_all(&bytes)?;
+        // a file over 4GB keeps the largest size readers accept
+        self.data_len = self.data_len.saturating_add(bytes.len() as u32);
+        Ok(())
+    }
+
+    /// fills in the chunk sizes and returns the inner writer
+    pub(crate) fn finish(mut self) -> Result<W, Error> {
+        let riff_len = (HEADER_LEN - 8).saturating_add(self.data_len);
+
+        self.inner.seek(SeekFrom::Start(4))?;
+        self.inner.write_all(&riff_len.to_le_bytes())?;
+        self.inner.seek(SeekFrom::Start(HEADER_LEN as u64 - 4))?;
+        self.inner.write_all(&self.data_len.to_le_bytes())?;
+        self.inner.seek(SeekFrom::End(0))?;
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// builds a wav file from a fmt chunk body, sample data and extra chunks placed before the data
+    fn wav_bytes(format: &[u8], data: &[u8], extra: &[(&[u8; 4], &[u8])]) -> Vec<u8> {
+        let mut chunks = Vec::new();
+
+        let mut push_chunk = |id: &[u8; 4], body: &[u8]| {
+            chunks.extend_from_slice(id);
+            chunks.extend_from_slice(&(body.len() as u32).to_le_bytes());
+            chunks.extend_from_slice(body);
+            if body.len() % 2 == 1 {
+                chunks.push(0);
+            }
+        };
+
+        for (id, body) in extra {
+            push_chunk(id, body);
+        }
+        push_chunk(b"fmt ", format);
+        push_chunk(b"data", data);
+
+        let mut bytes = b"RIFF".to_vec();
+        bytes.extend_from_slice(&(chunks.len() as u32 + 4).to_le_bytes());
+        bytes.extend_from_slice(b"WAVE");
+        bytes.extend_from_slice(&chunks);
+        bytes
+    }
+
+    fn format_chunk(format_tag: u16, channels: u16, bits_per_sample: u16) -> Vec<u8> {
+        let block_align = channels * bits_per_sample / 8;
+        let mut format = Vec::new();
+        format.extend_from_slice(&format_tag.to_le_bytes());
+        format.extend_from_slice(&channels.to_le_bytes());
+        format.extend_from_slice(&44_100_u32.to_le_bytes());
+        format.extend_from_slice(&(44_100 * block_align as u32).to_le_bytes());
+        format.extend_from_slice(&block_align.to_le_bytes());
+        format.extend_from_slice(&bits_per_sample.to_le_bytes());
+        format
+    }
+
+    fn extensible_chunk(sub_format: u16, channels: u16, bits: u16, mask: u32) -> Vec<u8> {
+        let mut format = format_chunk(WAVE_FORMAT_EXTENSIBLE, channels, bits);
+        format.extend_from_slice(&22_u16.to_le_bytes());
+        format.extend_from_slice(&bits.to_le_bytes());
+        format.extend_from_slice(&mask.to_le_bytes());
+        format.extend_from_slice(&sub_format.to_le_bytes());
+        // the rest of the KSDATAFORMAT_SUBTYPE guid
+        format.extend_from_slice(&[
+            0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xAA, 0x00, 0x38, 0x9B, 0x71,
+        ]);
+        format
+    }
+
+    #[test]
+    fn sample_formats() {
+        let cases: [(u16, u16, Vec<u8>); 6] = [
+            (WAVE_FORMAT_PCM, 8, vec![0, 128, 192]),
+            (
+                WAVE_FORMAT_PCM,
+                16,
+                [i16::MIN, 0, 16_384]
+                    .iter()
+                    .flat_map(|s| s.to_le_bytes())
+                    .collect(),
+            ),
+            (
+                WAVE_FORMAT_PCM,
+                24,
+                vec![0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40],
+            ),
+            (
+                WAVE_FORMAT_PCM,
+                32,
+                [i32::MIN, 0, 1 << 30]
+                    .iter()
+                    .flat_map(|s| s.to_le_bytes())
+                    .collect(),
+            ),
+            (
+                WAVE_FORMAT_IEEE_FLOAT,
+                32,
+                [-1_f32, 0.0, 0.5]
+                    .iter()
+                    .flat_map(|s| s.to_le_bytes())
+                    .collect(),
+            ),
+            (
+                WAVE_FORMAT_IEEE_FLOAT,
+                64,
+                [-1_f64, 0.0, 0.5]
+                    .iter()
+                    .flat_map(|s| s.to_le_bytes())
+                    .collect(),
+            ),
+        ];
+
+        for (format_tag, bits, data) in cases {
+            let bytes = wav_bytes(&format_chunk(format_tag, 1, bits), &data, &[]);
+            let wav = Wav::parse(&bytes).unwrap();
+
+            assert_eq!(
+                wav.samples(),
+                vec![-1.0, 0.0, 0.5],
+                "{bits} bit format {format_tag}"
+            );
+        }
+    }
+
+    #[test]
+    fn skips_other_chunks() {
+        let data: Vec<u8> = [1_i16, 2, 3, 4]
+            .iter()
+            .flat_map(|s| s.to_le_bytes())
+            .collect();
+        // an odd sized LIST chunk is padded, a fact chunk comes before the data as in float files
+        let bytes = wav_bytes(
+            &format_chunk(WAVE_FORMAT_PCM, 2, 16),
+            &data,
+            &[
+                (b"LIST", b"INFOISFT\x03\x00\x00\x00DAW"),
+                (b"fact", &[2, 0, 0, 0]),
+            ],
+        );
+
+        let wav = Wav::parse(&bytes).unwrap();
+        assert_eq!(wav.channels, 2);
+        assert_eq!(wav.sample_rate, 44_100);
+        assert_eq!(wav.samples().len(), 4);
+        assert_eq!(to_i16(&wav.samples()), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn writes_pcm() {
+        let mut writer = WavWriter::new(std::io::Cursor::new(Vec::new()), 2, 48_000).unwrap();
+        writer.write_samples(&[1, -1, 2]).unwrap();
+        writer.write_samples(&[-2]).unwrap();
+        let bytes = writer.finish().unwrap().into_inner();
+
+        assert_eq!(bytes.len(), HEADER_LEN as usize + 8);
+        assert_eq!(u32::from_le_bytes(bytes[4..8].try_into().unwrap()), 44);
+
+        let wav = Wav::parse(&bytes).unwrap();
+        assert_eq!(wav.format, WavFormat::I16);
+        assert_eq!(wav.channels, 2);
+        assert_eq!(wav.sample_rate, 48_000);
+        assert_eq!(to_i16(&wav.samples()), vec![1, -1, 2, -2]);
+    }
+
+    #[test]
+    fn extensible() {
+        let data: Vec<u8> = [0.25_f32, -0.25]
+            .iter()
+            .flat_map(|s| s.to_le_bytes())
+            .collect();
+        let format = extensible_chunk(WAVE_FORMAT_IEEE_FLOAT, 2, 32, 0x3);
+        let bytes = wav_bytes(&format, &data, &[]);
+
+        let wav = Wav::parse(&bytes).unwrap();
+        assert_eq!(wav.format, WavFormat::F32);
+        assert_eq!(wav.samples(), vec![0.25, -0.25]);
+    }
+
+    #[test]
+    fn downmixes_surround() {
+        // 5.1 with only the center channel carrying signal
+        let mask = 0x3F;
+        let frame = [0_f32, 0.0, 1.0, 0.0, 0.0, 0.0];
+        let data: Vec<u8> = frame.iter().flat_map(|s| s.to_le_bytes()).collect();
+        let bytes = wav_bytes(
+            &extensible_chunk(WAVE_FORMAT_IEEE_FLOAT, 6, 32, mask),
+            &data,
+            &[],
+        );
+
+        let wav = Wav::parse(&bytes).unwrap();
+        let (samples, channels) = wav.stereo_samples();
+        assert_eq!(channels, 2);
+        assert_eq!(samples.len(), 2);
+        assert!((samples[0] - samples[1]).abs() < f32::EPSILON);
+        assert!(samples[0] > 0.0 && samples[0] < 1.0);
+
+        // full scale on every channel must not clip
+        let loud = downmix(&[1.0; 6], 6, mask);
+        assert!(loud.iter().all(|sample| (sample - 1.0).abs() < 1e-6));
+    }
+
+    #[test]
+    fn rejects_invalid_files() {
+        assert!(Wav::parse(b"RIFF\x04\x00\x00\x00WAVE").is_err());
+        assert!(Wav::parse(b"not a wav file at all").is_err());
+
+        let bytes = wav_bytes(&format_chunk(WAVE_FORMAT_PCM, 1, 12), &[0, 0], &[]);
+        assert!(Wav::parse(&bytes).is_err());
+    }
+}
@@ -41,7 +41,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.10.0";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 617117851;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 984467050;
 
 // Section: executor
 
@@ -468,6 +468,53 @@ fn wire__crate__api__telepathy__ChatMessage_time_impl(
         },
     )
 }
+fn wire__crate__api__telepathy__CodecConfig_fec_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "CodecConfig_fec",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CodecConfig>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::telepathy::CodecConfig::fec(&*api_that_guard))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__telepathy__CodecConfig_new_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -553,6 +600,55 @@ fn wire__crate__api__telepathy__CodecConfig_set_enabled_impl(
         },
     )
 }
+fn wire__crate__api__telepathy__CodecConfig_set_fec_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "CodecConfig_set_fec",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CodecConfig>,
+            >>::sse_decode(&mut deserializer);
+            let api_fec = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::telepathy::CodecConfig::set_fec(&*api_that_guard, api_fec);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__telepathy__CodecConfig_set_residual_bits_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2982,6 +3078,54 @@ fn wire__crate__api__telepathy__Telepathy_end_call_impl(
         },
     )
 }
+fn wire__crate__api__telepathy__Telepathy_is_recording_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_is_recording",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::telepathy::Telepathy::is_recording(&*api_that_guard),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__telepathy__Telepathy_join_room_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -3042,6 +3186,56 @@ fn wire__crate__api__telepathy__Telepathy_join_room_impl(
         },
     )
 }
+fn wire__crate__api__telepathy__Telepathy_list_device_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_list_device_info",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::DartError>((move || {
+                    let mut api_that_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_that, 0, false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_that_guard = api_that_guard.unwrap();
+                    let output_ok =
+                        crate::api::telepathy::Telepathy::list_device_info(&*api_that_guard)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__telepathy__Telepathy_list_devices_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -3140,6 +3334,54 @@ let api_codec_config_guard = api_codec_config_guard.unwrap();
                     })().await)
                 } })
 }
+fn wire__crate__api__telepathy__Telepathy_opened_audio_configs_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_opened_audio_configs",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::telepathy::Telepathy::opened_audio_configs(&*api_that_guard),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__telepathy__Telepathy_restart_manager_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -3272,14 +3514,14 @@ fn wire__crate__api__telepathy__Telepathy_send_chat_impl(
         },
     )
 }
-fn wire__crate__api__telepathy__Telepathy_set_deafened_impl(
+fn wire__crate__api__telepathy__Telepathy_set_agc_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Telepathy_set_deafened",
+            debug_name: "Telepathy_set_agc",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3296,7 +3538,7 @@ fn wire__crate__api__telepathy__Telepathy_set_deafened_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
             >>::sse_decode(&mut deserializer);
-            let api_deafened = <bool>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
                 let mut api_that_guard = None;
@@ -3314,21 +3556,21 @@ fn wire__crate__api__telepathy__Telepathy_set_deafened_impl(
                 }
                 let api_that_guard = api_that_guard.unwrap();
                 let output_ok = Result::<_, ()>::Ok({
-                    crate::api::telepathy::Telepathy::set_deafened(&*api_that_guard, api_deafened);
+                    crate::api::telepathy::Telepathy::set_agc(&*api_that_guard, api_enabled);
                 })?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__telepathy__Telepathy_set_denoise_impl(
+fn wire__crate__api__telepathy__Telepathy_set_agc_attack_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Telepathy_set_denoise",
+            debug_name: "Telepathy_set_agc_attack",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3345,7 +3587,7 @@ fn wire__crate__api__telepathy__Telepathy_set_denoise_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
             >>::sse_decode(&mut deserializer);
-            let api_denoise = <bool>::sse_decode(&mut deserializer);
+            let api_milliseconds = <f32>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
                 let mut api_that_guard = None;
@@ -3363,21 +3605,24 @@ fn wire__crate__api__telepathy__Telepathy_set_denoise_impl(
                 }
                 let api_that_guard = api_that_guard.unwrap();
                 let output_ok = Result::<_, ()>::Ok({
-                    crate::api::telepathy::Telepathy::set_denoise(&*api_that_guard, api_denoise);
+                    crate::api::telepathy::Telepathy::set_agc_attack(
+                        &*api_that_guard,
+                        api_milliseconds,
+                    );
                 })?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__telepathy__Telepathy_set_efficiency_mode_impl(
+fn wire__crate__api__telepathy__Telepathy_set_agc_max_gain_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Telepathy_set_efficiency_mode",
+            debug_name: "Telepathy_set_agc_max_gain",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3394,7 +3639,7 @@ fn wire__crate__api__telepathy__Telepathy_set_efficiency_mode_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
             >>::sse_decode(&mut deserializer);
-            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            let api_decibel = <f32>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
                 let mut api_that_guard = None;
@@ -3412,9 +3657,9 @@ fn wire__crate__api__telepathy__Telepathy_set_efficiency_mode_impl(
                 }
                 let api_that_guard = api_that_guard.unwrap();
                 let output_ok = Result::<_, ()>::Ok({
-                    crate::api::telepathy::Telepathy::set_efficiency_mode(
+                    crate::api::telepathy::Telepathy::set_agc_max_gain(
                         &*api_that_guard,
-                        api_enabled,
+                        api_decibel,
                     );
                 })?;
                 Ok(output_ok)
@@ -3422,17 +3667,16 @@ fn wire__crate__api__telepathy__Telepathy_set_efficiency_mode_impl(
         },
     )
 }
-fn wire__crate__api__telepathy__Telepathy_set_identity_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__telepathy__Telepathy_set_agc_release_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Telepathy_set_identity",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "Telepathy_set_agc_release",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -3447,7 +3691,654 @@ fn wire__crate__api__telepathy__Telepathy_set_identity_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
             >>::sse_decode(&mut deserializer);
-            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_milliseconds = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::telepathy::Telepathy::set_agc_release(
+                        &*api_that_guard,
+                        api_milliseconds,
+                    );
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__telepathy__Telepathy_set_agc_target_level_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_set_agc_target_level",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_decibel = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::telepathy::Telepathy::set_agc_target_level(
+                        &*api_that_guard,
+                        api_decibel,
+                    );
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__telepathy__Telepathy_set_buffer_size_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_set_buffer_size",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_frames = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::telepathy::Telepathy::set_buffer_size(&*api_that_guard, api_frames);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__telepathy__Telepathy_set_comfort_noise_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_set_comfort_noise",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_comfort_noise = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::telepathy::Telepathy::set_comfort_noise(
+                        &*api_that_guard,
+                        api_comfort_noise,
+                    );
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__telepathy__Telepathy_set_deafened_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_set_deafened",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_deafened = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::telepathy::Telepathy::set_deafened(&*api_that_guard, api_deafened);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__telepathy__Telepathy_set_denoise_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_set_denoise",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_denoise = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::telepathy::Telepathy::set_denoise(&*api_that_guard, api_denoise);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__telepathy__Telepathy_set_devices_changed_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_set_devices_changed",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_devices_changed =
+                decode_DartFn_Inputs_list_device_info_list_device_info_Output_unit_AnyhowException(
+                    <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+                );
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::telepathy::Telepathy::set_devices_changed(
+                                &*api_that_guard,
+                                api_devices_changed,
+                            )
+                            .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__telepathy__Telepathy_set_echo_cancellation_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_set_echo_cancellation",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_echo_cancellation = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::telepathy::Telepathy::set_echo_cancellation(
+                        &*api_that_guard,
+                        api_echo_cancellation,
+                    );
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__telepathy__Telepathy_set_efficiency_mode_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_set_efficiency_mode",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::telepathy::Telepathy::set_efficiency_mode(
+                        &*api_that_guard,
+                        api_enabled,
+                    );
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__telepathy__Telepathy_set_identity_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_set_identity",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::DartError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::api::telepathy::Telepathy::set_identity(
+                            &*api_that_guard,
+                            api_key,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__telepathy__Telepathy_set_input_device_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_set_input_device",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_device = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::telepathy::Telepathy::set_input_device(
+                                &*api_that_guard,
+                                api_device,
+                            )
+                            .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__telepathy__Telepathy_set_input_volume_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_set_input_volume",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_decibel = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::telepathy::Telepathy::set_input_volume(
+                        &*api_that_guard,
+                        api_decibel,
+                    );
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__telepathy__Telepathy_set_model_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_set_model",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_model = <Option<Vec<u8>>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::DartError>(
@@ -3469,9 +4360,9 @@ fn wire__crate__api__telepathy__Telepathy_set_identity_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::api::telepathy::Telepathy::set_identity(
+                        let output_ok = crate::api::telepathy::Telepathy::set_model(
                             &*api_that_guard,
-                            api_key,
+                            api_model,
                         )
                         .await?;
                         Ok(output_ok)
@@ -3482,7 +4373,56 @@ fn wire__crate__api__telepathy__Telepathy_set_identity_impl(
         },
     )
 }
-fn wire__crate__api__telepathy__Telepathy_set_input_device_impl(
+fn wire__crate__api__telepathy__Telepathy_set_muted_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_set_muted",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_muted = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::telepathy::Telepathy::set_muted(&*api_that_guard, api_muted);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__telepathy__Telepathy_set_output_device_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3490,7 +4430,7 @@ fn wire__crate__api__telepathy__Telepathy_set_input_device_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Telepathy_set_input_device",
+            debug_name: "Telepathy_set_output_device",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3530,7 +4470,7 @@ fn wire__crate__api__telepathy__Telepathy_set_input_device_impl(
                         }
                         let api_that_guard = api_that_guard.unwrap();
                         let output_ok = Result::<_, ()>::Ok({
-                            crate::api::telepathy::Telepathy::set_input_device(
+                            crate::api::telepathy::Telepathy::set_output_device(
                                 &*api_that_guard,
                                 api_device,
                             )
@@ -3544,14 +4484,14 @@ fn wire__crate__api__telepathy__Telepathy_set_input_device_impl(
         },
     )
 }
-fn wire__crate__api__telepathy__Telepathy_set_input_volume_impl(
+fn wire__crate__api__telepathy__Telepathy_set_output_volume_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Telepathy_set_input_volume",
+            debug_name: "Telepathy_set_output_volume",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3586,7 +4526,7 @@ fn wire__crate__api__telepathy__Telepathy_set_input_volume_impl(
                 }
                 let api_that_guard = api_that_guard.unwrap();
                 let output_ok = Result::<_, ()>::Ok({
-                    crate::api::telepathy::Telepathy::set_input_volume(
+                    crate::api::telepathy::Telepathy::set_output_volume(
                         &*api_that_guard,
                         api_decibel,
                     );
@@ -3596,7 +4536,163 @@ fn wire__crate__api__telepathy__Telepathy_set_input_volume_impl(
         },
     )
 }
-fn wire__crate__api__telepathy__Telepathy_set_model_impl(
+fn wire__crate__api__telepathy__Telepathy_set_play_custom_ringtones_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_set_play_custom_ringtones",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_play = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::telepathy::Telepathy::set_play_custom_ringtones(
+                        &*api_that_guard,
+                        api_play,
+                    );
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__telepathy__Telepathy_set_preferred_channels_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_set_preferred_channels",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_channels = <Option<u16>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::telepathy::Telepathy::set_preferred_channels(
+                        &*api_that_guard,
+                        api_channels,
+                    );
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__telepathy__Telepathy_set_preferred_sample_rate_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_set_preferred_sample_rate",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_sample_rate = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::telepathy::Telepathy::set_preferred_sample_rate(
+                        &*api_that_guard,
+                        api_sample_rate,
+                    );
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__telepathy__Telepathy_set_recording_changed_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3604,7 +4700,7 @@ fn wire__crate__api__telepathy__Telepathy_set_model_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Telepathy_set_model",
+            debug_name: "Telepathy_set_recording_changed",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3621,10 +4717,13 @@ fn wire__crate__api__telepathy__Telepathy_set_model_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
             >>::sse_decode(&mut deserializer);
-            let api_model = <Option<Vec<u8>>>::sse_decode(&mut deserializer);
+            let api_recording_changed =
+                decode_DartFn_Inputs_String_bool_Output_unit_AnyhowException(
+                    <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+                );
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, crate::api::error::DartError>(
+                transform_result_sse::<_, ()>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -3643,11 +4742,13 @@ fn wire__crate__api__telepathy__Telepathy_set_model_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::api::telepathy::Telepathy::set_model(
-                            &*api_that_guard,
-                            api_model,
-                        )
-                        .await?;
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::telepathy::Telepathy::set_recording_changed(
+                                &*api_that_guard,
+                                api_recording_changed,
+                            )
+                            .await;
+                        })?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3656,14 +4757,14 @@ fn wire__crate__api__telepathy__Telepathy_set_model_impl(
         },
     )
 }
-fn wire__crate__api__telepathy__Telepathy_set_muted_impl(
+fn wire__crate__api__telepathy__Telepathy_set_rms_threshold_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Telepathy_set_muted",
+            debug_name: "Telepathy_set_rms_threshold",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3680,7 +4781,7 @@ fn wire__crate__api__telepathy__Telepathy_set_muted_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
             >>::sse_decode(&mut deserializer);
-            let api_muted = <bool>::sse_decode(&mut deserializer);
+            let api_decimal = <f32>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
                 let mut api_that_guard = None;
@@ -3698,24 +4799,26 @@ fn wire__crate__api__telepathy__Telepathy_set_muted_impl(
                 }
                 let api_that_guard = api_that_guard.unwrap();
                 let output_ok = Result::<_, ()>::Ok({
-                    crate::api::telepathy::Telepathy::set_muted(&*api_that_guard, api_muted);
+                    crate::api::telepathy::Telepathy::set_rms_threshold(
+                        &*api_that_guard,
+                        api_decimal,
+                    );
                 })?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__telepathy__Telepathy_set_output_device_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__telepathy__Telepathy_set_send_custom_ringtone_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Telepathy_set_output_device",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "Telepathy_set_send_custom_ringtone",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -3730,51 +4833,42 @@ fn wire__crate__api__telepathy__Telepathy_set_output_device_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
             >>::sse_decode(&mut deserializer);
-            let api_device = <Option<String>>::sse_decode(&mut deserializer);
+            let api_send = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let mut api_that_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, false,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => {
-                                    api_that_guard =
-                                        Some(api_that.lockable_decode_async_ref().await)
-                                }
-                                _ => unreachable!(),
-                            }
-                        }
-                        let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = Result::<_, ()>::Ok({
-                            crate::api::telepathy::Telepathy::set_output_device(
-                                &*api_that_guard,
-                                api_device,
-                            )
-                            .await;
-                        })?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::telepathy::Telepathy::set_send_custom_ringtone(
+                        &*api_that_guard,
+                        api_send,
+                    );
+                })?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__telepathy__Telepathy_set_output_volume_impl(
+fn wire__crate__api__telepathy__Telepathy_set_silence_detection_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Telepathy_set_output_volume",
+            debug_name: "Telepathy_set_silence_detection",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3791,9 +4885,9 @@ fn wire__crate__api__telepathy__Telepathy_set_output_volume_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
             >>::sse_decode(&mut deserializer);
-            let api_decibel = <f32>::sse_decode(&mut deserializer);
+            let api_mode = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
+            transform_result_sse::<_, crate::api::error::DartError>((move || {
                 let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -3808,25 +4902,23 @@ fn wire__crate__api__telepathy__Telepathy_set_output_volume_impl(
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok({
-                    crate::api::telepathy::Telepathy::set_output_volume(
-                        &*api_that_guard,
-                        api_decibel,
-                    );
-                })?;
+                let output_ok = crate::api::telepathy::Telepathy::set_silence_detection(
+                    &*api_that_guard,
+                    api_mode,
+                )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__telepathy__Telepathy_set_play_custom_ringtones_impl(
+fn wire__crate__api__telepathy__Telepathy_set_stereo_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Telepathy_set_play_custom_ringtones",
+            debug_name: "Telepathy_set_stereo",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3843,7 +4935,7 @@ fn wire__crate__api__telepathy__Telepathy_set_play_custom_ringtones_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
             >>::sse_decode(&mut deserializer);
-            let api_play = <bool>::sse_decode(&mut deserializer);
+            let api_stereo = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
                 let mut api_that_guard = None;
@@ -3861,24 +4953,21 @@ fn wire__crate__api__telepathy__Telepathy_set_play_custom_ringtones_impl(
                 }
                 let api_that_guard = api_that_guard.unwrap();
                 let output_ok = Result::<_, ()>::Ok({
-                    crate::api::telepathy::Telepathy::set_play_custom_ringtones(
-                        &*api_that_guard,
-                        api_play,
-                    );
+                    crate::api::telepathy::Telepathy::set_stereo(&*api_that_guard, api_stereo);
                 })?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__telepathy__Telepathy_set_rms_threshold_impl(
+fn wire__crate__api__telepathy__Telepathy_set_vad_hangover_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Telepathy_set_rms_threshold",
+            debug_name: "Telepathy_set_vad_hangover",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3895,7 +4984,7 @@ fn wire__crate__api__telepathy__Telepathy_set_rms_threshold_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
             >>::sse_decode(&mut deserializer);
-            let api_decimal = <f32>::sse_decode(&mut deserializer);
+            let api_milliseconds = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
                 let mut api_that_guard = None;
@@ -3913,9 +5002,9 @@ fn wire__crate__api__telepathy__Telepathy_set_rms_threshold_impl(
                 }
                 let api_that_guard = api_that_guard.unwrap();
                 let output_ok = Result::<_, ()>::Ok({
-                    crate::api::telepathy::Telepathy::set_rms_threshold(
+                    crate::api::telepathy::Telepathy::set_vad_hangover(
                         &*api_that_guard,
-                        api_decimal,
+                        api_milliseconds,
                     );
                 })?;
                 Ok(output_ok)
@@ -3923,14 +5012,14 @@ fn wire__crate__api__telepathy__Telepathy_set_rms_threshold_impl(
         },
     )
 }
-fn wire__crate__api__telepathy__Telepathy_set_send_custom_ringtone_impl(
+fn wire__crate__api__telepathy__Telepathy_set_vad_threshold_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Telepathy_set_send_custom_ringtone",
+            debug_name: "Telepathy_set_vad_threshold",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3947,7 +5036,7 @@ fn wire__crate__api__telepathy__Telepathy_set_send_custom_ringtone_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
             >>::sse_decode(&mut deserializer);
-            let api_send = <bool>::sse_decode(&mut deserializer);
+            let api_probability = <f32>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
                 let mut api_that_guard = None;
@@ -3965,9 +5054,9 @@ fn wire__crate__api__telepathy__Telepathy_set_send_custom_ringtone_impl(
                 }
                 let api_that_guard = api_that_guard.unwrap();
                 let output_ok = Result::<_, ()>::Ok({
-                    crate::api::telepathy::Telepathy::set_send_custom_ringtone(
+                    crate::api::telepathy::Telepathy::set_vad_threshold(
                         &*api_that_guard,
-                        api_send,
+                        api_probability,
                     );
                 })?;
                 Ok(output_ok)
@@ -4050,6 +5139,68 @@ fn wire__crate__api__telepathy__Telepathy_start_call_impl(
         },
     )
 }
+fn wire__crate__api__telepathy__Telepathy_start_recording_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_start_recording",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_format = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::DartError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::api::telepathy::Telepathy::start_recording(
+                            &*api_that_guard,
+                            api_path,
+                            api_format,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__telepathy__Telepathy_start_screenshare_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -4202,6 +5353,64 @@ fn wire__crate__api__telepathy__Telepathy_start_session_impl(
         },
     )
 }
+fn wire__crate__api__telepathy__Telepathy_stop_recording_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Telepathy_stop_recording",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Telepathy>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::telepathy::Telepathy::stop_recording(&*api_that_guard)
+                                .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__telepathy__Telepathy_stop_session_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -4805,6 +6014,50 @@ fn decode_DartFn_Inputs_bool_bool_Output_unit_AnyhowException(
         ))
     }
 }
+fn decode_DartFn_Inputs_list_device_info_list_device_info_Output_unit_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(
+    Vec<crate::api::telepathy::DeviceInfo>,
+    Vec<crate::api::telepathy::DeviceInfo>,
+) -> flutter_rust_bridge::DartFnFuture<()> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(
+        dart_opaque: flutter_rust_bridge::DartOpaque,
+        arg0: Vec<crate::api::telepathy::DeviceInfo>,
+        arg1: Vec<crate::api::telepathy::DeviceInfo>,
+    ) -> () {
+        let args = vec![
+            arg0.into_into_dart().into_dart(),
+            arg1.into_into_dart().into_dart(),
+        ];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<()>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: Vec<crate::api::telepathy::DeviceInfo>,
+          arg1: Vec<crate::api::telepathy::DeviceInfo>| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+            arg1,
+        ))
+    }
+}
 fn decode_DartFn_Inputs_list_prim_u_8_strict_Output_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerContact_AnyhowException(
     dart_opaque: flutter_rust_bridge::DartOpaque,
 ) -> impl Fn(Vec<u8>) -> flutter_rust_bridge::DartFnFuture<Option<Contact>> {
@@ -5202,6 +6455,22 @@ impl SseDecode for String {
     }
 }
 
+impl SseDecode for crate::api::telepathy::AudioConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_device = <String>::sse_decode(deserializer);
+        let mut var_sampleRate = <u32>::sse_decode(deserializer);
+        let mut var_channels = <u16>::sse_decode(deserializer);
+        let mut var_bufferSize = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::telepathy::AudioConfig {
+            device: var_device,
+            sample_rate: var_sampleRate,
+            channels: var_channels,
+            buffer_size: var_bufferSize,
+        };
+    }
+}
+
 impl SseDecode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5219,6 +6488,28 @@ impl SseDecode for crate::api::error::DartError {
     }
 }
 
+impl SseDecode for crate::api::telepathy::DeviceInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_isDefault = <bool>::sse_decode(deserializer);
+        let mut var_sampleRates = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_channels = <Vec<u16>>::sse_decode(deserializer);
+        let mut var_minBufferSize = <Option<u32>>::sse_decode(deserializer);
+        let mut var_maxBufferSize = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::telepathy::DeviceInfo {
+            id: var_id,
+            name: var_name,
+            is_default: var_isDefault,
+            sample_rates: var_sampleRates,
+            channels: var_channels,
+            min_buffer_size: var_minBufferSize,
+            max_buffer_size: var_maxBufferSize,
+        };
+    }
+}
+
 impl SseDecode for f32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5259,6 +6550,44 @@ impl SseDecode for Vec<String> {
     }
 }
 
+impl SseDecode for Vec<crate::api::telepathy::DeviceInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::telepathy::DeviceInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u16>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5316,6 +6645,30 @@ impl SseDecode for Option<RecordingConfig> {
     }
 }
 
+impl SseDecode for Option<crate::api::telepathy::AudioConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::telepathy::AudioConfig>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u16>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5357,6 +6710,20 @@ impl SseDecode for (i32, i32) {
     }
 }
 
+impl SseDecode
+    for (
+        Vec<crate::api::telepathy::DeviceInfo>,
+        Vec<crate::api::telepathy::DeviceInfo>,
+    )
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <Vec<crate::api::telepathy::DeviceInfo>>::sse_decode(deserializer);
+        let mut var_field1 = <Vec<crate::api::telepathy::DeviceInfo>>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
 impl SseDecode for (Vec<String>, Vec<String>) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5366,6 +6733,20 @@ impl SseDecode for (Vec<String>, Vec<String>) {
     }
 }
 
+impl SseDecode
+    for (
+        Option<crate::api::telepathy::AudioConfig>,
+        Option<crate::api::telepathy::AudioConfig>,
+    )
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <Option<crate::api::telepathy::AudioConfig>>::sse_decode(deserializer);
+        let mut var_field1 = <Option<crate::api::telepathy::AudioConfig>>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
 impl SseDecode for (String, Vec<u8>) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5391,6 +6772,15 @@ impl SseDecode for crate::api::telepathy::Statistics {
         let mut var_uploadBandwidth = <usize>::sse_decode(deserializer);
         let mut var_downloadBandwidth = <usize>::sse_decode(deserializer);
         let mut var_loss = <f64>::sse_decode(deserializer);
+        let mut var_jitterBuffer = <usize>::sse_decode(deserializer);
+        let mut var_jitter = <f64>::sse_decode(deserializer);
+        let mut var_gain = <f32>::sse_decode(deserializer);
+        let mut var_speaking = <bool>::sse_decode(deserializer);
+        let mut var_captureDelay = <usize>::sse_decode(deserializer);
+        let mut var_processingDelay = <usize>::sse_decode(deserializer);
+        let mut var_networkDelay = <usize>::sse_decode(deserializer);
+        let mut var_playoutDelay = <usize>::sse_decode(deserializer);
+        let mut var_oneWayDelay = <usize>::sse_decode(deserializer);
         return crate::api::telepathy::Statistics {
             input_level: var_inputLevel,
             output_level: var_outputLevel,
@@ -5398,10 +6788,26 @@ impl SseDecode for crate::api::telepathy::Statistics {
             upload_bandwidth: var_uploadBandwidth,
             download_bandwidth: var_downloadBandwidth,
             loss: var_loss,
+            jitter_buffer: var_jitterBuffer,
+            jitter: var_jitter,
+            gain: var_gain,
+            speaking: var_speaking,
+            capture_delay: var_captureDelay,
+            processing_delay: var_processingDelay,
+            network_delay: var_networkDelay,
+            playout_delay: var_playoutDelay,
+            one_way_delay: var_oneWayDelay,
         };
     }
 }
 
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
 impl SseDecode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5443,194 +6849,224 @@ fn pde_ffi_dispatcher_primary_impl(
             rust_vec_len,
             data_len,
         ),
-        23 => {
+        25 => {
             wire__crate__api__telepathy__DartNotify_notified_impl(port, ptr, rust_vec_len, data_len)
         }
-        25 => wire__crate__api__telepathy__NetworkConfig_get_relay_address_impl(
+        27 => wire__crate__api__telepathy__NetworkConfig_get_relay_address_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        26 => wire__crate__api__telepathy__NetworkConfig_get_relay_id_impl(
+        28 => wire__crate__api__telepathy__NetworkConfig_get_relay_id_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        28 => wire__crate__api__telepathy__NetworkConfig_set_relay_address_impl(
+        30 => wire__crate__api__telepathy__NetworkConfig_set_relay_address_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        29 => wire__crate__api__telepathy__NetworkConfig_set_relay_id_impl(
+        31 => wire__crate__api__telepathy__NetworkConfig_set_relay_id_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        30 => wire__crate__api__overlay__overlay__Overlay_disable_impl(
+        32 => wire__crate__api__overlay__overlay__Overlay_disable_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        31 => wire__crate__api__overlay__overlay__Overlay_enable_impl(
+        33 => wire__crate__api__overlay__overlay__Overlay_enable_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        32 => {
+        34 => {
             wire__crate__api__overlay__overlay__Overlay_hide_impl(port, ptr, rust_vec_len, data_len)
         }
-        33 => wire__crate__api__overlay__overlay__Overlay_move_overlay_impl(
+        35 => wire__crate__api__overlay__overlay__Overlay_move_overlay_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        34 => {
+        36 => {
             wire__crate__api__overlay__overlay__Overlay_new_impl(port, ptr, rust_vec_len, data_len)
         }
-        36 => wire__crate__api__overlay__overlay__Overlay_set_background_color_impl(
+        38 => wire__crate__api__overlay__overlay__Overlay_set_background_color_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        37 => wire__crate__api__overlay__overlay__Overlay_set_font_color_impl(
+        39 => wire__crate__api__overlay__overlay__Overlay_set_font_color_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        38 => wire__crate__api__overlay__overlay__Overlay_set_font_height_impl(
+        40 => wire__crate__api__overlay__overlay__Overlay_set_font_height_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        39 => {
+        41 => {
             wire__crate__api__overlay__overlay__Overlay_show_impl(port, ptr, rust_vec_len, data_len)
         }
-        45 => wire__crate__api__telepathy__ScreenshareConfig_capabilities_impl(
+        47 => wire__crate__api__telepathy__ScreenshareConfig_capabilities_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        46 => wire__crate__api__telepathy__ScreenshareConfig_default_impl(
+        48 => wire__crate__api__telepathy__ScreenshareConfig_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        47 => wire__crate__api__telepathy__ScreenshareConfig_new_impl(
+        49 => wire__crate__api__telepathy__ScreenshareConfig_new_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        48 => wire__crate__api__telepathy__ScreenshareConfig_recording_config_impl(
+        50 => wire__crate__api__telepathy__ScreenshareConfig_recording_config_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        50 => wire__crate__api__telepathy__ScreenshareConfig_update_recording_config_impl(
+        52 => wire__crate__api__telepathy__ScreenshareConfig_update_recording_config_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        54 => wire__crate__api__player__SoundPlayer_play_impl(port, ptr, rust_vec_len, data_len),
-        55 => wire__crate__api__player__SoundPlayer_update_output_device_impl(
+        56 => wire__crate__api__player__SoundPlayer_play_impl(port, ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__player__SoundPlayer_update_output_device_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        57 => wire__crate__api__telepathy__Telepathy_audio_test_impl(
+        59 => wire__crate__api__telepathy__Telepathy_audio_test_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        60 => {
+        63 => {
             wire__crate__api__telepathy__Telepathy_join_room_impl(port, ptr, rust_vec_len, data_len)
         }
-        61 => wire__crate__api__telepathy__Telepathy_list_devices_impl(
+        64 => wire__crate__api__telepathy__Telepathy_list_device_info_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        65 => wire__crate__api__telepathy__Telepathy_list_devices_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        62 => wire__crate__api__telepathy__Telepathy_new_impl(port, ptr, rust_vec_len, data_len),
-        63 => wire__crate__api__telepathy__Telepathy_restart_manager_impl(
+        66 => wire__crate__api__telepathy__Telepathy_new_impl(port, ptr, rust_vec_len, data_len),
+        68 => wire__crate__api__telepathy__Telepathy_restart_manager_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        64 => {
+        69 => {
             wire__crate__api__telepathy__Telepathy_send_chat_impl(port, ptr, rust_vec_len, data_len)
         }
-        68 => wire__crate__api__telepathy__Telepathy_set_identity_impl(
+        79 => wire__crate__api__telepathy__Telepathy_set_devices_changed_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        69 => wire__crate__api__telepathy__Telepathy_set_input_device_impl(
+        82 => wire__crate__api__telepathy__Telepathy_set_identity_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        71 => {
+        83 => wire__crate__api__telepathy__Telepathy_set_input_device_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        85 => {
             wire__crate__api__telepathy__Telepathy_set_model_impl(port, ptr, rust_vec_len, data_len)
         }
-        73 => wire__crate__api__telepathy__Telepathy_set_output_device_impl(
+        87 => wire__crate__api__telepathy__Telepathy_set_output_device_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        78 => wire__crate__api__telepathy__Telepathy_start_call_impl(
+        92 => wire__crate__api__telepathy__Telepathy_set_recording_changed_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        79 => wire__crate__api__telepathy__Telepathy_start_screenshare_impl(
+        99 => wire__crate__api__telepathy__Telepathy_start_call_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        80 => wire__crate__api__telepathy__Telepathy_start_session_impl(
+        100 => wire__crate__api__telepathy__Telepathy_start_recording_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        81 => wire__crate__api__telepathy__Telepathy_stop_session_impl(
+        101 => wire__crate__api__telepathy__Telepathy_start_screenshare_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        84 => wire__crate__api__logger__init_logger_impl(port, ptr, rust_vec_len, data_len),
-        85 => wire__crate__api__player__load_ringtone_impl(port, ptr, rust_vec_len, data_len),
-        87 => wire__crate__api__logger__send_to_dart_logger_set_stream_sink_impl(
+        102 => wire__crate__api__telepathy__Telepathy_start_session_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        88 => {
+        103 => wire__crate__api__telepathy__Telepathy_stop_recording_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        104 => wire__crate__api__telepathy__Telepathy_stop_session_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        107 => wire__crate__api__logger__init_logger_impl(port, ptr, rust_vec_len, data_len),
+        108 => wire__crate__api__player__load_ringtone_impl(port, ptr, rust_vec_len, data_len),
+        110 => wire__crate__api__logger__send_to_dart_logger_set_stream_sink_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        111 => {
             wire__crate__api__telepathy__statistics_default_impl(port, ptr, rust_vec_len, data_len)
         }
         _ => unreachable!(),
@@ -5665,94 +7101,158 @@ fn pde_ffi_dispatcher_sync_impl(
         ),
         8 => wire__crate__api__telepathy__ChatMessage_is_sender_impl(ptr, rust_vec_len, data_len),
         9 => wire__crate__api__telepathy__ChatMessage_time_impl(ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__telepathy__CodecConfig_new_impl(ptr, rust_vec_len, data_len),
-        11 => {
+        10 => wire__crate__api__telepathy__CodecConfig_fec_impl(ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__telepathy__CodecConfig_new_impl(ptr, rust_vec_len, data_len),
+        12 => {
             wire__crate__api__telepathy__CodecConfig_set_enabled_impl(ptr, rust_vec_len, data_len)
         }
-        12 => wire__crate__api__telepathy__CodecConfig_set_residual_bits_impl(
+        13 => wire__crate__api__telepathy__CodecConfig_set_fec_impl(ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__telepathy__CodecConfig_set_residual_bits_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        13 => wire__crate__api__telepathy__CodecConfig_set_vbr_impl(ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__telepathy__CodecConfig_to_values_impl(ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__contact__Contact_from_parts_impl(ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__contact__Contact_id_impl(ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__contact__Contact_id_eq_impl(ptr, rust_vec_len, data_len),
-        18 => wire__crate__api__contact__Contact_new_impl(ptr, rust_vec_len, data_len),
-        19 => wire__crate__api__contact__Contact_nickname_impl(ptr, rust_vec_len, data_len),
-        20 => wire__crate__api__contact__Contact_peer_id_impl(ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__contact__Contact_pub_clone_impl(ptr, rust_vec_len, data_len),
-        22 => wire__crate__api__contact__Contact_set_nickname_impl(ptr, rust_vec_len, data_len),
-        24 => wire__crate__api__telepathy__DartNotify_notify_impl(ptr, rust_vec_len, data_len),
-        27 => wire__crate__api__telepathy__NetworkConfig_new_impl(ptr, rust_vec_len, data_len),
-        35 => wire__crate__api__overlay__overlay__Overlay_screen_resolution_impl(
+        15 => wire__crate__api__telepathy__CodecConfig_set_vbr_impl(ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__telepathy__CodecConfig_to_values_impl(ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__contact__Contact_from_parts_impl(ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__contact__Contact_id_impl(ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__contact__Contact_id_eq_impl(ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__contact__Contact_new_impl(ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__contact__Contact_nickname_impl(ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__contact__Contact_peer_id_impl(ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__contact__Contact_pub_clone_impl(ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__contact__Contact_set_nickname_impl(ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__telepathy__DartNotify_notify_impl(ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__telepathy__NetworkConfig_new_impl(ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__overlay__overlay__Overlay_screen_resolution_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        40 => {
+        42 => {
             wire__crate__api__telepathy__RecordingConfig_bitrate_impl(ptr, rust_vec_len, data_len)
         }
-        41 => wire__crate__api__telepathy__RecordingConfig_device_impl(ptr, rust_vec_len, data_len),
-        42 => {
+        43 => wire__crate__api__telepathy__RecordingConfig_device_impl(ptr, rust_vec_len, data_len),
+        44 => {
             wire__crate__api__telepathy__RecordingConfig_encoder_impl(ptr, rust_vec_len, data_len)
         }
-        43 => {
+        45 => {
             wire__crate__api__telepathy__RecordingConfig_framerate_impl(ptr, rust_vec_len, data_len)
         }
-        44 => wire__crate__api__telepathy__RecordingConfig_height_impl(ptr, rust_vec_len, data_len),
-        49 => wire__crate__api__telepathy__ScreenshareConfig_to_string_impl(
+        46 => wire__crate__api__telepathy__RecordingConfig_height_impl(ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__telepathy__ScreenshareConfig_to_string_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        53 => wire__crate__api__player__SoundHandle_cancel_impl(ptr, rust_vec_len, data_len),
+        54 => wire__crate__api__player__SoundPlayer_host_impl(ptr, rust_vec_len, data_len),
+        55 => wire__crate__api__player__SoundPlayer_new_impl(ptr, rust_vec_len, data_len),
+        58 => wire__crate__api__player__SoundPlayer_update_output_volume_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        60 => wire__crate__api__telepathy__Telepathy_build_chat_impl(ptr, rust_vec_len, data_len),
+        61 => wire__crate__api__telepathy__Telepathy_end_call_impl(ptr, rust_vec_len, data_len),
+        62 => wire__crate__api__telepathy__Telepathy_is_recording_impl(ptr, rust_vec_len, data_len),
+        67 => wire__crate__api__telepathy__Telepathy_opened_audio_configs_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        70 => wire__crate__api__telepathy__Telepathy_set_agc_impl(ptr, rust_vec_len, data_len),
+        71 => {
+            wire__crate__api__telepathy__Telepathy_set_agc_attack_impl(ptr, rust_vec_len, data_len)
+        }
+        72 => wire__crate__api__telepathy__Telepathy_set_agc_max_gain_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        73 => {
+            wire__crate__api__telepathy__Telepathy_set_agc_release_impl(ptr, rust_vec_len, data_len)
+        }
+        74 => wire__crate__api__telepathy__Telepathy_set_agc_target_level_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        75 => {
+            wire__crate__api__telepathy__Telepathy_set_buffer_size_impl(ptr, rust_vec_len, data_len)
+        }
+        76 => wire__crate__api__telepathy__Telepathy_set_comfort_noise_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        77 => wire__crate__api__telepathy__Telepathy_set_deafened_impl(ptr, rust_vec_len, data_len),
+        78 => wire__crate__api__telepathy__Telepathy_set_denoise_impl(ptr, rust_vec_len, data_len),
+        80 => wire__crate__api__telepathy__Telepathy_set_echo_cancellation_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        81 => wire__crate__api__telepathy__Telepathy_set_efficiency_mode_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        84 => wire__crate__api__telepathy__Telepathy_set_input_volume_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        86 => wire__crate__api__telepathy__Telepathy_set_muted_impl(ptr, rust_vec_len, data_len),
+        88 => wire__crate__api__telepathy__Telepathy_set_output_volume_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        89 => wire__crate__api__telepathy__Telepathy_set_play_custom_ringtones_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        51 => wire__crate__api__player__SoundHandle_cancel_impl(ptr, rust_vec_len, data_len),
-        52 => wire__crate__api__player__SoundPlayer_host_impl(ptr, rust_vec_len, data_len),
-        53 => wire__crate__api__player__SoundPlayer_new_impl(ptr, rust_vec_len, data_len),
-        56 => wire__crate__api__player__SoundPlayer_update_output_volume_impl(
+        90 => wire__crate__api__telepathy__Telepathy_set_preferred_channels_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        58 => wire__crate__api__telepathy__Telepathy_build_chat_impl(ptr, rust_vec_len, data_len),
-        59 => wire__crate__api__telepathy__Telepathy_end_call_impl(ptr, rust_vec_len, data_len),
-        65 => wire__crate__api__telepathy__Telepathy_set_deafened_impl(ptr, rust_vec_len, data_len),
-        66 => wire__crate__api__telepathy__Telepathy_set_denoise_impl(ptr, rust_vec_len, data_len),
-        67 => wire__crate__api__telepathy__Telepathy_set_efficiency_mode_impl(
+        91 => wire__crate__api__telepathy__Telepathy_set_preferred_sample_rate_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        70 => wire__crate__api__telepathy__Telepathy_set_input_volume_impl(
+        93 => wire__crate__api__telepathy__Telepathy_set_rms_threshold_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        72 => wire__crate__api__telepathy__Telepathy_set_muted_impl(ptr, rust_vec_len, data_len),
-        74 => wire__crate__api__telepathy__Telepathy_set_output_volume_impl(
+        94 => wire__crate__api__telepathy__Telepathy_set_send_custom_ringtone_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        75 => wire__crate__api__telepathy__Telepathy_set_play_custom_ringtones_impl(
+        95 => wire__crate__api__telepathy__Telepathy_set_silence_detection_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        76 => wire__crate__api__telepathy__Telepathy_set_rms_threshold_impl(
+        96 => wire__crate__api__telepathy__Telepathy_set_stereo_impl(ptr, rust_vec_len, data_len),
+        97 => wire__crate__api__telepathy__Telepathy_set_vad_hangover_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        77 => wire__crate__api__telepathy__Telepathy_set_send_custom_ringtone_impl(
+        98 => wire__crate__api__telepathy__Telepathy_set_vad_threshold_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        82 => wire__crate__api__logger__create_log_stream_impl(ptr, rust_vec_len, data_len),
-        83 => wire__crate__api__crypto__generate_keys_impl(ptr, rust_vec_len, data_len),
-        86 => wire__crate__api__logger__rust_set_up_impl(ptr, rust_vec_len, data_len),
+        105 => wire__crate__api__logger__create_log_stream_impl(ptr, rust_vec_len, data_len),
+        106 => wire__crate__api__crypto__generate_keys_impl(ptr, rust_vec_len, data_len),
+        109 => wire__crate__api__logger__rust_set_up_impl(ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
@@ -5954,6 +7454,29 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Telepathy>> for Telepathy {
     }
 }
 
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::telepathy::AudioConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.device.into_into_dart().into_dart(),
+            self.sample_rate.into_into_dart().into_dart(),
+            self.channels.into_into_dart().into_dart(),
+            self.buffer_size.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::telepathy::AudioConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::telepathy::AudioConfig>
+    for crate::api::telepathy::AudioConfig
+{
+    fn into_into_dart(self) -> crate::api::telepathy::AudioConfig {
+        self
+    }
+}
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::error::DartError {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
@@ -5969,6 +7492,32 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::error::DartError>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::telepathy::DeviceInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.is_default.into_into_dart().into_dart(),
+            self.sample_rates.into_into_dart().into_dart(),
+            self.channels.into_into_dart().into_dart(),
+            self.min_buffer_size.into_into_dart().into_dart(),
+            self.max_buffer_size.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::telepathy::DeviceInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::telepathy::DeviceInfo>
+    for crate::api::telepathy::DeviceInfo
+{
+    fn into_into_dart(self) -> crate::api::telepathy::DeviceInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::logger::SendToDartLogger {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         Vec::<u8>::new().into_dart()
@@ -5995,6 +7544,15 @@ impl flutter_rust_bridge::IntoDart for crate::api::telepathy::Statistics {
             self.upload_bandwidth.into_into_dart().into_dart(),
             self.download_bandwidth.into_into_dart().into_dart(),
             self.loss.into_into_dart().into_dart(),
+            self.jitter_buffer.into_into_dart().into_dart(),
+            self.jitter.into_into_dart().into_dart(),
+            self.gain.into_into_dart().into_dart(),
+            self.speaking.into_into_dart().into_dart(),
+            self.capture_delay.into_into_dart().into_dart(),
+            self.processing_delay.into_into_dart().into_dart(),
+            self.network_delay.into_into_dart().into_dart(),
+            self.playout_delay.into_into_dart().into_dart(),
+            self.one_way_delay.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -6269,6 +7827,16 @@ impl SseEncode for String {
     }
 }
 
+impl SseEncode for crate::api::telepathy::AudioConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.device, serializer);
+        <u32>::sse_encode(self.sample_rate, serializer);
+        <u16>::sse_encode(self.channels, serializer);
+        <Option<u32>>::sse_encode(self.buffer_size, serializer);
+    }
+}
+
 impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6283,6 +7851,19 @@ impl SseEncode for crate::api::error::DartError {
     }
 }
 
+impl SseEncode for crate::api::telepathy::DeviceInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <bool>::sse_encode(self.is_default, serializer);
+        <Vec<u32>>::sse_encode(self.sample_rates, serializer);
+        <Vec<u16>>::sse_encode(self.channels, serializer);
+        <Option<u32>>::sse_encode(self.min_buffer_size, serializer);
+        <Option<u32>>::sse_encode(self.max_buffer_size, serializer);
+    }
+}
+
 impl SseEncode for f32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6324,6 +7905,36 @@ impl SseEncode for Vec<String> {
     }
 }
 
+impl SseEncode for Vec<crate::api::telepathy::DeviceInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::telepathy::DeviceInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u16>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u32>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6374,6 +7985,26 @@ impl SseEncode for Option<RecordingConfig> {
     }
 }
 
+impl SseEncode for Option<crate::api::telepathy::AudioConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::telepathy::AudioConfig>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u16>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6411,6 +8042,19 @@ impl SseEncode for (i32, i32) {
     }
 }
 
+impl SseEncode
+    for (
+        Vec<crate::api::telepathy::DeviceInfo>,
+        Vec<crate::api::telepathy::DeviceInfo>,
+    )
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::telepathy::DeviceInfo>>::sse_encode(self.0, serializer);
+        <Vec<crate::api::telepathy::DeviceInfo>>::sse_encode(self.1, serializer);
+    }
+}
+
 impl SseEncode for (Vec<String>, Vec<String>) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6419,6 +8063,19 @@ impl SseEncode for (Vec<String>, Vec<String>) {
     }
 }
 
+impl SseEncode
+    for (
+        Option<crate::api::telepathy::AudioConfig>,
+        Option<crate::api::telepathy::AudioConfig>,
+    )
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<crate::api::telepathy::AudioConfig>>::sse_encode(self.0, serializer);
+        <Option<crate::api::telepathy::AudioConfig>>::sse_encode(self.1, serializer);
+    }
+}
+
 impl SseEncode for (String, Vec<u8>) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6441,6 +8098,22 @@ impl SseEncode for crate::api::telepathy::Statistics {
         <usize>::sse_encode(self.upload_bandwidth, serializer);
         <usize>::sse_encode(self.download_bandwidth, serializer);
         <f64>::sse_encode(self.loss, serializer);
+        <usize>::sse_encode(self.jitter_buffer, serializer);
+        <f64>::sse_encode(self.jitter, serializer);
+        <f32>::sse_encode(self.gain, serializer);
+        <bool>::sse_encode(self.speaking, serializer);
+        <usize>::sse_encode(self.capture_delay, serializer);
+        <usize>::sse_encode(self.processing_delay, serializer);
+        <usize>::sse_encode(self.network_delay, serializer);
+        <usize>::sse_encode(self.playout_delay, serializer);
+        <usize>::sse_encode(self.one_way_delay, serializer);
+    }
+}
+
+impl SseEncode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u16::<NativeEndian>(self).unwrap();
     }
 }
 